@@ -1,7 +1,21 @@
-use std::{env::var, collections::HashMap};
+use std::{env::var, hash::Hash, collections::{HashMap, BTreeMap}, sync::Arc};
 use dotenv::dotenv;
+use reqwest::Client;
+use serde::de::DeserializeOwned;
+use futures::stream::{self, Stream, StreamExt};
 use crate::error::Error;
-use crate::{Unit, APIEndpoint};
+use crate::client::{ClientConfig, AuthMethod};
+use crate::retry::RetryPolicy;
+use crate::rate_limit::RateLimiter;
+use crate::cache::{Cache, CachePolicy};
+use crate::cassette::Cassette;
+use crate::middleware::Middleware;
+use crate::request::*;
+use crate::timestamp::IntoTimestamp;
+use crate::pagination::{Timestamped, HistoricalEndpoint, RangeCursor};
+use crate::batch::OHLCVEndpoint;
+use crate::gaps::{Grid, GapReport};
+use crate::{Unit, APIEndpointTrait, BaseURL};
 use crate::schemas::{self as sh, CoinDeskResponse};
 use crate::schemas::min_api;
 use crate::schemas::data_api::indices_and_reference_rates::{IndicesMarket, IndicesOHLCV};
@@ -15,19 +29,89 @@ use crate::schemas::data_api::on_chain_core::{OCCoreETHBlock, OCCoreAssetByChain
 use crate::schemas::data_api::asset::{AssetMetadata, AssetEvent, AssetCodeRepoMetrics, AssetDiscord, AssetReddit, AssetTelegram, AssetTwitter};
 use crate::schemas::data_api::news::{NewsStatus, NewsLang, NewsSourceID, NewsLatestArticle, NewsSourceType, NewsSource, NewsCategory};
 use crate::schemas::data_api::overview::OverviewMktCapOHLCV;
-use crate::utils::{Param, call_api_endpoint};
+use crate::utils::{Param, call_backend_endpoint};
 
 
 /// API data collection backend.
 pub struct CoinDesk {
     /// CoinDesk API key
     api_key: Option<String>,
+    /// HTTP client that is reused for every request sent by the backend
+    client: Client,
+    /// Base URLs of the CoinDesk API hosts
+    base_url: BaseURL,
+    /// Method of passing the API key to CoinDesk API
+    auth_method: AuthMethod,
+    /// Retry policy for transient failures
+    retry_policy: Option<RetryPolicy>,
+    /// Client-side rate limiter (may be shared between several backends)
+    rate_limiter: Option<Arc<RateLimiter>>,
+    /// Cache of the API responses (may be shared between several backends)
+    cache: Option<Arc<dyn Cache>>,
+    /// Time-to-live rules of the cached responses
+    cache_policy: CachePolicy,
+    /// Record/replay storage of the API responses
+    cassette: Option<Cassette>,
+    /// Middleware applied to every request, in the order of registration
+    middleware: Vec<Arc<dyn Middleware>>,
 }
 
 impl CoinDesk {
     /// Creates a new backend for data collection.
+    ///
+    /// Note: The HTTP client is built from the default `ClientConfig`.
     pub fn new() -> Self {
-        Self { api_key: None }
+        let client: Client = ClientConfig::default().build_client().expect("Default HTTP client configuration must be valid.");
+        Self::from_client(client)
+    }
+
+    /// Creates a new backend for data collection with the HTTP client built from the provided configuration.
+    ///
+    /// # Input
+    /// - `config`: Configuration of the HTTP client (e.g., timeouts, proxy, user agent, custom root certificates)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use ccdata_api::{CoinDesk, ClientConfig};
+    ///
+    /// let config: ClientConfig = ClientConfig { connect_timeout: Some(Duration::from_secs(5)), ..Default::default() };
+    /// let mut backend: CoinDesk = CoinDesk::with_client_config(config).unwrap();
+    /// // Provide API key as the environment variable called API_KEY
+    /// backend.build(&"API_KEY").unwrap();
+    /// ```
+    pub fn with_client_config(config: ClientConfig) -> Result<Self, Error> {
+        Ok(Self::from_client(config.build_client()?))
+    }
+
+    fn from_client(client: Client) -> Self {
+        Self {
+            api_key: None, client, base_url: BaseURL::default(), auth_method: AuthMethod::default(), retry_policy: None, rate_limiter: None,
+            cache: None, cache_policy: CachePolicy::default(), cassette: None, middleware: Vec::new(),
+        }
+    }
+
+    /// Returns the reference to the HTTP client used by the backend.
+    pub fn client(&self) -> &Client {
+        &self.client
+    }
+
+    /// Rebuilds the HTTP client from the provided configuration.
+    ///
+    /// # Input
+    /// - `config`: Configuration of the HTTP client (e.g., timeouts, proxy, user agent, custom root certificates)
+    pub fn update_client_config(&mut self, config: ClientConfig) -> Result<(), Error> {
+        self.client = config.build_client()?;
+        Ok(())
+    }
+
+    /// Replaces the HTTP client with an externally constructed one.
+    ///
+    /// # Input
+    /// - `client`: HTTP client that will be used by the backend to send requests to CoinDesk API endpoints
+    pub fn update_client(&mut self, client: Client) -> () {
+        self.client = client;
     }
 
     /// Returns the refernce to the defined API key.
@@ -71,6 +155,500 @@ impl CoinDesk {
         self.api_key = Some(new_api_key);
     }
 
+    /// Returns the reference to the base URLs of the CoinDesk API hosts used by the backend.
+    pub fn base_url(&self) -> &BaseURL {
+        &self.base_url
+    }
+
+    /// Updates the base URLs of the CoinDesk API hosts.
+    ///
+    /// # Input
+    /// - `new_base_url`: Base URLs that will replace the default Data API and Min-API hosts (e.g., local mock server, caching proxy or staging mirror)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ccdata_api::{CoinDesk, BaseURL};
+    ///
+    /// let mut backend: CoinDesk = CoinDesk::new();
+    ///
+    /// let base_url: BaseURL = BaseURL { data_api: String::from("http://127.0.0.1:8080"), ..Default::default() };
+    /// backend.update_base_url(base_url);
+    ///
+    /// assert_eq!(backend.base_url().data_api, "http://127.0.0.1:8080");
+    /// ```
+    pub fn update_base_url(&mut self, new_base_url: BaseURL) -> () {
+        self.base_url = new_base_url;
+    }
+
+    /// Returns the method of passing the API key to CoinDesk API.
+    pub fn auth_method(&self) -> AuthMethod {
+        self.auth_method
+    }
+
+    /// Updates the method of passing the API key to CoinDesk API.
+    ///
+    /// # Input
+    /// - `new_auth_method`: `Header` sends the API key in the `Authorization` header (Default), `QueryString` adds it to the URL
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ccdata_api::{CoinDesk, AuthMethod};
+    ///
+    /// let mut backend: CoinDesk = CoinDesk::new();
+    /// backend.update_auth_method(AuthMethod::QueryString);
+    ///
+    /// assert_eq!(backend.auth_method(), AuthMethod::QueryString);
+    /// ```
+    pub fn update_auth_method(&mut self, new_auth_method: AuthMethod) -> () {
+        self.auth_method = new_auth_method;
+    }
+
+    /// Returns the reference to the retry policy of the backend.
+    pub fn retry_policy(&self) -> Option<&RetryPolicy> {
+        self.retry_policy.as_ref()
+    }
+
+    /// Updates the retry policy of the backend.
+    ///
+    /// Note: By default the backend does not retry failed requests.
+    ///
+    /// # Input
+    /// - `new_retry_policy`: Retry policy for transient failures (`None` disables retries)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ccdata_api::{CoinDesk, RetryPolicy};
+    ///
+    /// let mut backend: CoinDesk = CoinDesk::new();
+    /// backend.update_retry_policy(Some(RetryPolicy::default()));
+    ///
+    /// assert_eq!(backend.retry_policy().unwrap().max_attempts, 3);
+    /// ```
+    pub fn update_retry_policy(&mut self, new_retry_policy: Option<RetryPolicy>) -> () {
+        self.retry_policy = new_retry_policy;
+    }
+
+    /// Returns the reference to the rate limiter of the backend.
+    pub fn rate_limiter(&self) -> Option<&Arc<RateLimiter>> {
+        self.rate_limiter.as_ref()
+    }
+
+    /// Updates the rate limiter of the backend.
+    ///
+    /// Note: The same rate limiter can be used by several backends that share the API key.
+    ///
+    /// # Input
+    /// - `new_rate_limiter`: Client-side rate limiter (`None` disables throttling)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::sync::Arc;
+    /// use ccdata_api::{CoinDesk, RateLimiter, RateLimitWindow};
+    ///
+    /// let rate_limiter: Arc<RateLimiter> = Arc::new(RateLimiter::new().with_limit(RateLimitWindow::Second, 20));
+    ///
+    /// let mut backend_1: CoinDesk = CoinDesk::new();
+    /// backend_1.update_rate_limiter(Some(rate_limiter.clone()));
+    /// let mut backend_2: CoinDesk = CoinDesk::new();
+    /// backend_2.update_rate_limiter(Some(rate_limiter));
+    /// ```
+    pub fn update_rate_limiter(&mut self, new_rate_limiter: Option<Arc<RateLimiter>>) -> () {
+        self.rate_limiter = new_rate_limiter;
+    }
+
+    /// Returns the reference to the response cache of the backend.
+    pub fn cache(&self) -> Option<&Arc<dyn Cache>> {
+        self.cache.as_ref()
+    }
+
+    /// Updates the response cache of the backend.
+    ///
+    /// Note: The cache key consists of the endpoint URL and the normalized query parameters, the API key is not a part of the key.
+    ///
+    /// # Input
+    /// - `new_cache`: Cache of the API responses (`None` disables caching)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::sync::Arc;
+    /// use ccdata_api::{CoinDesk, FileCache};
+    ///
+    /// let mut backend: CoinDesk = CoinDesk::new();
+    /// let cache: FileCache = FileCache::new(std::env::temp_dir().join("ccdata_api_cache")).unwrap();
+    /// backend.update_cache(Some(Arc::new(cache)));
+    ///
+    /// assert!(backend.cache().is_some());
+    /// ```
+    pub fn update_cache(&mut self, new_cache: Option<Arc<dyn Cache>>) -> () {
+        self.cache = new_cache;
+    }
+
+    /// Returns the time-to-live rules of the cached responses.
+    pub fn cache_policy(&self) -> &CachePolicy {
+        &self.cache_policy
+    }
+
+    /// Updates the time-to-live rules of the cached responses.
+    ///
+    /// # Input
+    /// - `new_cache_policy`: Default TTL and per-endpoint TTLs of the cached responses
+    pub fn update_cache_policy(&mut self, new_cache_policy: CachePolicy) -> () {
+        self.cache_policy = new_cache_policy;
+    }
+
+    /// Returns the reference to the cassette of the backend.
+    pub fn cassette(&self) -> Option<&Cassette> {
+        self.cassette.as_ref()
+    }
+
+    /// Updates the cassette of the backend.
+    ///
+    /// Note: In the record mode every response is saved to the cassette directory (without the API key), and in the replay mode
+    /// the responses are served from the cassette directory without sending any requests (API key is not required).
+    ///
+    /// # Input
+    /// - `new_cassette`: Record/replay storage of the API responses (`None` disables recording and replaying)
+    pub fn update_cassette(&mut self, new_cassette: Option<Cassette>) -> () {
+        self.cassette = new_cassette;
+    }
+
+    /// Returns the middleware registered on the backend.
+    pub fn middleware(&self) -> &[Arc<dyn Middleware>] {
+        &self.middleware
+    }
+
+    /// Registers the middleware on the backend.
+    ///
+    /// Note: The hooks are invoked in the order in which the middleware was registered.
+    ///
+    /// # Input
+    /// - `middleware`: Hooks that are applied to every request and response
+    pub fn add_middleware(&mut self, middleware: Arc<dyn Middleware>) -> () {
+        self.middleware.push(middleware);
+    }
+
+    /// Replaces the middleware registered on the backend.
+    ///
+    /// # Input
+    /// - `new_middleware`: Middleware applied to every request, in the order of invocation
+    pub fn update_middleware(&mut self, new_middleware: Vec<Arc<dyn Middleware>>) -> () {
+        self.middleware = new_middleware;
+    }
+
+    /// Sends the request to any API endpoint, including the CoinDesk API endpoints that are not wrapped by the backend methods.
+    ///
+    /// # Input
+    /// - `endpoint`: CoinDesk API endpoint (`APIEndpoint` or a custom type that implements `APIEndpointTrait`)
+    /// - `unit`: Unit for data to be binned by (e.g., `Day`, `Hour`)
+    /// - `params`: List of parameters expected by the CoinDesk API endpoint
+    /// - `additional_params`: Additional parameters to add to the request (e.g., `&param=value`)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ccdata_api::{CoinDesk, CoinDeskResponse, APIEndpointTrait, Group, Param, Unit, DATA_API_URL};
+    ///
+    /// struct SpotLatestTick;
+    ///
+    /// impl APIEndpointTrait for SpotLatestTick {
+    ///     fn default_groups(&self) -> Option<Vec<Group>> { None }
+    ///
+    ///     fn default_params(&self) -> Option<Vec<Param<'_>>> { None }
+    ///
+    ///     fn url(&self, _unit: &Unit) -> String { format!("{DATA_API_URL}/spot/v1/latest/tick") }
+    /// }
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> () {
+    ///
+    ///     let mut backend: CoinDesk = CoinDesk::new();
+    ///     // Provide API key as the environment variable called API_KEY
+    ///     backend.build(&"API_KEY").unwrap();
+    ///
+    ///     let instruments: Vec<String> = vec![String::from("BTC-USD")];
+    ///     let params: Vec<Param> = vec![Param::Market { v: String::from("kraken"), }, Param::Instruments { v: &instruments, }];
+    ///     let tick: CoinDeskResponse<serde_json::Value> = backend.call_api_endpoint(SpotLatestTick, Unit::NA, params, None).await.unwrap();
+    ///     assert!(tick.data.is_some());
+    ///
+    /// }
+    /// ```
+    pub async fn call_api_endpoint<'a, R: DeserializeOwned>(&self, endpoint: impl APIEndpointTrait, unit: Unit, params: Vec<Param<'a>>,
+                                                            additional_params: Option<String>) -> Result<R, Error> {
+        call_backend_endpoint::<R>(self, endpoint, unit, params, additional_params).await
+    }
+
+    /// Sends the request and deserializes the response into the schema that is paired with the request.
+    ///
+    /// # Input
+    /// - `request`: Request to a CoinDesk API endpoint (Any type that implements `Endpoint`)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ccdata_api::{CoinDesk, SpotOHLCVRequest, SpotMarket, Unit};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> () {
+    ///
+    ///     let mut backend: CoinDesk = CoinDesk::new();
+    ///     // Provide API key as the environment variable called API_KEY
+    ///     backend.build(&"API_KEY").unwrap();
+    ///
+    ///     let request: SpotOHLCVRequest = SpotOHLCVRequest::new("BTC-USD")
+    ///         .to_timestamp(1728860400)
+    ///         .limit(2000)
+    ///         .market(SpotMarket::KRAKEN)
+    ///         .unit(Unit::Day);
+    ///     let ohlcv = backend.send(&request).await.unwrap();
+    ///     assert_eq!(ohlcv.data.unwrap().len(), 2000);
+    ///
+    /// }
+    /// ```
+    pub async fn send<E: Endpoint>(&self, request: &E) -> Result<E::Response, Error> {
+        call_backend_endpoint::<E::Response>(self, request.endpoint(), request.unit(), request.params(), request.additional_params()).await
+    }
+
+    /// Sends the request repeatedly, moving `to_timestamp` backwards page by page, and returns all data points within the time range.
+    ///
+    /// Note: The pagination stops at `from` or at the first available timestamp reported by CoinDesk API (`CCErrorOtherInfo.first`).
+    /// The returned data points are deduplicated and ordered by timestamp (oldest first). The `limit` of the request sets the page size.
+    ///
+    /// # Input
+    /// - `request`: Request to a historical CoinDesk API endpoint (Any type that implements `HistoricalEndpoint`)
+    /// - `from`: Initial timestamp from which the data will be extracted
+    /// - `to`: Final timestamp up to which the data will be extracted
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ccdata_api::{CoinDesk, SpotOHLCV, SpotOHLCVRequest, SpotMarket, Unit};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> () {
+    ///
+    ///     let mut backend: CoinDesk = CoinDesk::new();
+    ///     // Provide API key as the environment variable called API_KEY
+    ///     backend.build(&"API_KEY").unwrap();
+    ///
+    ///     let request: SpotOHLCVRequest = SpotOHLCVRequest::new("BTC-USD")
+    ///         .market(SpotMarket::KRAKEN)
+    ///         .unit(Unit::Hour);
+    ///     let ohlcv: Vec<SpotOHLCV> = backend.send_range(&request, 1722470400, 1728860400).await.unwrap();
+    ///     assert_eq!(ohlcv.len(), 1768);
+    ///
+    /// }
+    /// ```
+    pub async fn send_range<T: Timestamped, E: HistoricalEndpoint<T>>(&self, request: &E, from: impl IntoTimestamp, to: impl IntoTimestamp) -> Result<Vec<T>, Error> {
+        let mut request: E = request.clone();
+        let mut cursor: RangeCursor = RangeCursor::new(from.into_timestamp(), to.into_timestamp());
+        let mut data: BTreeMap<i64, T> = BTreeMap::new();
+        while let Some(to_timestamp) = cursor.next_to_timestamp() {
+            request.update_to_timestamp(Some(to_timestamp));
+            for v in cursor.advance(self.send(&request).await)? {
+                data.entry(v.timestamp()).or_insert(v);
+            }
+        }
+        Ok(data.into_values().collect())
+    }
+
+    /// Returns a stream of pages of the time range, sending the next request only when the next page is polled.
+    ///
+    /// Note: Pages are yielded from the most recent to the oldest, and the data points within every page are ordered by timestamp (oldest first).
+    /// The pagination stops at `from` or at the first available timestamp reported by CoinDesk API (`CCErrorOtherInfo.first`), and the stream
+    /// ends after the first error. Dropping the stream stops the pagination.
+    ///
+    /// # Input
+    /// - `request`: Request to a historical CoinDesk API endpoint (Any type that implements `HistoricalEndpoint`)
+    /// - `from`: Initial timestamp from which the data will be extracted
+    /// - `to`: Final timestamp up to which the data will be extracted
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use futures::StreamExt;
+    /// use ccdata_api::{CoinDesk, SpotOHLCVRequest, SpotMarket, Unit};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> () {
+    ///
+    ///     let mut backend: CoinDesk = CoinDesk::new();
+    ///     // Provide API key as the environment variable called API_KEY
+    ///     backend.build(&"API_KEY").unwrap();
+    ///
+    ///     let request: SpotOHLCVRequest = SpotOHLCVRequest::new("BTC-USD")
+    ///         .market(SpotMarket::KRAKEN)
+    ///         .unit(Unit::Minute);
+    ///     let mut pages = Box::pin(backend.stream_range(&request, 1722470400, 1728860400));
+    ///     while let Some(page) = pages.next().await {
+    ///         assert!(page.unwrap().len() <= 2000);
+    ///     }
+    ///
+    /// }
+    /// ```
+    pub fn stream_range<'a, T, E>(&'a self, request: &E, from: impl IntoTimestamp, to: impl IntoTimestamp) -> impl Stream<Item = Result<Vec<T>, Error>> + 'a
+    where
+        T: Timestamped + 'a,
+        E: HistoricalEndpoint<T> + 'a,
+    {
+        stream::unfold((request.clone(), RangeCursor::new(from.into_timestamp(), to.into_timestamp())), move |(mut request, mut cursor)| async move {
+            loop {
+                request.update_to_timestamp(Some(cursor.next_to_timestamp()?));
+                match cursor.advance(self.send(&request).await) {
+                    Ok(page) if page.is_empty() => continue,
+                    Ok(mut page) => {
+                        page.sort_by_key(|v| v.timestamp() );
+                        return Some((Ok(page), (request, cursor)));
+                    },
+                    Err(e) => {
+                        cursor.stop();
+                        return Some((Err(e), (request, cursor)));
+                    },
+                }
+            }
+        })
+    }
+
+    /// Refetches the missing intervals of the series from CoinDesk API and returns the series with the refetched data points.
+    ///
+    /// Note: Every range of missing intervals reported by `Grid::detect` is requested with [`CoinDesk::send_range`]. The returned data points
+    /// are deduplicated and ordered by timestamp (oldest first). Intervals that CoinDesk API has no data for (`404 Not Found` response) remain
+    /// missing, and the other gaps are still refetched.
+    ///
+    /// # Input
+    /// - `request`: Request to a historical CoinDesk API endpoint that the series was fetched with (Any type that implements `HistoricalEndpoint`)
+    /// - `series`: Data points of the same instrument
+    /// - `grid`: Grid of the intervals of the series (e.g., `Grid::from_unit(&Unit::Hour, 1)`)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ccdata_api::{CoinDesk, Grid, SpotOHLCV, SpotOHLCVRequest, SpotMarket, Unit};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> () {
+    ///
+    ///     let mut backend: CoinDesk = CoinDesk::new();
+    ///     // Provide API key as the environment variable called API_KEY
+    ///     backend.build(&"API_KEY").unwrap();
+    ///
+    ///     let request: SpotOHLCVRequest = SpotOHLCVRequest::new("BTC-USD")
+    ///         .market(SpotMarket::KRAKEN)
+    ///         .unit(Unit::Hour);
+    ///     let grid: Grid = Grid::from_unit(&Unit::Hour, 1).unwrap();
+    ///     let ohlcv: Vec<SpotOHLCV> = backend.send_range(&request, 1722470400, 1728860400).await.unwrap();
+    ///     let ohlcv: Vec<SpotOHLCV> = backend.refetch_gaps(&request, &ohlcv, &grid).await.unwrap();
+    ///     assert!(grid.detect(&ohlcv).missing.is_empty());
+    ///
+    /// }
+    /// ```
+    pub async fn refetch_gaps<T, E>(&self, request: &E, series: &[T], grid: &Grid) -> Result<Vec<T>, Error>
+    where
+        T: Timestamped + Clone,
+        E: HistoricalEndpoint<T>,
+    {
+        let report: GapReport = grid.detect(series);
+        let mut data: BTreeMap<i64, T> = series.iter().map(|v| (v.timestamp(), v.clone()) ).collect();
+        for (from, to) in report.missing {
+            let refetched: Vec<T> = match self.send_range(request, from, to).await {
+                Ok(v) => v,
+                // No data for the gap (e.g., exchange outage)
+                Err(Error::NotFound(_)) => continue,
+                Err(e) => return Err(e),
+            };
+            for v in refetched {
+                data.entry(v.timestamp()).or_insert(v);
+            }
+        }
+        Ok(data.into_values().collect())
+    }
+
+    /// Sends the requests concurrently, with at most `concurrency` requests in flight, and returns the response to every request by its key.
+    ///
+    /// Note: A failed request does not abort the batch, its error is returned under its key. Every request passes through the rate limiter and
+    /// the retry policy of the backend. If several requests have the same key, only the response to the last one is kept.
+    ///
+    /// # Input
+    /// - `requests`: Pairs of keys (e.g., instrument symbols) and requests to CoinDesk API endpoints
+    /// - `concurrency`: Maximum number of requests in flight (`0` is treated as `1`)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::collections::HashMap;
+    /// use ccdata_api::{CoinDesk, AssetEventsRequest};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> () {
+    ///
+    ///     let mut backend: CoinDesk = CoinDesk::new();
+    ///     // Provide API key as the environment variable called API_KEY
+    ///     backend.build(&"API_KEY").unwrap();
+    ///
+    ///     let requests = ["ETH", "SOL", "XRP"].map(|v| (v, AssetEventsRequest::new(v)) );
+    ///     let events = backend.send_batch(requests, 2).await;
+    ///     assert!(events.get("ETH").unwrap().is_ok());
+    ///
+    /// }
+    /// ```
+    pub async fn send_batch<K, E>(&self, requests: impl IntoIterator<Item = (K, E)>, concurrency: usize) -> HashMap<K, Result<E::Response, Error>>
+    where
+        K: Eq + Hash,
+        E: Endpoint,
+    {
+        stream::iter(requests)
+            .map(|(key, request)| async move { (key, self.send(&request).await) } )
+            .buffer_unordered(concurrency.max(1))
+            .collect().await
+    }
+
+    /// Sends the OHLCV request for every `(instrument, market)` target concurrently, with at most `concurrency` requests in flight,
+    /// and returns the response for every instrument.
+    ///
+    /// Note: The other parameters of the request (e.g., `unit`, `to_timestamp`, `limit`) are shared by all targets. A failed request does not
+    /// abort the batch, its error is returned under its instrument. If an instrument appears in several targets, only the response for the last
+    /// target is kept.
+    ///
+    /// # Input
+    /// - `request`: Request to an OHLCV API endpoint that is used as the template for every target (Any type that implements `OHLCVEndpoint`)
+    /// - `targets`: Pairs of instrument symbols and market names
+    /// - `concurrency`: Maximum number of requests in flight (`0` is treated as `1`)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ccdata_api::{CoinDesk, SpotOHLCVRequest, SpotMarket, Unit};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> () {
+    ///
+    ///     let mut backend: CoinDesk = CoinDesk::new();
+    ///     // Provide API key as the environment variable called API_KEY
+    ///     backend.build(&"API_KEY").unwrap();
+    ///
+    ///     let request: SpotOHLCVRequest = SpotOHLCVRequest::new("").unit(Unit::Hour).limit(24);
+    ///     let targets = [("BTC-USD", SpotMarket::KRAKEN), ("ETH-USD", SpotMarket::KRAKEN), ("BTC-USDT", SpotMarket::BINANCE)];
+    ///     let ohlcv = backend.get_ohlcv_batch(&request, targets, 8).await;
+    ///     assert_eq!(ohlcv.get("ETH-USD").unwrap().as_ref().unwrap().data.as_ref().unwrap().len(), 24);
+    ///
+    /// }
+    /// ```
+    pub async fn get_ohlcv_batch<E: OHLCVEndpoint>(
+        &self, request: &E, targets: impl IntoIterator<Item = (impl ToString, E::Market)>, concurrency: usize
+    ) -> HashMap<String, Result<E::Response, Error>> {
+        let requests = targets.into_iter().map(|(instrument, market)| {
+            let instrument: String = instrument.to_string();
+            let mut request: E = request.clone();
+            request.update_target(&instrument, market);
+            (instrument, request)
+        } );
+        self.send_batch(requests, concurrency).await
+    }
+
     /// Initiates the API data collection backend with the API key stored in the environment variable.
     ///
     /// # Input
@@ -106,14 +684,88 @@ impl CoinDesk {
     /// back through time for the number of points as specifed by the limit. Timestamp values are based on 00:00 GMT time.
     ///
     /// # Input
-    /// - `to_timestamp`: Final timestamp up to which the data will be extracted
-    /// - `limit`: Maximum number of datapoints per API endpoint call
+    /// - `to_timestamp`: Final timestamp up to which the data will be extracted
+    /// - `limit`: Maximum number of datapoints per API endpoint call
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    ///
+    /// use ccdata_api::CoinDesk;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> () {
+    ///
+    ///     let mut backend: CoinDesk = CoinDesk::new();
+    ///     // Provide API key as the environment variable called API_KEY
+    ///     backend.build(&"API_KEY").unwrap();
+    ///
+    ///     let limit: usize = 2000;
+    ///     let balance_distribution = backend.get_balance_distribution(None, Some(limit)).await.unwrap();
+    ///     assert!(balance_distribution.data.unwrap().data.unwrap().len() <= limit);
+    ///
+    /// }
+    /// ```
+    pub async fn get_balance_distribution(&self, to_timestamp: Option<i64>, limit: Option<usize>) -> Result<sh::CCMinResponse<sh::CCMinWrapper<Vec<min_api::BalanceDistribution>>>, Error> {
+        self.send(&BalanceDistributionRequest { to_timestamp, limit, }).await
+    }
+
+    /// # Historical OHLCV+ \[Day, Hour, Minute\] (Indices & Ref. Rates)
+    /// Returns historical OHLCV data for a given instrument.
+    ///
+    /// # Description (CoinDesk Documentation)
+    /// This endpoint is meticulously designed to provide historical candlestick data for various indices, captured at one-day intervals.
+    /// The data encompasses crucial metrics such as OPEN, HIGH, LOW, CLOSE, VOLUME and additional trading-derived values (OHLCV+),
+    /// offering a comprehensive view of an index's historical performance. This information is essential for conducting in-depth market analyses and making
+    /// informed decisions based on past trends.
+    ///
+    /// # Input
+    /// - `instument`: Instrument symbol
+    /// - `to_timestamp`: Final timestamp up to which the data will be extracted
+    /// - `limit`: Maximum number of datapoints per API endpoint call
+    /// - `market`: Market name
+    /// - `unit`: Unit of the interval between successive data points
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    ///
+    /// use ccdata_api::{CoinDesk, Unit, IndicesMarket};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> () {
+    ///
+    ///     let mut backend: CoinDesk = CoinDesk::new();
+    ///     // Provide API key as the environment variable called API_KEY
+    ///     backend.build(&"API_KEY").unwrap();
+    ///
+    ///     let market: IndicesMarket = IndicesMarket::CADLI;
+    ///     let limit: usize = 2000;
+    ///     let ohlcv = backend.get_indices_ohlcv("BTC-USD", None, Some(limit), market, Unit::Day).await.unwrap();
+    ///     assert_eq!(ohlcv.data.unwrap().len(), limit);
+    ///
+    /// }
+    /// ```
+    pub async fn get_indices_ohlcv(&self, instrument: &str, to_timestamp: Option<i64>, limit: Option<usize>, market: IndicesMarket, unit: Unit) -> Result<CoinDeskResponse<Vec<IndicesOHLCV>>, Error> {
+        self.send(&IndicesOHLCVRequest { to_timestamp, limit, market, unit, ..IndicesOHLCVRequest::new(instrument) }).await
+    }
+
+    /// # Historical OHLCV+ \[Day, Hour, Minute\] (Indices & Ref. Rates) - Stream
+    /// Returns a stream of pages between `from` and `to`, paginating over [`get_indices_ohlcv`](Self::get_indices_ohlcv) (see [`stream_range`](Self::stream_range)).
+    ///
+    /// # Input
+    /// - `instrument`: Instrument symbol
+    /// - `from`: Initial timestamp from which the data will be extracted
+    /// - `to`: Final timestamp up to which the data will be extracted
+    /// - `market`: Market name
+    /// - `unit`: Unit of the interval between successive data points
     ///
     /// # Examples
     ///
     /// ```rust
     ///
-    /// use ccdata_api::CoinDesk;
+    /// use futures::StreamExt;
+    /// use ccdata_api::{CoinDesk, Unit, IndicesMarket};
     ///
     /// #[tokio::main]
     /// async fn main() -> () {
@@ -122,34 +774,23 @@ impl CoinDesk {
     ///     // Provide API key as the environment variable called API_KEY
     ///     backend.build(&"API_KEY").unwrap();
     ///
-    ///     let limit: usize = 2000;
-    ///     let balance_distribution = backend.get_balance_distribution(None, Some(limit)).await.unwrap();
-    ///     assert!(balance_distribution.data.unwrap().data.unwrap().len() <= limit);
+    ///     let mut pages = Box::pin(backend.get_indices_ohlcv_stream("BTC-USD", 1722470400, 1728860400, IndicesMarket::CADLI, Unit::Minute));
+    ///     let page = pages.next().await.unwrap().unwrap();
+    ///     assert!(page.windows(2).all(|v| v[0].timestamp < v[1].timestamp ));
     ///
     /// }
     /// ```
-    pub async fn get_balance_distribution(&self, to_timestamp: Option<i64>, limit: Option<usize>) -> Result<sh::CCMinResponse<sh::CCMinWrapper<Vec<min_api::BalanceDistribution>>>, Error> {
-        call_api_endpoint::<sh::CCMinResponse<sh::CCMinWrapper<Vec<min_api::BalanceDistribution>>>>(
-            self.api_key()?,
-            APIEndpoint::BalanceDistribution, Unit::NA,
-            vec![Param::Symbol { v: "BTC", }, Param::Limit { v: limit, }, Param::ToTs { v: to_timestamp, }],
-            None
-        ).await
+    pub fn get_indices_ohlcv_stream<'a>(&'a self, instrument: &str, from: impl IntoTimestamp, to: impl IntoTimestamp, market: IndicesMarket, unit: Unit) -> impl Stream<Item = Result<Vec<IndicesOHLCV>, Error>> + 'a {
+        self.stream_range(&IndicesOHLCVRequest { market, unit, ..IndicesOHLCVRequest::new(instrument) }, from, to)
     }
 
-    /// # Historical OHLCV+ \[Day, Hour, Minute\] (Indices & Ref. Rates)
-    /// Returns historical OHLCV data for a given instrument.
-    ///
-    /// # Description (CoinDesk Documentation)
-    /// This endpoint is meticulously designed to provide historical candlestick data for various indices, captured at one-day intervals.
-    /// The data encompasses crucial metrics such as OPEN, HIGH, LOW, CLOSE, VOLUME and additional trading-derived values (OHLCV+),
-    /// offering a comprehensive view of an index's historical performance. This information is essential for conducting in-depth market analyses and making
-    /// informed decisions based on past trends.
+    /// # Historical OHLCV+ \[Day, Hour, Minute\] (Indices & Ref. Rates) - Time Range
+    /// Returns all data points between `from` and `to`, paginating over [`get_indices_ohlcv`](Self::get_indices_ohlcv) (see [`send_range`](Self::send_range)).
     ///
     /// # Input
-    /// - `instument`: Instrument symbol
-    /// - `to_timestamp`: Final timestamp up to which the data will be extracted
-    /// - `limit`: Maximum number of datapoints per API endpoint call
+    /// - `instrument`: Instrument symbol
+    /// - `from`: Initial timestamp from which the data will be extracted
+    /// - `to`: Final timestamp up to which the data will be extracted
     /// - `market`: Market name
     /// - `unit`: Unit of the interval between successive data points
     ///
@@ -166,20 +807,13 @@ impl CoinDesk {
     ///     // Provide API key as the environment variable called API_KEY
     ///     backend.build(&"API_KEY").unwrap();
     ///
-    ///     let market: IndicesMarket = IndicesMarket::CADLI;
-    ///     let limit: usize = 2000;
-    ///     let ohlcv = backend.get_indices_ohlcv("BTC-USD", None, Some(limit), market, Unit::Day).await.unwrap();
-    ///     assert_eq!(ohlcv.data.unwrap().len(), limit);
+    ///     let data = backend.get_indices_ohlcv_range("BTC-USD", 1722470400, 1728860400, IndicesMarket::CADLI, Unit::Hour).await.unwrap();
+    ///     assert!(data.windows(2).all(|v| v[0].timestamp < v[1].timestamp ));
     ///
     /// }
     /// ```
-    pub async fn get_indices_ohlcv(&self, instrument: &str, to_timestamp: Option<i64>, limit: Option<usize>, market: IndicesMarket, unit: Unit) -> Result<CoinDeskResponse<Vec<IndicesOHLCV>>, Error> {
-        call_api_endpoint::<CoinDeskResponse<Vec<IndicesOHLCV>>>(
-            self.api_key()?,
-            APIEndpoint::IndicesOHLCV, unit,
-            vec![Param::Instrument { v: instrument, }, Param::Limit { v: limit, }, Param::ToTimestamp { v: to_timestamp, }, Param::Market { v: market.to_string(), }],
-            None
-        ).await
+    pub async fn get_indices_ohlcv_range(&self, instrument: &str, from: impl IntoTimestamp, to: impl IntoTimestamp, market: IndicesMarket, unit: Unit) -> Result<Vec<IndicesOHLCV>, Error> {
+        self.send_range(&IndicesOHLCVRequest { market, unit, ..IndicesOHLCVRequest::new(instrument) }, from, to).await
     }
 
     /// # Historical OHLCV+ \[Day, Hour, Minute\] (Spot)
@@ -218,12 +852,73 @@ impl CoinDesk {
     /// }
     /// ```
     pub async fn get_spot_ohlcv(&self, instrument: &str, to_timestamp: Option<i64>, limit: Option<usize>, market: SpotMarket, unit: Unit) -> Result<CoinDeskResponse<Vec<SpotOHLCV>>, Error> {
-        call_api_endpoint::<CoinDeskResponse<Vec<SpotOHLCV>>>(
-            self.api_key()?,
-            APIEndpoint::SpotOHLCV, unit,
-            vec![Param::Instrument { v: instrument, }, Param::Limit { v: limit, }, Param::ToTimestamp { v: to_timestamp, }, Param::Market { v: market.to_string(), }],
-            None
-        ).await
+        self.send(&SpotOHLCVRequest { to_timestamp, limit, market, unit, ..SpotOHLCVRequest::new(instrument) }).await
+    }
+
+    /// # Historical OHLCV+ \[Day, Hour, Minute\] (Spot) - Stream
+    /// Returns a stream of pages between `from` and `to`, paginating over [`get_spot_ohlcv`](Self::get_spot_ohlcv) (see [`stream_range`](Self::stream_range)).
+    ///
+    /// # Input
+    /// - `instrument`: Instrument symbol
+    /// - `from`: Initial timestamp from which the data will be extracted
+    /// - `to`: Final timestamp up to which the data will be extracted
+    /// - `market`: Market name
+    /// - `unit`: Unit of the interval between successive data points
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    ///
+    /// use futures::StreamExt;
+    /// use ccdata_api::{CoinDesk, Unit, SpotMarket};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> () {
+    ///
+    ///     let mut backend: CoinDesk = CoinDesk::new();
+    ///     // Provide API key as the environment variable called API_KEY
+    ///     backend.build(&"API_KEY").unwrap();
+    ///
+    ///     let mut pages = Box::pin(backend.get_spot_ohlcv_stream("BTC-USD", 1722470400, 1728860400, SpotMarket::KRAKEN, Unit::Minute));
+    ///     let page = pages.next().await.unwrap().unwrap();
+    ///     assert!(page.windows(2).all(|v| v[0].timestamp < v[1].timestamp ));
+    ///
+    /// }
+    /// ```
+    pub fn get_spot_ohlcv_stream<'a>(&'a self, instrument: &str, from: impl IntoTimestamp, to: impl IntoTimestamp, market: SpotMarket, unit: Unit) -> impl Stream<Item = Result<Vec<SpotOHLCV>, Error>> + 'a {
+        self.stream_range(&SpotOHLCVRequest { market, unit, ..SpotOHLCVRequest::new(instrument) }, from, to)
+    }
+
+    /// # Historical OHLCV+ \[Day, Hour, Minute\] (Spot) - Time Range
+    /// Returns all data points between `from` and `to`, paginating over [`get_spot_ohlcv`](Self::get_spot_ohlcv) (see [`send_range`](Self::send_range)).
+    ///
+    /// # Input
+    /// - `instrument`: Instrument symbol
+    /// - `from`: Initial timestamp from which the data will be extracted
+    /// - `to`: Final timestamp up to which the data will be extracted
+    /// - `market`: Market name
+    /// - `unit`: Unit of the interval between successive data points
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    ///
+    /// use ccdata_api::{CoinDesk, Unit, SpotMarket};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> () {
+    ///
+    ///     let mut backend: CoinDesk = CoinDesk::new();
+    ///     // Provide API key as the environment variable called API_KEY
+    ///     backend.build(&"API_KEY").unwrap();
+    ///
+    ///     let data = backend.get_spot_ohlcv_range("BTC-USD", 1722470400, 1728860400, SpotMarket::KRAKEN, Unit::Hour).await.unwrap();
+    ///     assert!(data.windows(2).all(|v| v[0].timestamp < v[1].timestamp ));
+    ///
+    /// }
+    /// ```
+    pub async fn get_spot_ohlcv_range(&self, instrument: &str, from: impl IntoTimestamp, to: impl IntoTimestamp, market: SpotMarket, unit: Unit) -> Result<Vec<SpotOHLCV>, Error> {
+        self.send_range(&SpotOHLCVRequest { market, unit, ..SpotOHLCVRequest::new(instrument) }, from, to).await
     }
 
     /// # Instrument Metadata (Spot)
@@ -258,13 +953,8 @@ impl CoinDesk {
     ///
     /// }
     /// ```
-    pub async fn get_spot_instrument_metadata(&self, instruments: &Vec<String>, market: SpotMarket) -> Result<CoinDeskResponse<HashMap<String, SpotInstrumentMetdata>>, Error> {
-        call_api_endpoint::<CoinDeskResponse<HashMap<String, SpotInstrumentMetdata>>>(
-            self.api_key()?,
-            APIEndpoint::SpotInstrumentMetadata, Unit::NA,
-            vec![Param::Instruments { v: instruments, }, Param::Market { v: market.to_string(), }],
-            None
-        ).await
+    pub async fn get_spot_instrument_metadata(&self, instruments: &[String], market: SpotMarket) -> Result<CoinDeskResponse<HashMap<String, SpotInstrumentMetdata>>, Error> {
+        self.send(&SpotInstrumentMetadataRequest { market, ..SpotInstrumentMetadataRequest::new(instruments) }).await
     }
 
     /// # Markets (Spot) - V2
@@ -299,13 +989,7 @@ impl CoinDesk {
     /// }
     /// ```
     pub async fn get_spot_markets_v2(&self, markets: Vec<SpotMarket>) -> Result<CoinDeskResponse<HashMap<String, SpotMarkets>>, Error> {
-        let v: Vec<String> = markets.iter().map(|v| v.to_string() ).collect();
-        call_api_endpoint::<CoinDeskResponse<HashMap<String, SpotMarkets>>>(
-            self.api_key()?,
-            APIEndpoint::SpotMarketsV2, Unit::NA,
-            vec![Param::Markets { v, }],
-            None
-        ).await
+        self.send(&SpotMarketsRequest::new(markets)).await
     }
 
     /// # Markets + Instruments \[Mapped\] (Spot)
@@ -344,13 +1028,8 @@ impl CoinDesk {
     ///
     /// }
     /// ```
-    pub async fn get_spot_markets_instruments(&self, instruments: &Vec<String>, market: SpotMarket, instrument_status: SpotInstrumentStatus) -> Result<CoinDeskResponse<HashMap<String, SpotMarketsInstruments>>, Error> {
-        call_api_endpoint::<CoinDeskResponse<HashMap<String, SpotMarketsInstruments>>>(
-            self.api_key()?,
-            APIEndpoint::SpotMarketsInstruments, Unit::NA,
-            vec![Param::Instruments { v: instruments, }, Param::Market { v: market.to_string(), }, Param::InstrumentStatus { v: instrument_status, }],
-            None
-        ).await
+    pub async fn get_spot_markets_instruments(&self, instruments: &[String], market: SpotMarket, instrument_status: SpotInstrumentStatus) -> Result<CoinDeskResponse<HashMap<String, SpotMarketsInstruments>>, Error> {
+        self.send(&SpotMarketsInstrumentsRequest { market, instrument_status, ..SpotMarketsInstrumentsRequest::new(instruments) }).await
     }
 
     /// # Historical OHLCV+ \[Day, Hour, Minute\] (Futures)
@@ -391,12 +1070,73 @@ impl CoinDesk {
     /// }
     /// ```
     pub async fn get_futures_ohlcv(&self, instrument: &str, to_timestamp: Option<i64>, limit: Option<usize>, market: FuturesMarket, unit: Unit) -> Result<CoinDeskResponse<Vec<FuturesOHLCV>>, Error> {
-        call_api_endpoint::<CoinDeskResponse<Vec<FuturesOHLCV>>>(
-            self.api_key()?,
-            APIEndpoint::FuturesOHLCV, unit,
-            vec![Param::Instrument { v: instrument, }, Param::Limit { v: limit, }, Param::ToTimestamp { v: to_timestamp, }, Param::Market { v: market.to_string(), }],
-            None
-        ).await
+        self.send(&FuturesOHLCVRequest { to_timestamp, limit, market, unit, ..FuturesOHLCVRequest::new(instrument) }).await
+    }
+
+    /// # Historical OHLCV+ \[Day, Hour, Minute\] (Futures) - Stream
+    /// Returns a stream of pages between `from` and `to`, paginating over [`get_futures_ohlcv`](Self::get_futures_ohlcv) (see [`stream_range`](Self::stream_range)).
+    ///
+    /// # Input
+    /// - `instrument`: Instrument symbol
+    /// - `from`: Initial timestamp from which the data will be extracted
+    /// - `to`: Final timestamp up to which the data will be extracted
+    /// - `market`: Market name
+    /// - `unit`: Unit of the interval between successive data points
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    ///
+    /// use futures::StreamExt;
+    /// use ccdata_api::{CoinDesk, Unit, FuturesMarket};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> () {
+    ///
+    ///     let mut backend: CoinDesk = CoinDesk::new();
+    ///     // Provide API key as the environment variable called API_KEY
+    ///     backend.build(&"API_KEY").unwrap();
+    ///
+    ///     let mut pages = Box::pin(backend.get_futures_ohlcv_stream("BTC-USDT-VANILLA-PERPETUAL", 1722470400, 1728860400, FuturesMarket::BINANCE, Unit::Minute));
+    ///     let page = pages.next().await.unwrap().unwrap();
+    ///     assert!(page.windows(2).all(|v| v[0].timestamp < v[1].timestamp ));
+    ///
+    /// }
+    /// ```
+    pub fn get_futures_ohlcv_stream<'a>(&'a self, instrument: &str, from: impl IntoTimestamp, to: impl IntoTimestamp, market: FuturesMarket, unit: Unit) -> impl Stream<Item = Result<Vec<FuturesOHLCV>, Error>> + 'a {
+        self.stream_range(&FuturesOHLCVRequest { market, unit, ..FuturesOHLCVRequest::new(instrument) }, from, to)
+    }
+
+    /// # Historical OHLCV+ \[Day, Hour, Minute\] (Futures) - Time Range
+    /// Returns all data points between `from` and `to`, paginating over [`get_futures_ohlcv`](Self::get_futures_ohlcv) (see [`send_range`](Self::send_range)).
+    ///
+    /// # Input
+    /// - `instrument`: Instrument symbol
+    /// - `from`: Initial timestamp from which the data will be extracted
+    /// - `to`: Final timestamp up to which the data will be extracted
+    /// - `market`: Market name
+    /// - `unit`: Unit of the interval between successive data points
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    ///
+    /// use ccdata_api::{CoinDesk, Unit, FuturesMarket};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> () {
+    ///
+    ///     let mut backend: CoinDesk = CoinDesk::new();
+    ///     // Provide API key as the environment variable called API_KEY
+    ///     backend.build(&"API_KEY").unwrap();
+    ///
+    ///     let data = backend.get_futures_ohlcv_range("BTC-USDT-VANILLA-PERPETUAL", 1722470400, 1728860400, FuturesMarket::BINANCE, Unit::Hour).await.unwrap();
+    ///     assert!(data.windows(2).all(|v| v[0].timestamp < v[1].timestamp ));
+    ///
+    /// }
+    /// ```
+    pub async fn get_futures_ohlcv_range(&self, instrument: &str, from: impl IntoTimestamp, to: impl IntoTimestamp, market: FuturesMarket, unit: Unit) -> Result<Vec<FuturesOHLCV>, Error> {
+        self.send_range(&FuturesOHLCVRequest { market, unit, ..FuturesOHLCVRequest::new(instrument) }, from, to).await
     }
 
     /// # Instrument Metadata (Futures)
@@ -434,13 +1174,8 @@ impl CoinDesk {
     ///
     /// }
     /// ```
-    pub async fn get_futures_instrument_metadata(&self, instruments: &Vec<String>, market: FuturesMarket) -> Result<CoinDeskResponse<HashMap<String, FuturesInstrumentMetadata>>, Error> {
-        call_api_endpoint::<CoinDeskResponse<HashMap<String, FuturesInstrumentMetadata>>>(
-            self.api_key()?,
-            APIEndpoint::FuturesInstrumentMetadata, Unit::NA,
-            vec![Param::Instruments { v: instruments, }, Param::Market { v: market.to_string(), }],
-            None
-        ).await
+    pub async fn get_futures_instrument_metadata(&self, instruments: &[String], market: FuturesMarket) -> Result<CoinDeskResponse<HashMap<String, FuturesInstrumentMetadata>>, Error> {
+        self.send(&FuturesInstrumentMetadataRequest { market, ..FuturesInstrumentMetadataRequest::new(instruments) }).await
     }
 
     /// # Markets (Futures)
@@ -477,13 +1212,7 @@ impl CoinDesk {
     /// }
     /// ```
     pub async fn get_futures_markets_v2(&self, markets: Vec<FuturesMarket>) -> Result<CoinDeskResponse<HashMap<String, FuturesMarkets>>, Error> {
-        let v: Vec<String> = markets.iter().map(|v| v.to_string() ).collect();
-        call_api_endpoint::<CoinDeskResponse<HashMap<String, FuturesMarkets>>>(
-            self.api_key()?,
-            APIEndpoint::FuturesMarketsV2, Unit::NA,
-            vec![Param::Markets { v, }],
-            None
-        ).await
+        self.send(&FuturesMarketsRequest::new(markets)).await
     }  
 
     /// # Historical OHLCV+ \[Day, Hour, Minute\] (Options)
@@ -523,12 +1252,41 @@ impl CoinDesk {
     /// }
     /// ```
     pub async fn get_options_ohlcv(&self, instrument: &str, to_timestamp: Option<i64>, limit: Option<usize>, market: OptionsMarket, unit: Unit) -> Result<CoinDeskResponse<Vec<OptionsOHLCV>>, Error> {
-        call_api_endpoint::<CoinDeskResponse<Vec<OptionsOHLCV>>>(
-            self.api_key()?,
-            APIEndpoint::OptionsOHLCV, unit,
-            vec![Param::Instrument { v: instrument, }, Param::Limit { v: limit, }, Param::ToTimestamp { v: to_timestamp, }, Param::Market { v: market.to_string(), }],
-            None
-        ).await
+        self.send(&OptionsOHLCVRequest { to_timestamp, limit, market, unit, ..OptionsOHLCVRequest::new(instrument) }).await
+    }
+
+    /// # Historical OHLCV+ \[Day, Hour, Minute\] (Options) - Stream
+    /// Returns a stream of pages between `from` and `to`, paginating over [`get_options_ohlcv`](Self::get_options_ohlcv) (see [`stream_range`](Self::stream_range)).
+    ///
+    /// # Input
+    /// - `instrument`: Instrument symbol
+    /// - `from`: Initial timestamp from which the data will be extracted
+    /// - `to`: Final timestamp up to which the data will be extracted
+    /// - `market`: Market name
+    /// - `unit`: Unit of the interval between successive data points
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    ///
+    /// use futures::StreamExt;
+    /// use ccdata_api::{CoinDesk, Unit, OptionsMarket};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> () {
+    ///
+    ///     let mut backend: CoinDesk = CoinDesk::new();
+    ///     // Provide API key as the environment variable called API_KEY
+    ///     backend.build(&"API_KEY").unwrap();
+    ///
+    ///     let mut pages = Box::pin(backend.get_options_ohlcv_stream("BTC-USD-20241227-15000-P", 1722470400, 1728860400, OptionsMarket::OKEX, Unit::Minute));
+    ///     let page = pages.next().await.unwrap().unwrap();
+    ///     assert!(page.windows(2).all(|v| v[0].timestamp < v[1].timestamp ));
+    ///
+    /// }
+    /// ```
+    pub fn get_options_ohlcv_stream<'a>(&'a self, instrument: &str, from: impl IntoTimestamp, to: impl IntoTimestamp, market: OptionsMarket, unit: Unit) -> impl Stream<Item = Result<Vec<OptionsOHLCV>, Error>> + 'a {
+        self.stream_range(&OptionsOHLCVRequest { market, unit, ..OptionsOHLCVRequest::new(instrument) }, from, to)
     }
 
     /// # Instrument Metadata (Options)
@@ -564,13 +1322,8 @@ impl CoinDesk {
     ///
     /// }
     /// ```
-    pub async fn get_options_instrument_metadata(&self, instruments: &Vec<String>, market: OptionsMarket) -> Result<CoinDeskResponse<HashMap<String, OptionsInstrumentMetadata>>, Error> {
-        call_api_endpoint::<CoinDeskResponse<HashMap<String, OptionsInstrumentMetadata>>>(
-            self.api_key()?,
-            APIEndpoint::OptionsInstrumentMetadata, Unit::NA,
-            vec![Param::Instruments { v: instruments, }, Param::Market { v: market.to_string(), }],
-            None
-        ).await
+    pub async fn get_options_instrument_metadata(&self, instruments: &[String], market: OptionsMarket) -> Result<CoinDeskResponse<HashMap<String, OptionsInstrumentMetadata>>, Error> {
+        self.send(&OptionsInstrumentMetadataRequest { market, ..OptionsInstrumentMetadataRequest::new(instruments) }).await
     }
 
     /// # Markets (Options)
@@ -608,13 +1361,7 @@ impl CoinDesk {
     /// }
     /// ```
     pub async fn get_options_markets_v2(&self, markets: Vec<OptionsMarket>) -> Result<CoinDeskResponse<HashMap<String, OptionsMarkets>>, Error> {
-        let v: Vec<String> = markets.iter().map(|v| v.to_string() ).collect();
-        call_api_endpoint::<CoinDeskResponse<HashMap<String, OptionsMarkets>>>(
-            self.api_key()?,
-            APIEndpoint::OptionsMarketsV2, Unit::NA,
-            vec![Param::Markets { v, }],
-            None
-        ).await
+        self.send(&OptionsMarketsRequest::new(markets)).await
     }
 
     /// # Historical OHLCV+ \[Day, Hour, Minute\] (Derivatives Indices)
@@ -654,12 +1401,41 @@ impl CoinDesk {
     /// }
     /// ```
     pub async fn get_der_indices_ohlcv(&self, instrument: &str, to_timestamp: Option<i64>, limit: Option<usize>, market: DerIndicesMarket, unit: Unit) -> Result<CoinDeskResponse<Vec<DerIndicesOHLCV>>, Error> {
-        call_api_endpoint::<CoinDeskResponse<Vec<DerIndicesOHLCV>>>(
-            self.api_key()?,
-            APIEndpoint::DerIndicesOHLCV, unit,
-            vec![Param::Instrument { v: instrument, }, Param::Limit { v: limit, }, Param::ToTimestamp { v: to_timestamp, }, Param::Market { v: market.to_string(), }],
-            None
-        ).await
+        self.send(&DerIndicesOHLCVRequest { to_timestamp, limit, market, unit, ..DerIndicesOHLCVRequest::new(instrument) }).await
+    }
+
+    /// # Historical OHLCV+ \[Day, Hour, Minute\] (Derivatives Indices) - Stream
+    /// Returns a stream of pages between `from` and `to`, paginating over [`get_der_indices_ohlcv`](Self::get_der_indices_ohlcv) (see [`stream_range`](Self::stream_range)).
+    ///
+    /// # Input
+    /// - `instrument`: Instrument symbol
+    /// - `from`: Initial timestamp from which the data will be extracted
+    /// - `to`: Final timestamp up to which the data will be extracted
+    /// - `market`: Market name
+    /// - `unit`: Unit of the interval between successive data points
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    ///
+    /// use futures::StreamExt;
+    /// use ccdata_api::{CoinDesk, Unit, DerIndicesMarket};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> () {
+    ///
+    ///     let mut backend: CoinDesk = CoinDesk::new();
+    ///     // Provide API key as the environment variable called API_KEY
+    ///     backend.build(&"API_KEY").unwrap();
+    ///
+    ///     let mut pages = Box::pin(backend.get_der_indices_ohlcv_stream("BTCUSDT", 1722470400, 1728860400, DerIndicesMarket::BINANCE, Unit::Minute));
+    ///     let page = pages.next().await.unwrap().unwrap();
+    ///     assert!(page.windows(2).all(|v| v[0].timestamp < v[1].timestamp ));
+    ///
+    /// }
+    /// ```
+    pub fn get_der_indices_ohlcv_stream<'a>(&'a self, instrument: &str, from: impl IntoTimestamp, to: impl IntoTimestamp, market: DerIndicesMarket, unit: Unit) -> impl Stream<Item = Result<Vec<DerIndicesOHLCV>, Error>> + 'a {
+        self.stream_range(&DerIndicesOHLCVRequest { market, unit, ..DerIndicesOHLCVRequest::new(instrument) }, from, to)
     }
 
     /// # Markets (Derivatives Indices)
@@ -696,13 +1472,7 @@ impl CoinDesk {
     /// }
     /// ```
     pub async fn get_der_indices_markets_v2(&self, markets: Vec<DerIndicesMarket>) -> Result<CoinDeskResponse<HashMap<String, DerIndicesMarkets>>, Error> {
-        let v: Vec<String> = markets.iter().map(|v| v.to_string() ).collect();
-        call_api_endpoint::<CoinDeskResponse<HashMap<String, DerIndicesMarkets>>>(
-            self.api_key()?,
-            APIEndpoint::DerIndicesMarketsV2, Unit::NA,
-            vec![Param::Markets { v, }],
-            None
-        ).await
+        self.send(&DerIndicesMarketsRequest::new(markets)).await
     }
 
     /// # Historical OHLCV+ (Swap) \[Day, Hour, Minute\] (On-Chain DEX)
@@ -742,12 +1512,41 @@ impl CoinDesk {
     /// }
     /// ```
     pub async fn get_ocdex_ohlcv(&self, instrument: &str, to_timestamp: Option<i64>, limit: Option<usize>, market: OCDEXMarket, unit: Unit) -> Result<CoinDeskResponse<Vec<OCDEXOHLCV>>, Error> {
-        call_api_endpoint::<CoinDeskResponse<Vec<OCDEXOHLCV>>>(
-            self.api_key()?,
-            APIEndpoint::OCDEXOHLCV, unit,
-            vec![Param::Instrument { v: instrument, }, Param::Limit { v: limit, }, Param::ToTimestamp { v: to_timestamp, }, Param::Market { v: market.to_string(), }],
-            None
-        ).await
+        self.send(&OCDEXOHLCVRequest { to_timestamp, limit, market, unit, ..OCDEXOHLCVRequest::new(instrument) }).await
+    }
+
+    /// # Historical OHLCV+ (Swap) \[Day, Hour, Minute\] (On-Chain DEX) - Stream
+    /// Returns a stream of pages between `from` and `to`, paginating over [`get_ocdex_ohlcv`](Self::get_ocdex_ohlcv) (see [`stream_range`](Self::stream_range)).
+    ///
+    /// # Input
+    /// - `instrument`: Instrument symbol
+    /// - `from`: Initial timestamp from which the data will be extracted
+    /// - `to`: Final timestamp up to which the data will be extracted
+    /// - `market`: Market name
+    /// - `unit`: Unit of the interval between successive data points
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    ///
+    /// use futures::StreamExt;
+    /// use ccdata_api::{CoinDesk, Unit, OCDEXMarket};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> () {
+    ///
+    ///     let mut backend: CoinDesk = CoinDesk::new();
+    ///     // Provide API key as the environment variable called API_KEY
+    ///     backend.build(&"API_KEY").unwrap();
+    ///
+    ///     let mut pages = Box::pin(backend.get_ocdex_ohlcv_stream("0x0d4a11d5eeaac28ec3f61d100daf4d40471f1852_2", 1722470400, 1728860400, OCDEXMarket::UNISWAPV2, Unit::Minute));
+    ///     let page = pages.next().await.unwrap().unwrap();
+    ///     assert!(page.windows(2).all(|v| v[0].timestamp < v[1].timestamp ));
+    ///
+    /// }
+    /// ```
+    pub fn get_ocdex_ohlcv_stream<'a>(&'a self, instrument: &str, from: impl IntoTimestamp, to: impl IntoTimestamp, market: OCDEXMarket, unit: Unit) -> impl Stream<Item = Result<Vec<OCDEXOHLCV>, Error>> + 'a {
+        self.stream_range(&OCDEXOHLCVRequest { market, unit, ..OCDEXOHLCVRequest::new(instrument) }, from, to)
     }
 
     /// # Markets (On-Chain DEX)
@@ -784,13 +1583,7 @@ impl CoinDesk {
     /// }
     /// ```
     pub async fn get_ocdex_markets_v2(&self, markets: Vec<OCDEXMarket>) -> Result<CoinDeskResponse<HashMap<String, OCDEXMarkets>>, Error> {
-        let v: Vec<String> = markets.iter().map(|v| v.to_string() ).collect();
-        call_api_endpoint::<CoinDeskResponse<HashMap<String, OCDEXMarkets>>>(
-            self.api_key()?,
-            APIEndpoint::OCDEXMarketsV2, Unit::NA,
-            vec![Param::Markets { v, }],
-            None
-        ).await
+        self.send(&OCDEXMarketsRequest::new(markets)).await
     }
 
     /// # ETH Blocks \[Full Processed\] (On-Chain Core)
@@ -828,12 +1621,7 @@ impl CoinDesk {
     /// }
     /// ```
     pub async fn get_occore_eth_block(&self, block_number: i64) -> Result<CoinDeskResponse<OCCoreETHBlock>, Error> {
-        call_api_endpoint::<CoinDeskResponse<OCCoreETHBlock>>(
-            self.api_key()?,
-            APIEndpoint::OCCoreETHBlocks, Unit::NA,
-            vec![Param::OCCoreBlockNumber { v: block_number, }],
-            None
-        ).await
+        self.send(&OCCoreETHBlockRequest::new(block_number)).await
     }
 
     /// # Assets Summary By Chain (On-Chain Core)
@@ -867,12 +1655,7 @@ impl CoinDesk {
     /// }
     /// ```
     pub async fn get_occore_assets_by_chain(&self, chain_asset: &str) -> Result<CoinDeskResponse<OCCoreAssetByChain>, Error> {
-        call_api_endpoint::<CoinDeskResponse<OCCoreAssetByChain>>(
-            self.api_key()?,
-            APIEndpoint::OCCoreAssetsByChain, Unit::NA,
-            vec![Param::ChainAsset { v: chain_asset, }],
-            None
-        ).await
+        self.send(&OCCoreAssetsByChainRequest::new(chain_asset)).await
     }
 
     /// # Asset By Address Lookup (On-Chain Core)
@@ -910,33 +1693,91 @@ impl CoinDesk {
     ///
     /// }
     /// ```
-    pub async fn get_occore_asset_by_address(&self, chain_asset: &str, address: &str, quote_asset: &str) -> Result<CoinDeskResponse<OCCoreAssetByAddress>, Error> {
-        call_api_endpoint::<CoinDeskResponse<OCCoreAssetByAddress>>(
-            self.api_key()?,
-            APIEndpoint::OCCoreAssetByAddress, Unit::NA,
-            vec![Param::ChainAsset { v: chain_asset, }, Param::OCCoreAddress { v: address, }, Param::OCCoreQuoteAsset { v: quote_asset, }],
-            None
-        ).await
+    pub async fn get_occore_asset_by_address(&self, chain_asset: &str, address: &str, quote_asset: &str) -> Result<CoinDeskResponse<OCCoreAssetByAddress>, Error> {
+        self.send(&OCCoreAssetByAddressRequest { quote_asset: quote_asset.to_owned(), ..OCCoreAssetByAddressRequest::new(chain_asset, address) }).await
+    }
+
+    /// # Historical Supply Day (On-Chain Core)
+    /// Returns supply history for a given asset.
+    ///
+    /// # Description (CoinDesk Documentation)
+    /// The On-Chain Historical Supply Day endpoint retrieves comprehensive historical supply data for various digital assets identified by either their
+    /// CoinDesk asset ID or unique asset symbol. This endpoint offers a detailed view of an asset's supply dynamics on a daily basis, providing insights
+    /// into circulating supply, total issued supply, staked supply, burnt tokens, and more.
+    ///
+    /// # Input
+    /// - `asset`: Asset symbol
+    /// - `to_timestamp`: Final timestamp up to which the data will be extracted
+    /// - `limit`: Maximum number of datapoints per API endpoint call
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    ///
+    /// use ccdata_api::CoinDesk;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> () {
+    ///
+    ///     let mut backend: CoinDesk = CoinDesk::new();
+    ///     // Provide API key as the environment variable called API_KEY
+    ///     backend.build(&"API_KEY").unwrap();
+    ///
+    ///     let limit: usize = 2000;
+    ///     let historical_supply = backend.get_occore_supply("BTC", None, Some(limit)).await.unwrap();
+    ///     assert!(historical_supply.data.unwrap().len() <= limit);
+    ///
+    /// }
+    /// ```
+    pub async fn get_occore_supply(&self, asset: &str, to_timestamp: Option<i64>, limit: Option<usize>) -> Result<CoinDeskResponse<Vec<OCCoreSupply>>, Error> {
+        self.send(&OCCoreSupplyRequest { to_timestamp, limit, ..OCCoreSupplyRequest::new(asset) }).await
+    }
+
+    /// # Historical Supply Day (On-Chain Core) - Stream
+    /// Returns a stream of pages between `from` and `to`, paginating over [`get_occore_supply`](Self::get_occore_supply) (see [`stream_range`](Self::stream_range)).
+    ///
+    /// # Input
+    /// - `asset`: Asset symbol
+    /// - `from`: Initial timestamp from which the data will be extracted
+    /// - `to`: Final timestamp up to which the data will be extracted
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    ///
+    /// use futures::StreamExt;
+    /// use ccdata_api::{CoinDesk};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> () {
+    ///
+    ///     let mut backend: CoinDesk = CoinDesk::new();
+    ///     // Provide API key as the environment variable called API_KEY
+    ///     backend.build(&"API_KEY").unwrap();
+    ///
+    ///     let mut pages = Box::pin(backend.get_occore_supply_stream("BTC", 1577836800, 1728860400));
+    ///     let page = pages.next().await.unwrap().unwrap();
+    ///     assert!(page.windows(2).all(|v| v[0].timestamp < v[1].timestamp ));
+    ///
+    /// }
+    /// ```
+    pub fn get_occore_supply_stream<'a>(&'a self, asset: &str, from: impl IntoTimestamp, to: impl IntoTimestamp) -> impl Stream<Item = Result<Vec<OCCoreSupply>, Error>> + 'a {
+        self.stream_range(&OCCoreSupplyRequest::new(asset), from, to)
     }
 
-    /// # Historical Supply Day (On-Chain Core)
-    /// Returns supply history for a given asset.
-    ///
-    /// # Description (CoinDesk Documentation)
-    /// The On-Chain Historical Supply Day endpoint retrieves comprehensive historical supply data for various digital assets identified by either their
-    /// CoinDesk asset ID or unique asset symbol. This endpoint offers a detailed view of an asset's supply dynamics on a daily basis, providing insights
-    /// into circulating supply, total issued supply, staked supply, burnt tokens, and more.
+    /// # Historical Supply Day (On-Chain Core) - Time Range
+    /// Returns all data points between `from` and `to`, paginating over [`get_occore_supply`](Self::get_occore_supply) (see [`send_range`](Self::send_range)).
     ///
     /// # Input
     /// - `asset`: Asset symbol
-    /// - `to_timestamp`: Final timestamp up to which the data will be extracted
-    /// - `limit`: Maximum number of datapoints per API endpoint call
+    /// - `from`: Initial timestamp from which the data will be extracted
+    /// - `to`: Final timestamp up to which the data will be extracted
     ///
     /// # Examples
     ///
     /// ```rust
     ///
-    /// use ccdata_api::CoinDesk;
+    /// use ccdata_api::{CoinDesk};
     ///
     /// #[tokio::main]
     /// async fn main() -> () {
@@ -945,19 +1786,13 @@ impl CoinDesk {
     ///     // Provide API key as the environment variable called API_KEY
     ///     backend.build(&"API_KEY").unwrap();
     ///
-    ///     let limit: usize = 2000;
-    ///     let historical_supply = backend.get_occore_supply("BTC", None, Some(limit)).await.unwrap();
-    ///     assert!(historical_supply.data.unwrap().len() <= limit);
+    ///     let data = backend.get_occore_supply_range("BTC", 1577836800, 1728860400).await.unwrap();
+    ///     assert!(data.windows(2).all(|v| v[0].timestamp < v[1].timestamp ));
     ///
     /// }
     /// ```
-    pub async fn get_occore_supply(&self, asset: &str, to_timestamp: Option<i64>, limit: Option<usize>) -> Result<CoinDeskResponse<Vec<OCCoreSupply>>, Error> {
-        call_api_endpoint::<CoinDeskResponse<Vec<OCCoreSupply>>>(
-            self.api_key()?,
-            APIEndpoint::OCCoreSupply, Unit::NA,
-            vec![Param::Asset { v: asset, }, Param::ToTimestamp { v: to_timestamp, }, Param::Limit { v: limit, }],
-            None
-        ).await
+    pub async fn get_occore_supply_range(&self, asset: &str, from: impl IntoTimestamp, to: impl IntoTimestamp) -> Result<Vec<OCCoreSupply>, Error> {
+        self.send_range(&OCCoreSupplyRequest::new(asset), from, to).await
     }
 
     /// # Full Asset Metadata (Asset) - V2
@@ -993,12 +1828,7 @@ impl CoinDesk {
     /// }
     /// ```
     pub async fn get_asset_metadata_v2(&self, assets: Vec<String>) -> Result<CoinDeskResponse<HashMap<String, AssetMetadata>>, Error> {
-        call_api_endpoint::<CoinDeskResponse<HashMap<String, AssetMetadata>>>(
-            self.api_key()?,
-            APIEndpoint::AssetMetadataV2, Unit::NA,
-            vec![Param::Assets { v: assets, }],
-            None
-        ).await
+        self.send(&AssetMetadataRequest::new(assets)).await
     }
 
     /// # Significant Asset Events (Asset)
@@ -1033,12 +1863,7 @@ impl CoinDesk {
     /// }
     /// ```
     pub async fn get_asset_events(&self, asset: &str, to_timestamp: Option<i64>, limit: Option<usize>) -> Result<CoinDeskResponse<Vec<AssetEvent>>, Error> {
-        call_api_endpoint::<CoinDeskResponse<Vec<AssetEvent>>>(
-            self.api_key()?,
-            APIEndpoint::AssetEvents, Unit::NA,
-            vec![Param::Asset { v: asset, }, Param::ToTimestamp { v: to_timestamp, }, Param::Limit { v: limit, }],
-            None
-        ).await
+        self.send(&AssetEventsRequest { to_timestamp, limit, ..AssetEventsRequest::new(asset) }).await
     }
 
     /// # Historical Social \[Code Repository Day\] (Asset)
@@ -1073,12 +1898,37 @@ impl CoinDesk {
     /// }
     /// ```
     pub async fn get_asset_code_repo(&self, asset: &str, to_timestamp: Option<i64>, limit: Option<usize>) -> Result<CoinDeskResponse<Vec<AssetCodeRepoMetrics>>, Error> {
-        call_api_endpoint::<CoinDeskResponse<Vec<AssetCodeRepoMetrics>>>(
-            self.api_key()?,
-            APIEndpoint::AssetCodeRepo, Unit::NA,
-            vec![Param::Asset { v: asset, }, Param::ToTimestamp{ v: to_timestamp, }, Param::Limit { v: limit, }],
-            None
-        ).await
+        self.send(&AssetCodeRepoRequest { to_timestamp, limit, ..AssetCodeRepoRequest::new(asset) }).await
+    }
+
+    /// # Historical Social \[Code Repository Day\] (Asset) - Time Range
+    /// Returns all data points between `from` and `to`, paginating over [`get_asset_code_repo`](Self::get_asset_code_repo) (see [`send_range`](Self::send_range)).
+    ///
+    /// # Input
+    /// - `asset`: Asset symbol
+    /// - `from`: Initial timestamp from which the data will be extracted
+    /// - `to`: Final timestamp up to which the data will be extracted
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    ///
+    /// use ccdata_api::{CoinDesk};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> () {
+    ///
+    ///     let mut backend: CoinDesk = CoinDesk::new();
+    ///     // Provide API key as the environment variable called API_KEY
+    ///     backend.build(&"API_KEY").unwrap();
+    ///
+    ///     let data = backend.get_asset_code_repo_range("ETH", 1577836800, 1728860400).await.unwrap();
+    ///     assert!(data.windows(2).all(|v| v[0].timestamp < v[1].timestamp ));
+    ///
+    /// }
+    /// ```
+    pub async fn get_asset_code_repo_range(&self, asset: &str, from: impl IntoTimestamp, to: impl IntoTimestamp) -> Result<Vec<AssetCodeRepoMetrics>, Error> {
+        self.send_range(&AssetCodeRepoRequest::new(asset), from, to).await
     }
 
     /// # Historical Social \[Discord Day\] (Asset)
@@ -1113,12 +1963,37 @@ impl CoinDesk {
     /// }
     /// ```
     pub async fn get_asset_discord(&self, asset: &str, to_timestamp: Option<i64>, limit: Option<usize>) -> Result<CoinDeskResponse<Vec<AssetDiscord>>, Error> {
-        call_api_endpoint::<CoinDeskResponse<Vec<AssetDiscord>>>(
-            self.api_key()?,
-            APIEndpoint::AssetDiscord, Unit::NA,
-            vec![Param::Asset { v: asset, }, Param::ToTimestamp { v: to_timestamp, }, Param::Limit { v: limit, }],
-            None
-        ).await
+        self.send(&AssetDiscordRequest { to_timestamp, limit, ..AssetDiscordRequest::new(asset) }).await
+    }
+
+    /// # Historical Social \[Discord Day\] (Asset) - Time Range
+    /// Returns all data points between `from` and `to`, paginating over [`get_asset_discord`](Self::get_asset_discord) (see [`send_range`](Self::send_range)).
+    ///
+    /// # Input
+    /// - `asset`: Asset symbol
+    /// - `from`: Initial timestamp from which the data will be extracted
+    /// - `to`: Final timestamp up to which the data will be extracted
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    ///
+    /// use ccdata_api::{CoinDesk};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> () {
+    ///
+    ///     let mut backend: CoinDesk = CoinDesk::new();
+    ///     // Provide API key as the environment variable called API_KEY
+    ///     backend.build(&"API_KEY").unwrap();
+    ///
+    ///     let data = backend.get_asset_discord_range("ETH", 1577836800, 1728860400).await.unwrap();
+    ///     assert!(data.windows(2).all(|v| v[0].timestamp < v[1].timestamp ));
+    ///
+    /// }
+    /// ```
+    pub async fn get_asset_discord_range(&self, asset: &str, from: impl IntoTimestamp, to: impl IntoTimestamp) -> Result<Vec<AssetDiscord>, Error> {
+        self.send_range(&AssetDiscordRequest::new(asset), from, to).await
     }
 
     /// # Historical Social \[Reddit Day\] (Asset)
@@ -1154,12 +2029,37 @@ impl CoinDesk {
     /// }
     /// ```
     pub async fn get_asset_reddit(&self, asset: &str, to_timestamp: Option<i64>, limit: Option<usize>) -> Result<CoinDeskResponse<Vec<AssetReddit>>, Error> {
-        call_api_endpoint::<CoinDeskResponse<Vec<AssetReddit>>>(
-            self.api_key()?,
-            APIEndpoint::AssetReddit, Unit::NA,
-            vec![Param::Asset { v: asset, }, Param::ToTimestamp { v: to_timestamp, }, Param::Limit { v: limit, }],
-            None
-        ).await
+        self.send(&AssetRedditRequest { to_timestamp, limit, ..AssetRedditRequest::new(asset) }).await
+    }
+
+    /// # Historical Social \[Reddit Day\] (Asset) - Time Range
+    /// Returns all data points between `from` and `to`, paginating over [`get_asset_reddit`](Self::get_asset_reddit) (see [`send_range`](Self::send_range)).
+    ///
+    /// # Input
+    /// - `asset`: Asset symbol
+    /// - `from`: Initial timestamp from which the data will be extracted
+    /// - `to`: Final timestamp up to which the data will be extracted
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    ///
+    /// use ccdata_api::{CoinDesk};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> () {
+    ///
+    ///     let mut backend: CoinDesk = CoinDesk::new();
+    ///     // Provide API key as the environment variable called API_KEY
+    ///     backend.build(&"API_KEY").unwrap();
+    ///
+    ///     let data = backend.get_asset_reddit_range("ETH", 1577836800, 1728860400).await.unwrap();
+    ///     assert!(data.windows(2).all(|v| v[0].timestamp < v[1].timestamp ));
+    ///
+    /// }
+    /// ```
+    pub async fn get_asset_reddit_range(&self, asset: &str, from: impl IntoTimestamp, to: impl IntoTimestamp) -> Result<Vec<AssetReddit>, Error> {
+        self.send_range(&AssetRedditRequest::new(asset), from, to).await
     }
 
     /// # Historical Social \[Telegram Day\] (Asset)
@@ -1194,12 +2094,37 @@ impl CoinDesk {
     /// }
     /// ```
     pub async fn get_asset_telegram(&self, asset: &str, to_timestamp: Option<i64>, limit: Option<usize>) -> Result<CoinDeskResponse<Vec<AssetTelegram>>, Error> {
-        call_api_endpoint::<CoinDeskResponse<Vec<AssetTelegram>>>(
-            self.api_key()?,
-            APIEndpoint::AssetTelegram, Unit::NA,
-            vec![Param::Asset { v: asset, }, Param::ToTimestamp { v: to_timestamp, }, Param::Limit { v: limit, }],
-            None
-        ).await
+        self.send(&AssetTelegramRequest { to_timestamp, limit, ..AssetTelegramRequest::new(asset) }).await
+    }
+
+    /// # Historical Social \[Telegram Day\] (Asset) - Time Range
+    /// Returns all data points between `from` and `to`, paginating over [`get_asset_telegram`](Self::get_asset_telegram) (see [`send_range`](Self::send_range)).
+    ///
+    /// # Input
+    /// - `asset`: Asset symbol
+    /// - `from`: Initial timestamp from which the data will be extracted
+    /// - `to`: Final timestamp up to which the data will be extracted
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    ///
+    /// use ccdata_api::{CoinDesk};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> () {
+    ///
+    ///     let mut backend: CoinDesk = CoinDesk::new();
+    ///     // Provide API key as the environment variable called API_KEY
+    ///     backend.build(&"API_KEY").unwrap();
+    ///
+    ///     let data = backend.get_asset_telegram_range("SOL", 1577836800, 1728860400).await.unwrap();
+    ///     assert!(data.windows(2).all(|v| v[0].timestamp < v[1].timestamp ));
+    ///
+    /// }
+    /// ```
+    pub async fn get_asset_telegram_range(&self, asset: &str, from: impl IntoTimestamp, to: impl IntoTimestamp) -> Result<Vec<AssetTelegram>, Error> {
+        self.send_range(&AssetTelegramRequest::new(asset), from, to).await
     }
 
     /// # Historical Social \[X (Twitter) Day\] (Asset)
@@ -1234,12 +2159,37 @@ impl CoinDesk {
     /// }
     /// ```
     pub async fn get_asset_twitter(&self, asset: &str, to_timestamp: Option<i64>, limit: Option<usize>) -> Result<CoinDeskResponse<Vec<AssetTwitter>>, Error> {
-        call_api_endpoint::<CoinDeskResponse<Vec<AssetTwitter>>>(
-            self.api_key()?,
-            APIEndpoint::AssetTwitter, Unit::NA,
-            vec![Param::Asset { v: asset, }, Param::ToTimestamp { v: to_timestamp, }, Param::Limit { v: limit, }],
-            None
-        ).await
+        self.send(&AssetTwitterRequest { to_timestamp, limit, ..AssetTwitterRequest::new(asset) }).await
+    }
+
+    /// # Historical Social \[X (Twitter) Day\] (Asset) - Time Range
+    /// Returns all data points between `from` and `to`, paginating over [`get_asset_twitter`](Self::get_asset_twitter) (see [`send_range`](Self::send_range)).
+    ///
+    /// # Input
+    /// - `asset`: Asset symbol
+    /// - `from`: Initial timestamp from which the data will be extracted
+    /// - `to`: Final timestamp up to which the data will be extracted
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    ///
+    /// use ccdata_api::{CoinDesk};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> () {
+    ///
+    ///     let mut backend: CoinDesk = CoinDesk::new();
+    ///     // Provide API key as the environment variable called API_KEY
+    ///     backend.build(&"API_KEY").unwrap();
+    ///
+    ///     let data = backend.get_asset_twitter_range("SOL", 1577836800, 1728860400).await.unwrap();
+    ///     assert!(data.windows(2).all(|v| v[0].timestamp < v[1].timestamp ));
+    ///
+    /// }
+    /// ```
+    pub async fn get_asset_twitter_range(&self, asset: &str, from: impl IntoTimestamp, to: impl IntoTimestamp) -> Result<Vec<AssetTwitter>, Error> {
+        self.send_range(&AssetTwitterRequest::new(asset), from, to).await
     }
 
     /// # Latest Articles (News)
@@ -1282,15 +2232,7 @@ impl CoinDesk {
     /// ```
     pub async fn get_news_latest_articles(&self, language: NewsLang, source_id: NewsSourceID, categories: Option<Vec<String>>,
                                           exclude_categories: Option<Vec<String>>, to_timestamp: Option<i64>, limit: Option<usize>) -> Result<CoinDeskResponse<Vec<NewsLatestArticle>>, Error> {
-        call_api_endpoint::<CoinDeskResponse<Vec<NewsLatestArticle>>>(
-            self.api_key()?,
-            APIEndpoint::NewsLatestArticles, Unit::NA,
-            vec![
-                Param::NewsLanguage { v: language, }, Param::NewsSourceID { v: source_id, }, Param::NewsCategories { v: categories, },
-                Param::NewsExcludeCategories { v: exclude_categories, }, Param::ToTimestamp { v: to_timestamp, }, Param::Limit { v: limit, },
-            ],
-            None
-        ).await
+        self.send(&NewsLatestArticlesRequest { language, categories, exclude_categories, to_timestamp, limit, ..NewsLatestArticlesRequest::new(source_id) }).await
     }
 
     /// # Sources (News)
@@ -1328,12 +2270,7 @@ impl CoinDesk {
     /// }
     /// ```
     pub async fn get_news_sources(&self, language: NewsLang, source_type: NewsSourceType, status: NewsStatus) -> Result<CoinDeskResponse<Vec<NewsSource>>, Error> {
-        call_api_endpoint::<CoinDeskResponse<Vec<NewsSource>>>(
-            self.api_key()?,
-            APIEndpoint::NewsSources, Unit::NA,
-            vec![Param::NewsLanguage { v: language, }, Param::NewsSourceType { v: source_type, }, Param::NewsStatus { v: status, }],
-            None
-        ).await
+        self.send(&NewsSourcesRequest { language, source_type, status, }).await
     }
 
     /// # Categories (News)
@@ -1368,12 +2305,7 @@ impl CoinDesk {
     /// }
     /// ```
     pub async fn get_news_categories(&self, status: NewsStatus) -> Result<CoinDeskResponse<Vec<NewsCategory>>, Error> {
-        call_api_endpoint::<CoinDeskResponse<Vec<NewsCategory>>>(
-            self.api_key()?,
-            APIEndpoint::NewsCategories, Unit::NA,
-            vec![Param::NewsStatus { v: status, }],
-            None
-        ).await
+        self.send(&NewsCategoriesRequest { status, }).await
     }
 
     /// # MktCap Historical OHLCV \[All Assets Day\] (Overview)
@@ -1409,12 +2341,67 @@ impl CoinDesk {
     /// }
     /// ```
     pub async fn get_overview_mktcap_ohlcv(&self, to_timestamp: Option<i64>, limit: Option<usize>) -> Result<CoinDeskResponse<Vec<OverviewMktCapOHLCV>>, Error> {
-        call_api_endpoint::<CoinDeskResponse<Vec<OverviewMktCapOHLCV>>>(
-            self.api_key()?,
-            APIEndpoint::OverviewMktCapOHLCV, Unit::NA,
-            vec![Param::ToTimestamp { v: to_timestamp, }, Param::Limit { v: limit, }],
-            None
-        ).await
+        self.send(&OverviewMktCapOHLCVRequest { to_timestamp, limit, ..OverviewMktCapOHLCVRequest::new() }).await
+    }
+
+    /// # MktCap Historical OHLCV \[All Assets Day\] (Overview) - Time Range
+    /// Returns all data points between `from` and `to`, paginating over [`get_overview_mktcap_ohlcv`](Self::get_overview_mktcap_ohlcv) (see [`send_range`](Self::send_range)).
+    ///
+    /// # Input
+    /// - `from`: Initial timestamp from which the data will be extracted
+    /// - `to`: Final timestamp up to which the data will be extracted
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    ///
+    /// use ccdata_api::{CoinDesk};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> () {
+    ///
+    ///     let mut backend: CoinDesk = CoinDesk::new();
+    ///     // Provide API key as the environment variable called API_KEY
+    ///     backend.build(&"API_KEY").unwrap();
+    ///
+    ///     let data = backend.get_overview_mktcap_ohlcv_range(1577836800, 1728860400).await.unwrap();
+    ///     assert!(data.windows(2).all(|v| v[0].timestamp < v[1].timestamp ));
+    ///
+    /// }
+    /// ```
+    pub async fn get_overview_mktcap_ohlcv_range(&self, from: impl IntoTimestamp, to: impl IntoTimestamp) -> Result<Vec<OverviewMktCapOHLCV>, Error> {
+        self.send_range(&OverviewMktCapOHLCVRequest::new(), from, to).await
+    }
+
+    /// # MktCap Historical OHLCV \[All Assets Day\] (Overview) - Stream
+    /// Returns a stream of pages between `from` and `to`, paginating over [`get_overview_mktcap_ohlcv`](Self::get_overview_mktcap_ohlcv) (see [`stream_range`](Self::stream_range)).
+    ///
+    /// # Input
+    /// - `from`: Initial timestamp from which the data will be extracted
+    /// - `to`: Final timestamp up to which the data will be extracted
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    ///
+    /// use futures::StreamExt;
+    /// use ccdata_api::{CoinDesk};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> () {
+    ///
+    ///     let mut backend: CoinDesk = CoinDesk::new();
+    ///     // Provide API key as the environment variable called API_KEY
+    ///     backend.build(&"API_KEY").unwrap();
+    ///
+    ///     let mut pages = Box::pin(backend.get_overview_mktcap_ohlcv_stream(1577836800, 1728860400));
+    ///     let page = pages.next().await.unwrap().unwrap();
+    ///     assert!(page.windows(2).all(|v| v[0].timestamp < v[1].timestamp ));
+    ///
+    /// }
+    /// ```
+    pub fn get_overview_mktcap_ohlcv_stream<'a>(&'a self, from: impl IntoTimestamp, to: impl IntoTimestamp) -> impl Stream<Item = Result<Vec<OverviewMktCapOHLCV>, Error>> + 'a {
+        self.stream_range(&OverviewMktCapOHLCVRequest::new(), from, to)
     }
 }
 
@@ -1430,4 +2417,588 @@ mod tests {
         backend.build(&"API_KEY").unwrap();
         assert!(backend.api_key != None);
     }
-}
\ No newline at end of file
+
+    #[tokio::test]
+    async fn unit_test_base_url_override() -> () {
+        use crate::{Unit, SpotMarket};
+        use crate::test_utils::{MockServer, MockResponse, mock_backend};
+        let server: MockServer = MockServer::start(vec![MockResponse::ok(include_str!("../tests/fixtures/spot_ohlcv.json"))]).await;
+        let backend: CoinDesk = mock_backend(&server);
+        let ohlcv = backend.get_spot_ohlcv("BTC-USD", None, Some(2), SpotMarket::KRAKEN, Unit::Day).await.unwrap();
+        assert_eq!(ohlcv.data.unwrap().len(), 2);
+        assert!(server.requests()[0].starts_with("GET /spot/v1/historical/days?"));
+    }
+
+    #[tokio::test]
+    async fn unit_test_auth_method() -> () {
+        use crate::{AuthMethod, NewsStatus};
+        use crate::test_utils::{MockServer, MockResponse, mock_backend};
+        let server: MockServer = MockServer::start(vec![MockResponse::ok("{\"Data\":[]}"), MockResponse::ok("{\"Data\":[]}")]).await;
+        let mut backend: CoinDesk = mock_backend(&server);
+        // Header (Default)
+        backend.get_news_categories(NewsStatus::ACTIVE).await.unwrap();
+        let request: String = server.requests()[0].to_lowercase();
+        assert!(request.contains("authorization: apikey xxxxxxx"));
+        assert!(!request.contains("api_key="));
+        // Query string
+        backend.update_auth_method(AuthMethod::QueryString);
+        backend.get_news_categories(NewsStatus::ACTIVE).await.unwrap();
+        let request: String = server.requests()[1].to_lowercase();
+        assert!(request.starts_with("get /news/v1/category/list?api_key=xxxxxxx&status=active"));
+        assert!(!request.contains("authorization"));
+    }
+
+    #[tokio::test]
+    async fn unit_test_api_key_redaction() -> () {
+        use crate::{BaseURL, AuthMethod, NewsStatus};
+        let mut backend: CoinDesk = CoinDesk::new();
+        backend.update_api_key(String::from("xxxxxxx"));
+        backend.update_auth_method(AuthMethod::QueryString);
+        // Nothing listens on port 9 (discard), so the request fails with the URL in the error
+        backend.update_base_url(BaseURL { data_api: String::from("http://127.0.0.1:9"), ..Default::default() });
+        let error: String = backend.get_news_categories(NewsStatus::ACTIVE).await.unwrap_err().to_string();
+        assert!(error.contains("api_key=REDACTED"));
+        assert!(!error.contains("xxxxxxx"));
+    }
+
+    #[tokio::test]
+    async fn unit_test_retry_policy() -> () {
+        use std::{sync::{Arc, Mutex}, time::Duration};
+        use crate::{NewsStatus, RetryPolicy, RetryEvent, RetryReason};
+        use crate::test_utils::{MockServer, MockResponse, mock_backend};
+        let server: MockServer = MockServer::start(vec![
+            MockResponse::new(503, "<html>Service Unavailable</html>"),
+            MockResponse::new(429, "").header("Retry-After", "0"),
+            MockResponse::ok("{\"Data\":[]}"),
+        ]).await;
+        let events: Arc<Mutex<Vec<RetryEvent>>> = Arc::new(Mutex::new(Vec::new()));
+        let events_: Arc<Mutex<Vec<RetryEvent>>> = events.clone();
+        let policy: RetryPolicy = RetryPolicy { base_delay: Duration::from_millis(1), ..Default::default() }
+            .with_on_retry(Arc::new(move |event: &RetryEvent| events_.lock().unwrap().push(event.clone()) ));
+        let mut backend: CoinDesk = mock_backend(&server);
+        backend.update_retry_policy(Some(policy));
+        let categories = backend.get_news_categories(NewsStatus::ACTIVE).await.unwrap();
+        assert_eq!(categories.data.unwrap().len(), 0);
+        assert_eq!(server.requests().len(), 3);
+        let events: Vec<RetryEvent> = events.lock().unwrap().clone();
+        assert_eq!(events.iter().map(|v| v.reason ).collect::<Vec<RetryReason>>(), vec![RetryReason::Status(503), RetryReason::Status(429)]);
+        assert_eq!(events[1].delay, Duration::ZERO);
+    }
+
+    #[tokio::test]
+    async fn unit_test_rate_limiter() -> () {
+        use std::{sync::Arc, time::{Duration, Instant}};
+        use crate::{NewsStatus, RateLimiter, RateLimitWindow};
+        use crate::test_utils::{MockServer, MockResponse, mock_backend};
+        let server: MockServer = MockServer::start(vec![
+            MockResponse::ok("{\"Data\":[]}").header("X-RateLimit-Remaining-Second", "0"),
+            MockResponse::ok("{\"Data\":[]}"),
+        ]).await;
+        let rate_limiter: Arc<RateLimiter> = Arc::new(RateLimiter::new().with_limit(RateLimitWindow::Second, 10));
+        let mut backend: CoinDesk = mock_backend(&server);
+        backend.update_rate_limiter(Some(rate_limiter.clone()));
+        let start: Instant = Instant::now();
+        backend.get_news_categories(NewsStatus::ACTIVE).await.unwrap();
+        // The response reported that no calls remain in the current second, so the second call is delayed
+        backend.get_news_categories(NewsStatus::ACTIVE).await.unwrap();
+        assert!(Duration::from_millis(90) <= start.elapsed());
+        assert_eq!(server.requests().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn unit_test_status_errors() -> () {
+        use std::time::Duration;
+        use crate::{Unit, SpotMarket, NewsStatus};
+        use crate::error::Error;
+        use crate::test_utils::{MockServer, MockResponse, mock_backend};
+        let not_found: &str = "{\"Data\":[],\"Err\":{\"type\":2,\"message\":\"No data for the requested range\",\"other_info\":{\"first\":1279324800}}}";
+        let server: MockServer = MockServer::start(vec![
+            MockResponse::new(401, "{\"Data\":{},\"Err\":{\"type\":1,\"message\":\"Invalid API key\"}}"),
+            MockResponse::new(404, not_found),
+            MockResponse::new(429, "").header("Retry-After", "3"),
+            MockResponse::new(502, "<html>Bad Gateway</html>"),
+            MockResponse::new(400, "{\"Data\":{},\"Err\":{\"type\":5,\"message\":\"Invalid market\"}}"),
+        ]).await;
+        let backend: CoinDesk = mock_backend(&server);
+        let error: Error = backend.get_news_categories(NewsStatus::ACTIVE).await.unwrap_err();
+        assert!(matches!(error, Error::Unauthorized(Some(e)) if e.type_ == 1));
+        let error: Error = backend.get_spot_ohlcv("BTC-USD", Some(0), None, SpotMarket::KRAKEN, Unit::Day).await.unwrap_err();
+        assert!(matches!(error, Error::NotFound(Some(e)) if e.other_info.as_ref().unwrap().first == Some(1279324800)));
+        let error: Error = backend.get_news_categories(NewsStatus::ACTIVE).await.unwrap_err();
+        assert!(matches!(error, Error::RateLimited { retry_after: Some(v) } if v == Duration::from_secs(3)));
+        let error: Error = backend.get_news_categories(NewsStatus::ACTIVE).await.unwrap_err();
+        assert!(matches!(error, Error::Http { status: 502, .. }));
+        let error: Error = backend.get_news_categories(NewsStatus::ACTIVE).await.unwrap_err();
+        assert!(matches!(error, Error::Api(e) if e.message == "Invalid market"));
+    }
+
+    #[tokio::test]
+    async fn unit_test_empty_object_fields() -> () {
+        use crate::{NewsLang, NewsSourceID, SpotMarket};
+        use crate::test_utils::{MockServer, MockResponse, mock_backend};
+        let server: MockServer = MockServer::start(vec![
+            MockResponse::ok(include_str!("../tests/fixtures/news_latest_articles.json")),
+            MockResponse::ok(include_str!("../tests/fixtures/spot_instrument_metadata.json")),
+        ]).await;
+        let backend: CoinDesk = mock_backend(&server);
+        // Empty objects inside the strings are not modified
+        let articles = backend.get_news_latest_articles(NewsLang::EN, NewsSourceID::CoinDesk, None, None, None, Some(1)).await.unwrap();
+        assert!(articles.error.is_none());
+        let article = &articles.data.unwrap()[0];
+        assert_eq!(article.title, "Developers debate empty {} payloads");
+        assert_eq!(article.body, "The node returned {} for the request, while the legacy endpoint returned {\"result\": {}} instead.");
+        assert_eq!(article.keywords, "BTC|{}");
+        let metadata = backend.get_spot_instrument_metadata(&vec![String::from("XXBTZUSD")], SpotMarket::KRAKEN).await.unwrap();
+        assert!(metadata.error.is_none());
+        let external_data: &str = &metadata.data.unwrap()["XXBTZUSD"].instrument_external_data;
+        assert!(external_data.contains("\"margin\":{}"));
+        let external_data: serde_json::Value = serde_json::from_str(external_data).unwrap();
+        assert_eq!(external_data["altname"], "XBTUSD");
+    }
+
+    #[tokio::test]
+    async fn unit_test_response_cache() -> () {
+        use std::sync::Arc;
+        use crate::{APIEndpoint, Unit, SpotMarket, NewsStatus};
+        use crate::cache::{CachePolicy, MemoryCache};
+        use crate::test_utils::{MockServer, MockResponse, mock_backend};
+        let server: MockServer = MockServer::start(vec![
+            MockResponse::ok(include_str!("../tests/fixtures/spot_ohlcv.json")),
+            MockResponse::ok("{\"Data\":[]}"),
+            MockResponse::ok("{\"Data\":[]}"),
+        ]).await;
+        let cache: Arc<MemoryCache> = Arc::new(MemoryCache::new(10));
+        let mut backend: CoinDesk = mock_backend(&server);
+        backend.update_cache(Some(cache.clone()));
+        backend.update_cache_policy(CachePolicy::default().with_endpoint_ttl(&APIEndpoint::NewsCategories, &Unit::NA, None));
+        let ohlcv = backend.get_spot_ohlcv("BTC-USD", Some(1728777600), Some(2), SpotMarket::KRAKEN, Unit::Day).await.unwrap();
+        // The API key is not a part of the cache key, so the cached response is reused by a backend with a different API key
+        backend.update_api_key(String::from("yyyyyyy"));
+        let cached_ohlcv = backend.get_spot_ohlcv("BTC-USD", Some(1728777600), Some(2), SpotMarket::KRAKEN, Unit::Day).await.unwrap();
+        assert_eq!(ohlcv.data.unwrap().len(), cached_ohlcv.data.unwrap().len());
+        assert_eq!(server.requests().len(), 1);
+        assert_eq!(cache.len(), 1);
+        // Caching is disabled for the endpoint
+        backend.get_news_categories(NewsStatus::ACTIVE).await.unwrap();
+        backend.get_news_categories(NewsStatus::ACTIVE).await.unwrap();
+        assert_eq!(server.requests().len(), 3);
+        assert_eq!(cache.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn unit_test_cassette_record_replay() -> () {
+        use crate::{Unit, SpotMarket};
+        use crate::cassette::{Cassette, CassetteMode};
+        use crate::test_utils::{MockServer, MockResponse, mock_backend};
+        let directory = std::env::temp_dir().join(format!("ccdata_api_cassettes_{}", std::process::id()));
+        let server: MockServer = MockServer::start(vec![MockResponse::ok(include_str!("../tests/fixtures/spot_ohlcv.json"))]).await;
+        // Record
+        let mut backend: CoinDesk = mock_backend(&server);
+        backend.update_api_key(String::from("secret_api_key"));
+        backend.update_cassette(Some(Cassette::new(CassetteMode::Record, &directory).unwrap()));
+        let recorded = backend.get_spot_ohlcv("BTC-USD", Some(1728777600), Some(2), SpotMarket::KRAKEN, Unit::Day).await.unwrap();
+        let files: Vec<String> = std::fs::read_dir(&directory).unwrap().map(|v| std::fs::read_to_string(v.unwrap().path()).unwrap() ).collect();
+        assert_eq!(files.len(), 1);
+        assert!(!files[0].contains("secret_api_key"));
+        // Replay without the API key and the network
+        let mut backend: CoinDesk = CoinDesk::new();
+        backend.update_cassette(Some(Cassette::new(CassetteMode::Replay, &directory).unwrap()));
+        let replayed = backend.get_spot_ohlcv("BTC-USD", Some(1728777600), Some(2), SpotMarket::KRAKEN, Unit::Day).await.unwrap();
+        assert_eq!(recorded.data.unwrap().len(), replayed.data.unwrap().len());
+        assert_eq!(server.requests().len(), 1);
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[cfg(feature = "tracing")]
+    #[tokio::test]
+    async fn unit_test_tracing_span() -> () {
+        use crate::{AuthMethod, NewsStatus};
+        use tracing_subscriber::layer::SubscriberExt;
+        use crate::test_utils::{MockServer, MockResponse, RecordingLayer, mock_backend};
+        let server: MockServer = MockServer::start(vec![MockResponse::new(503, ""), MockResponse::ok("{\"Data\":[]}")]).await;
+        let mut backend: CoinDesk = mock_backend(&server);
+        backend.update_api_key(String::from("secret_api_key"));
+        backend.update_auth_method(AuthMethod::QueryString);
+        backend.update_retry_policy(Some(crate::RetryPolicy { base_delay: std::time::Duration::from_millis(1), ..Default::default() }));
+        let layer: RecordingLayer = RecordingLayer::default();
+        let _guard = tracing::subscriber::set_default(tracing_subscriber::registry().with(layer.clone()));
+        backend.get_news_categories(NewsStatus::ACTIVE).await.unwrap();
+        let fields: Vec<String> = layer.fields(tracing::Level::INFO);
+        assert!(fields.contains(&format!("endpoint={}/news/v1/category/list", crate::DATA_API_URL)));
+        assert!(fields.contains(&String::from("params=status=ACTIVE")));
+        assert!(fields.contains(&String::from("status=200")));
+        assert!(fields.contains(&String::from("body_size=11")));
+        assert!(fields.contains(&String::from("retries=1")));
+        assert!(fields.iter().any(|v| v.starts_with("latency_ms=") ));
+        assert!(!fields.iter().any(|v| v.contains("secret_api_key") ));
+        // Response body is only logged at the TRACE level
+        assert!(!fields.iter().any(|v| v.starts_with("body=") ));
+        assert!(layer.fields(tracing::Level::TRACE).contains(&String::from("body={\"Data\":[]}")));
+    }
+
+    #[tokio::test]
+    async fn unit_test_middleware() -> () {
+        use std::sync::{Arc, Mutex};
+        use reqwest::{Request, StatusCode, header::HeaderValue};
+        use crate::{NewsStatus, RetryPolicy};
+        use crate::error::Error;
+        use crate::middleware::{Middleware, MiddlewareFuture};
+        use crate::utils::RawResponse;
+        use crate::test_utils::{MockServer, MockResponse, mock_backend};
+
+        #[derive(Default)]
+        struct Recorder { statuses: Mutex<Vec<u16>>, }
+
+        impl Middleware for Recorder {
+            fn before_request<'a>(&'a self, request: &'a mut Request) -> MiddlewareFuture<'a> {
+                Box::pin(async move {
+                    tokio::task::yield_now().await;
+                    request.headers_mut().insert("X-Middleware", HeaderValue::from_static("recorder"));
+                    Ok(())
+                })
+            }
+
+            fn after_response<'a>(&'a self, response: &'a mut RawResponse) -> MiddlewareFuture<'a> {
+                Box::pin(async move {
+                    let mut statuses = self.statuses.lock().unwrap();
+                    statuses.push(response.status.as_u16());
+                    // Inject a fault into the first response
+                    if statuses.len() == 1 { response.status = StatusCode::SERVICE_UNAVAILABLE }
+                    Ok::<(), Error>(())
+                })
+            }
+        }
+
+        let server: MockServer = MockServer::start(vec![MockResponse::ok("{\"Data\":[]}"), MockResponse::ok("{\"Data\":[]}")]).await;
+        let recorder: Arc<Recorder> = Arc::new(Recorder::default());
+        let mut backend: CoinDesk = mock_backend(&server);
+        backend.update_retry_policy(Some(RetryPolicy { base_delay: std::time::Duration::from_millis(1), ..Default::default() }));
+        backend.add_middleware(recorder.clone());
+        backend.get_news_categories(NewsStatus::ACTIVE).await.unwrap();
+        // The injected fault is retried, and the header is added to every attempt
+        assert_eq!(*recorder.statuses.lock().unwrap(), vec![200, 200]);
+        assert!(server.requests().iter().all(|v| v.to_lowercase().contains("x-middleware: recorder") ));
+    }
+
+    #[tokio::test]
+    async fn unit_test_middleware_cache_and_cassette() -> () {
+        use std::sync::{Arc, atomic::{AtomicUsize, Ordering}};
+        use reqwest::Request;
+        use crate::{NewsStatus, DATA_API_URL};
+        use crate::cache::{Cache, MemoryCache};
+        use crate::cassette::{Cassette, CassetteMode};
+        use crate::middleware::{Middleware, MiddlewareFuture};
+        use crate::utils::RawResponse;
+        use crate::test_utils::{MockServer, MockResponse, mock_backend};
+
+        #[derive(Default)]
+        struct Tagger { responses: AtomicUsize, }
+
+        impl Middleware for Tagger {
+            fn before_request<'a>(&'a self, request: &'a mut Request) -> MiddlewareFuture<'a> {
+                Box::pin(async move {
+                    request.url_mut().query_pairs_mut().append_pair("source", "tagger");
+                    Ok(())
+                })
+            }
+
+            fn after_response<'a>(&'a self, response: &'a mut RawResponse) -> MiddlewareFuture<'a> {
+                Box::pin(async move {
+                    self.responses.fetch_add(1, Ordering::Relaxed);
+                    response.body = response.body.replace("[]", "null");
+                    Ok(())
+                })
+            }
+        }
+
+        let directory = std::env::temp_dir().join(format!("ccdata_api_middleware_cassettes_{}", std::process::id()));
+        let server: MockServer = MockServer::start(vec![MockResponse::ok("{\"Data\":[]}")]).await;
+        let tagger: Arc<Tagger> = Arc::new(Tagger::default());
+        let cache: Arc<MemoryCache> = Arc::new(MemoryCache::new(10));
+        let mut backend: CoinDesk = mock_backend(&server);
+        backend.update_cache(Some(cache.clone()));
+        backend.update_cassette(Some(Cassette::new(CassetteMode::Record, &directory).unwrap()));
+        backend.add_middleware(tagger.clone());
+        let response = backend.get_news_categories(NewsStatus::ACTIVE).await.unwrap();
+        let cached_response = backend.get_news_categories(NewsStatus::ACTIVE).await.unwrap();
+        assert!(response.data.is_none() && cached_response.data.is_none());
+        assert_eq!(server.requests().len(), 1);
+        // The cache key is computed from the modified request, and the cache stores the received response
+        let key: String = format!("{}/news/v1/category/list?source=tagger&status=ACTIVE", DATA_API_URL);
+        assert_eq!(cache.get(&key), Some(String::from("{\"Data\":[]}")));
+        // The hooks are applied to the cached and the replayed responses
+        backend.update_cache(None);
+        backend.update_cassette(Some(Cassette::new(CassetteMode::Replay, &directory).unwrap()));
+        let replayed_response = backend.get_news_categories(NewsStatus::ACTIVE).await.unwrap();
+        assert!(replayed_response.data.is_none());
+        assert_eq!(tagger.responses.load(Ordering::Relaxed), 3);
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[tokio::test]
+    async fn unit_test_custom_api_endpoint() -> () {
+        use crate::{CoinDeskResponse, APIEndpointTrait, Group, Param, Unit, DATA_API_URL};
+        use crate::test_utils::{MockServer, MockResponse, mock_backend};
+
+        struct CustomSpotOHLCV;
+
+        impl APIEndpointTrait for CustomSpotOHLCV {
+            fn default_groups(&self) -> Option<Vec<Group>> { Some(vec![Group::OHLC]) }
+
+            fn default_params(&self) -> Option<Vec<Param<'_>>> { Some(vec![Param::Market { v: String::from("kraken"), }]) }
+
+            fn url(&self, unit: &Unit) -> String { format!("{DATA_API_URL}/spot/v1/historical{}", unit) }
+        }
+
+        let server: MockServer = MockServer::start(vec![MockResponse::ok(include_str!("../tests/fixtures/spot_ohlcv.json"))]).await;
+        let backend: CoinDesk = mock_backend(&server);
+        let ohlcv: CoinDeskResponse<Vec<serde_json::Value>> = backend.call_api_endpoint(CustomSpotOHLCV, Unit::Hour, vec![Param::Instrument { v: "BTC-USD", }], None).await.unwrap();
+        assert_eq!(ohlcv.data.unwrap().len(), 2);
+        let request: String = server.requests()[0].clone();
+        assert!(request.starts_with("GET /spot/v1/historical/hours?instrument=BTC-USD&groups=OHLC&market=kraken"));
+    }
+
+    #[tokio::test]
+    async fn unit_test_send_request() -> () {
+        use serde::Deserialize;
+        use crate::{CoinDeskResponse, APIEndpoint, Endpoint, Param, Unit, SpotMarket, SpotOHLCVRequest};
+        use crate::test_utils::{MockServer, MockResponse, mock_backend};
+
+        #[derive(Deserialize)]
+        struct Close {
+            #[serde(rename = "CLOSE")]
+            close: f64,
+        }
+
+        struct CloseRequest;
+
+        impl Endpoint for CloseRequest {
+            type API = APIEndpoint;
+            type Response = CoinDeskResponse<Vec<Close>>;
+
+            fn endpoint(&self) -> APIEndpoint { APIEndpoint::SpotOHLCV }
+
+            fn unit(&self) -> Unit { Unit::Hour }
+
+            fn params(&self) -> Vec<Param<'_>> { vec![Param::Instrument { v: "BTC-USD", }, Param::Market { v: String::from("kraken"), }] }
+        }
+
+        let fixture: &str = include_str!("../tests/fixtures/spot_ohlcv.json");
+        let server: MockServer = MockServer::start(vec![MockResponse::ok(fixture), MockResponse::ok(fixture)]).await;
+        let backend: CoinDesk = mock_backend(&server);
+        let request: SpotOHLCVRequest = SpotOHLCVRequest::new("BTC-USD").to_timestamp(1728860400).limit(2).market(SpotMarket::KRAKEN);
+        let ohlcv = backend.send(&request).await.unwrap();
+        assert_eq!(ohlcv.data.unwrap()[0].mapped_instrument, "BTC-USD");
+        let closes: Vec<Close> = backend.send(&CloseRequest).await.unwrap().data.unwrap();
+        assert!(closes.iter().all(|v| v.close > 0.0 ));
+        let requests: Vec<String> = server.requests();
+        assert!(requests[0].starts_with("GET /spot/v1/historical/days?instrument=BTC-USD&limit=2&to_ts=1728860400&market=kraken"));
+        assert!(requests[1].starts_with("GET /spot/v1/historical/hours?instrument=BTC-USD&market=kraken"));
+    }
+
+    #[tokio::test]
+    async fn unit_test_request_builders() -> () {
+        use crate::{Group, AssetLookupPriority, Unit, FuturesMarket, FuturesOHLCVRequest, AssetMetadataRequest};
+        use crate::test_utils::{MockServer, MockResponse, mock_backend};
+        let server: MockServer = MockServer::start(vec![MockResponse::ok("{\"Data\":[]}"), MockResponse::ok("{\"Data\":{}}"), MockResponse::ok("{\"Data\":{}}")]).await;
+        let backend: CoinDesk = mock_backend(&server);
+        // Optional parameters and groups override
+        let request: FuturesOHLCVRequest = FuturesOHLCVRequest::new("BTC-USDT-VANILLA-PERPETUAL")
+            .market(FuturesMarket::BINANCE).unit(Unit::Minute).aggregate(5).fill(false).apply_mapping(false).groups([Group::Id, Group::OHLC]);
+        backend.send(&request).await.unwrap();
+        // Default parameters are overridden by the request parameters
+        backend.send(&AssetMetadataRequest::new(["BTC"]).asset_lookup_priority(AssetLookupPriority::Id).quote_asset("EUR")).await.unwrap();
+        // Default parameters are used if the optional parameters are not set
+        backend.send(&AssetMetadataRequest::new(["BTC"])).await.unwrap();
+        let requests: Vec<String> = server.requests();
+        assert!(requests[0].starts_with(
+            "GET /futures/v1/historical/minutes?instrument=BTC-USDT-VANILLA-PERPETUAL&limit=2000&market=binance&aggregate=5&fill=false&apply_mapping=false&groups=ID,OHLC HTTP/1.1"
+        ));
+        assert!(requests[1].starts_with("GET /asset/v2/metadata?assets=BTC&asset_lookup_priority=ID&quote_asset=EUR&groups=ID,BASIC"));
+        assert_eq!(requests[1].matches("quote_asset=").count(), 1);
+        assert!(requests[2].starts_with("GET /asset/v2/metadata?assets=BTC&groups=ID,BASIC,SUPPLY,SUPPLY_ADDRESSES,CLASSIFICATION&asset_lookup_priority=SYMBOL&quote_asset=USD"));
+    }
+
+    #[tokio::test]
+    async fn unit_test_query_encoding() -> () {
+        use crate::{AuthMethod, CoinDeskResponse, APIEndpoint, Param, Unit, OCCoreAssetByAddressRequest};
+        use crate::test_utils::{MockServer, MockResponse, mock_backend};
+        let server: MockServer = MockServer::start(vec![MockResponse::ok("{\"Data\":{}}"), MockResponse::ok("{\"Data\":[]}")]).await;
+        let mut backend: CoinDesk = mock_backend(&server);
+        backend.update_auth_method(AuthMethod::QueryString);
+        backend.update_api_key(String::from("xx&x#x"));
+        backend.send(&OCCoreAssetByAddressRequest::new("ETH", "0xabc&asset=BTC#x").quote_asset("US D")).await.unwrap();
+        let _: CoinDeskResponse<Vec<serde_json::Value>> = backend.call_api_endpoint(
+            APIEndpoint::NewsCategories, Unit::NA, vec![Param::Asset { v: "a/b?c", }], Some(String::from("&extra=1#2&x=y z"))
+        ).await.unwrap();
+        let requests: Vec<String> = server.requests();
+        assert!(requests[0].starts_with("GET /onchain/v2/data/by/address?api_key=xx%26x%23x&chain_asset=ETH&address=0xabc%26asset%3DBTC%23x&quote_asset=US+D&"));
+        assert!(requests[1].starts_with("GET /news/v1/category/list?api_key=xx%26x%23x&asset=a%2Fb%3Fc&extra=1%232&x=y+z HTTP/1.1"));
+    }
+
+    #[tokio::test]
+    async fn unit_test_send_range() -> () {
+        use crate::{SpotMarket, Unit, SpotOHLCVRequest};
+        use crate::test_utils::{MockServer, MockResponse, mock_backend};
+        let first: &str = "{\"Data\":[],\"Err\":{\"type\":2,\"message\":\"No data\",\"other_info\":{\"first\":300}}}";
+        let server: MockServer = MockServer::start(vec![
+            MockResponse::ohlcv(&[400, 500]),
+            MockResponse::ohlcv(&[300, 400]),
+            MockResponse::ok(first),
+            MockResponse::ohlcv(&[400, 500]),
+            MockResponse::ohlcv(&[300, 400]),
+        ]).await;
+        let backend: CoinDesk = mock_backend(&server);
+        // Pagination stops at the first available timestamp
+        let ohlcv = backend.get_spot_ohlcv_range("BTC-USD", 0, 550, SpotMarket::KRAKEN, Unit::Day).await.unwrap();
+        assert_eq!(ohlcv.iter().map(|v| v.timestamp ).collect::<Vec<i64>>(), vec![300, 400, 500]);
+        let requests: Vec<String> = server.requests();
+        assert!(requests[0].contains("to_ts=550"));
+        assert!(requests[1].contains("to_ts=399"));
+        assert!(requests[2].contains("to_ts=299"));
+        // Pagination stops at the start of the time range
+        let request: SpotOHLCVRequest = SpotOHLCVRequest::new("BTC-USD").limit(2);
+        let ohlcv = backend.send_range(&request, 350, 500).await.unwrap();
+        assert_eq!(ohlcv.iter().map(|v| v.timestamp ).collect::<Vec<i64>>(), vec![400, 500]);
+        assert_eq!(server.requests().len(), 5);
+        // Empty time range
+        assert!(backend.send_range(&request, 500, 350).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn unit_test_stream_range() -> () {
+        use futures::StreamExt;
+        use crate::{SpotMarket, SpotOHLCV, Unit};
+        use crate::error::Error;
+        use crate::test_utils::{MockServer, MockResponse, mock_backend};
+        let server: MockServer = MockServer::start(vec![
+            MockResponse::ohlcv(&[500, 400]),
+            MockResponse::ohlcv(&[300, 400]),
+            MockResponse::ok("{\"Data\":[],\"Err\":{\"type\":2,\"message\":\"No data\",\"other_info\":{\"first\":300}}}"),
+            MockResponse::ohlcv(&[400, 500]),
+            MockResponse::ok("{\"Data\":[],\"Err\":{\"type\":1,\"message\":\"Bad request\"}}"),
+        ]).await;
+        let backend: CoinDesk = mock_backend(&server);
+        // Pages are yielded lazily from the most recent to the oldest
+        let mut pages = Box::pin(backend.get_spot_ohlcv_stream("BTC-USD", 0, 550, SpotMarket::KRAKEN, Unit::Day));
+        let page: Vec<SpotOHLCV> = pages.next().await.unwrap().unwrap();
+        assert_eq!(page.iter().map(|v| v.timestamp ).collect::<Vec<i64>>(), vec![400, 500]);
+        assert_eq!(server.requests().len(), 1);
+        let page: Vec<SpotOHLCV> = pages.next().await.unwrap().unwrap();
+        assert_eq!(page.iter().map(|v| v.timestamp ).collect::<Vec<i64>>(), vec![300]);
+        assert!(pages.next().await.is_none());
+        assert_eq!(server.requests().len(), 3);
+        // The stream ends after the first error
+        let pages: Vec<Result<Vec<SpotOHLCV>, Error>> = backend.get_spot_ohlcv_stream("BTC-USD", 0, 550, SpotMarket::KRAKEN, Unit::Day).collect().await;
+        assert_eq!(pages.len(), 2);
+        assert!(matches!(pages[1], Err(Error::Api(_))));
+        assert_eq!(server.requests().len(), 5);
+    }
+
+    #[tokio::test]
+    async fn unit_test_range_not_found() -> () {
+        use futures::StreamExt;
+        use crate::{SpotOHLCV, SpotOHLCVRequest};
+        use crate::error::Error;
+        use crate::test_utils::{MockServer, MockResponse, mock_backend};
+        let no_data: &str = "{\"Data\":[],\"Err\":{\"type\":2,\"message\":\"No data for the requested range\",\"other_info\":{\"first\":300}}}";
+        let server: MockServer = MockServer::start(vec![
+            MockResponse::ohlcv(&[400, 500]),
+            MockResponse::ohlcv(&[300, 400]),
+            MockResponse::new(404, no_data),
+            MockResponse::ohlcv(&[400, 500]),
+            MockResponse::new(404, no_data),
+            MockResponse::new(404, "{\"Data\":[],\"Err\":{\"type\":2,\"message\":\"Not found\"}}"),
+        ]).await;
+        let backend: CoinDesk = mock_backend(&server);
+        let request: SpotOHLCVRequest = SpotOHLCVRequest::new("BTC-USD").limit(2);
+        // Range that starts before the first available timestamp
+        let ohlcv: Vec<SpotOHLCV> = backend.send_range(&request, 0, 550).await.unwrap();
+        assert_eq!(ohlcv.iter().map(|v| v.timestamp ).collect::<Vec<i64>>(), vec![300, 400, 500]);
+        assert!(server.requests()[2].contains("to_ts=299"));
+        let pages: Vec<Result<Vec<SpotOHLCV>, Error>> = backend.stream_range(&request, 0, 550).collect().await;
+        assert_eq!(pages.len(), 1);
+        assert_eq!(pages[0].as_ref().unwrap().len(), 2);
+        assert_eq!(server.requests().len(), 5);
+        // `404 Not Found` without the first available timestamp is returned
+        assert!(matches!(backend.send_range(&request, 0, 550).await, Err(Error::NotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn unit_test_refetch_gaps() -> () {
+        use crate::{SpotOHLCV, SpotOHLCVRequest};
+        use crate::gaps::Grid;
+        use crate::test_utils::{MockServer, MockResponse, mock_backend};
+        let server: MockServer = MockServer::start(vec![
+            MockResponse::ohlcv(&[120, 180]),
+            MockResponse::ohlcv(&[360, 420]),
+        ]).await;
+        let backend: CoinDesk = mock_backend(&server);
+        let series: Vec<SpotOHLCV> = [0, 60, 240, 300, 480].map(|timestamp| SpotOHLCV { timestamp, ..Default::default() } ).to_vec();
+        let request: SpotOHLCVRequest = SpotOHLCVRequest::new("BTC-USD").limit(2);
+        let grid: Grid = Grid::new(60);
+        let ohlcv: Vec<SpotOHLCV> = backend.refetch_gaps(&request, &series, &grid).await.unwrap();
+        assert_eq!(ohlcv.iter().map(|v| v.timestamp ).collect::<Vec<i64>>(), (0..9).map(|i| 60 * i ).collect::<Vec<i64>>());
+        assert!(grid.detect(&ohlcv).is_regular());
+        let requests: Vec<String> = server.requests();
+        assert!(requests[0].contains("to_ts=180"));
+        assert!(requests[1].contains("to_ts=420"));
+        // Regular series is not refetched
+        assert_eq!(backend.refetch_gaps(&request, &ohlcv, &grid).await.unwrap().len(), 9);
+        assert_eq!(server.requests().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn unit_test_refetch_gaps_not_found() -> () {
+        use crate::{SpotOHLCV, SpotOHLCVRequest};
+        use crate::gaps::{Grid, GapReport};
+        use crate::test_utils::{MockServer, MockResponse, mock_backend};
+        let server: MockServer = MockServer::start(vec![
+            MockResponse::new(404, "{\"Data\":[],\"Err\":{\"type\":2,\"message\":\"No data for the requested range\"}}"),
+            MockResponse::ohlcv(&[360, 420]),
+        ]).await;
+        let backend: CoinDesk = mock_backend(&server);
+        let series: Vec<SpotOHLCV> = [0, 60, 240, 300, 480].map(|timestamp| SpotOHLCV { timestamp, ..Default::default() } ).to_vec();
+        let request: SpotOHLCVRequest = SpotOHLCVRequest::new("BTC-USD").limit(2);
+        let grid: Grid = Grid::new(60);
+        // Gap without any data remains missing and the other gap is refetched
+        let ohlcv: Vec<SpotOHLCV> = backend.refetch_gaps(&request, &series, &grid).await.unwrap();
+        assert_eq!(ohlcv.iter().map(|v| v.timestamp ).collect::<Vec<i64>>(), vec![0, 60, 240, 300, 360, 420, 480]);
+        let report: GapReport = grid.detect(&ohlcv);
+        assert_eq!(report.missing, vec![(120, 180)]);
+        assert_eq!(server.requests().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn unit_test_ohlcv_batch() -> () {
+        use std::collections::HashMap;
+        use crate::{SpotMarket, SpotOHLCVRequest, Unit};
+        use crate::error::Error;
+        use crate::test_utils::{MockServer, MockResponse, mock_backend};
+        let server: MockServer = MockServer::start(vec![
+            MockResponse::ohlcv(&[3600, 7200]),
+            MockResponse::new(400, "{\"Data\":[],\"Err\":{\"type\":2,\"message\":\"Instrument not found\"}}"),
+            MockResponse::ohlcv(&[3600, 7200]),
+            MockResponse::ohlcv(&[3600, 7200]),
+            MockResponse::ohlcv(&[3600, 7200]),
+            MockResponse::ohlcv(&[3600, 7200]),
+        ]).await;
+        let backend: CoinDesk = mock_backend(&server);
+        let request: SpotOHLCVRequest = SpotOHLCVRequest::new("").unit(Unit::Hour).limit(2);
+        // Failed requests do not abort the batch
+        let targets = [("BTC-USD", SpotMarket::KRAKEN), ("XXX-USD", SpotMarket::KRAKEN), ("BTC-USDT", SpotMarket::BINANCE)];
+        let batch: HashMap<String, Result<_, Error>> = backend.get_ohlcv_batch(&request, targets, 1).await;
+        assert_eq!(batch.len(), 3);
+        assert_eq!(batch.get("BTC-USD").unwrap().as_ref().unwrap().data.as_ref().unwrap().len(), 2);
+        assert!(batch.get("XXX-USD").unwrap().is_err());
+        assert!(batch.get("BTC-USDT").unwrap().is_ok());
+        let requests: Vec<String> = server.requests();
+        assert!(requests[0].starts_with("GET /spot/v1/historical/hours?instrument=BTC-USD&limit=2&market=kraken"));
+        assert!(requests[2].starts_with("GET /spot/v1/historical/hours?instrument=BTC-USDT&limit=2&market=binance"));
+        // Concurrent requests
+        let targets = ["ETH-USD", "SOL-USD", "XRP-USD"].map(|v| (v, SpotMarket::KRAKEN) );
+        let batch: HashMap<String, Result<_, Error>> = backend.get_ohlcv_batch(&request, targets, 3).await;
+        assert!(batch.values().all(|v| v.is_ok() ));
+        assert_eq!(server.requests().len(), 6);
+    }
+}
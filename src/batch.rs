@@ -0,0 +1,43 @@
+use crate::request::{
+    Endpoint, IndicesOHLCVRequest, SpotOHLCVRequest, FuturesOHLCVRequest, OptionsOHLCVRequest, DerIndicesOHLCVRequest, OCDEXOHLCVRequest,
+};
+use crate::schemas::data_api::indices_and_reference_rates::IndicesMarket;
+use crate::schemas::data_api::spot::SpotMarket;
+use crate::schemas::data_api::futures::FuturesMarket;
+use crate::schemas::data_api::options::OptionsMarket;
+use crate::schemas::data_api::derivatives_indices::DerIndicesMarket;
+use crate::schemas::data_api::on_chain_dex::OCDEXMarket;
+
+
+/// Request to an OHLCV API endpoint that can be sent for many `(instrument, market)` targets (e.g., `CoinDesk::get_ohlcv_batch`).
+pub trait OHLCVEndpoint: Endpoint + Clone {
+    /// Market type of the API endpoint.
+    type Market;
+
+    /// Updates the instrument and the market that the request is sent for.
+    ///
+    /// # Input
+    /// - `new_instrument`: New instrument symbol
+    /// - `new_market`: New market name
+    fn update_target(&mut self, new_instrument: &str, new_market: Self::Market) -> ();
+}
+
+macro_rules! impl_ohlcv_endpoint {
+    ($($r:ty => $m:ty),*) => {
+        $(
+            impl OHLCVEndpoint for $r {
+                type Market = $m;
+
+                fn update_target(&mut self, new_instrument: &str, new_market: Self::Market) -> () {
+                    self.instrument = new_instrument.to_owned();
+                    self.market = new_market;
+                }
+            }
+        )*
+    };
+}
+
+impl_ohlcv_endpoint!(
+    IndicesOHLCVRequest => IndicesMarket, SpotOHLCVRequest => SpotMarket, FuturesOHLCVRequest => FuturesMarket, OptionsOHLCVRequest => OptionsMarket,
+    DerIndicesOHLCVRequest => DerIndicesMarket, OCDEXOHLCVRequest => OCDEXMarket
+);
@@ -0,0 +1,479 @@
+use std::{hash::Hash, collections::HashMap};
+use tokio::runtime::{Builder, Runtime};
+use crate::error::Error;
+use crate::backend::CoinDesk;
+use crate::Unit;
+use crate::request::Endpoint;
+use crate::timestamp::IntoTimestamp;
+use crate::pagination::{Timestamped, HistoricalEndpoint};
+use crate::batch::OHLCVEndpoint;
+use crate::gaps::Grid;
+use crate::schemas::{self as sh, CoinDeskResponse};
+use crate::schemas::min_api;
+use crate::schemas::data_api::indices_and_reference_rates::{IndicesMarket, IndicesOHLCV};
+use crate::schemas::data_api::spot::{SpotMarket, SpotInstrumentStatus, SpotOHLCV, SpotInstrumentMetdata, SpotMarkets, SpotMarketsInstruments};
+use crate::schemas::data_api::futures::{FuturesMarket, FuturesOHLCV, FuturesInstrumentMetadata, FuturesMarkets};
+use crate::schemas::data_api::options::{OptionsMarket, OptionsOHLCV, OptionsInstrumentMetadata, OptionsMarkets};
+use crate::schemas::data_api::derivatives_indices::{DerIndicesMarket, DerIndicesOHLCV, DerIndicesMarkets};
+use crate::schemas::data_api::on_chain_dex::{OCDEXMarket, OCDEXOHLCV, OCDEXMarkets};
+use crate::schemas::data_api::on_chain_core::{OCCoreETHBlock, OCCoreAssetByChain, OCCoreAssetByAddress, OCCoreSupply};
+use crate::schemas::data_api::asset::{AssetMetadata, AssetEvent, AssetCodeRepoMetrics, AssetDiscord, AssetReddit, AssetTelegram, AssetTwitter};
+use crate::schemas::data_api::news::{NewsStatus, NewsLang, NewsSourceID, NewsLatestArticle, NewsSourceType, NewsSource, NewsCategory};
+use crate::schemas::data_api::overview::OverviewMktCapOHLCV;
+
+
+/// Blocking (synchronous) API data collection backend.
+///
+/// The backend wraps `CoinDesk` and runs its requests on an internal single-threaded runtime, so it shares the URL building,
+/// configuration (e.g., retry policy, rate limiter, cache) and schemas with the asynchronous backend.
+///
+/// Note: The methods of the blocking backend must not be called from within an asynchronous runtime.
+///
+/// # Examples
+///
+/// ```rust
+/// use ccdata_api::{CoinDeskBlocking, Unit, SpotMarket};
+///
+/// let mut backend: CoinDeskBlocking = CoinDeskBlocking::new().unwrap();
+/// // Provide API key as the environment variable called API_KEY
+/// backend.build(&"API_KEY").unwrap();
+///
+/// let ohlcv = backend.get_spot_ohlcv("BTC-USD", Some(1728860400), Some(2000), SpotMarket::KRAKEN, Unit::Day).unwrap();
+/// assert_eq!(ohlcv.data.unwrap().len(), 2000);
+/// ```
+pub struct CoinDeskBlocking {
+    /// Asynchronous backend that sends the requests
+    backend: CoinDesk,
+    /// Runtime that drives the requests of the asynchronous backend
+    runtime: Runtime,
+}
+
+impl CoinDeskBlocking {
+    /// Creates a new blocking backend for data collection.
+    pub fn new() -> Result<Self, Error> {
+        Self::from_backend(CoinDesk::new())
+    }
+
+    /// Creates a new blocking backend from the configured asynchronous backend.
+    ///
+    /// # Input
+    /// - `backend`: Asynchronous backend (e.g., with the custom HTTP client, retry policy or rate limiter)
+    pub fn from_backend(backend: CoinDesk) -> Result<Self, Error> {
+        let runtime: Runtime = Builder::new_current_thread().enable_all().build()?;
+        Ok(Self { backend, runtime, })
+    }
+
+    /// Returns the reference to the asynchronous backend.
+    pub fn backend(&self) -> &CoinDesk {
+        &self.backend
+    }
+
+    /// Returns the mutable reference to the asynchronous backend, which can be used to update its configuration.
+    pub fn backend_mut(&mut self) -> &mut CoinDesk {
+        &mut self.backend
+    }
+
+    /// Returns the asynchronous backend.
+    pub fn into_backend(self) -> CoinDesk {
+        self.backend
+    }
+
+    /// Returns the API key of the backend.
+    pub fn api_key(&self) -> Result<&str, Error> {
+        self.backend.api_key()
+    }
+
+    /// Updates the API key.
+    ///
+    /// # Input
+    /// - `new_api_key`: New API key that will be used by the backend to send requests to CoinDesk API endpoints
+    pub fn update_api_key(&mut self, new_api_key: String) -> () {
+        self.backend.update_api_key(new_api_key)
+    }
+
+    /// Initiates the blocking backend with the API key stored in the environment variable.
+    ///
+    /// # Input
+    /// - `api_key_env_var`: Name of the environment variable in the .env file that stores the CoinDesk API key
+    pub fn build(&mut self, api_key_env_var: &str) -> Result<(), Error> {
+        self.backend.build(api_key_env_var)
+    }
+
+    /// Sends the request and deserializes the response into the schema that is paired with the request.
+    ///
+    /// Blocking version of [`CoinDesk::send`].
+    ///
+    /// # Input
+    /// - `request`: Request to a CoinDesk API endpoint (Any type that implements `Endpoint`)
+    pub fn send<E: Endpoint>(&self, request: &E) -> Result<E::Response, Error> {
+        self.runtime.block_on(self.backend.send(request))
+    }
+
+    /// Sends the request repeatedly, moving `to_timestamp` backwards page by page, and returns all data points within the time range.
+    ///
+    /// Blocking version of [`CoinDesk::send_range`].
+    ///
+    /// # Input
+    /// - `request`: Request to a historical CoinDesk API endpoint (Any type that implements `HistoricalEndpoint`)
+    /// - `from`: Initial timestamp from which the data will be extracted
+    /// - `to`: Final timestamp up to which the data will be extracted
+    pub fn send_range<T: Timestamped, E: HistoricalEndpoint<T>>(&self, request: &E, from: impl IntoTimestamp, to: impl IntoTimestamp) -> Result<Vec<T>, Error> {
+        self.runtime.block_on(self.backend.send_range(request, from, to))
+    }
+
+    /// Refetches the missing intervals of the series from CoinDesk API and returns the series with the refetched data points.
+    ///
+    /// Blocking version of [`CoinDesk::refetch_gaps`].
+    ///
+    /// # Input
+    /// - `request`: Request to a historical CoinDesk API endpoint that the series was fetched with (Any type that implements `HistoricalEndpoint`)
+    /// - `series`: Data points of the same instrument
+    /// - `grid`: Grid of the intervals of the series (e.g., `Grid::from_unit(&Unit::Hour, 1)`)
+    pub fn refetch_gaps<T, E>(&self, request: &E, series: &[T], grid: &Grid) -> Result<Vec<T>, Error>
+    where
+        T: Timestamped + Clone,
+        E: HistoricalEndpoint<T>,
+    {
+        self.runtime.block_on(self.backend.refetch_gaps(request, series, grid))
+    }
+
+    /// Sends the requests concurrently, with at most `concurrency` requests in flight, and returns the response to every request by its key.
+    ///
+    /// Blocking version of [`CoinDesk::send_batch`].
+    ///
+    /// # Input
+    /// - `requests`: Pairs of keys (e.g., instrument symbols) and requests to CoinDesk API endpoints
+    /// - `concurrency`: Maximum number of requests in flight (`0` is treated as `1`)
+    pub fn send_batch<K, E>(&self, requests: impl IntoIterator<Item = (K, E)>, concurrency: usize) -> HashMap<K, Result<E::Response, Error>>
+    where
+        K: Eq + Hash,
+        E: Endpoint,
+    {
+        self.runtime.block_on(self.backend.send_batch(requests, concurrency))
+    }
+
+    /// Sends the OHLCV request for every `(instrument, market)` target concurrently, with at most `concurrency` requests in flight,
+    /// and returns the response for every instrument.
+    ///
+    /// Blocking version of [`CoinDesk::get_ohlcv_batch`].
+    ///
+    /// # Input
+    /// - `request`: Request to an OHLCV API endpoint that is used as the template for every target (Any type that implements `OHLCVEndpoint`)
+    /// - `targets`: Pairs of instrument symbols and market names
+    /// - `concurrency`: Maximum number of requests in flight (`0` is treated as `1`)
+    pub fn get_ohlcv_batch<E: OHLCVEndpoint>(
+        &self, request: &E, targets: impl IntoIterator<Item = (impl ToString, E::Market)>, concurrency: usize
+    ) -> HashMap<String, Result<E::Response, Error>> {
+        self.runtime.block_on(self.backend.get_ohlcv_batch(request, targets, concurrency))
+    }
+
+    #[deprecated(since="1.0.6", note="Deprecated by CoinDesk")]
+    #[allow(deprecated)]
+    /// # Balance Distribution Daily (Blockchain Data)
+    ///
+    /// Blocking version of [`CoinDesk::get_balance_distribution`].
+    pub fn get_balance_distribution(&self, to_timestamp: Option<i64>, limit: Option<usize>) -> Result<sh::CCMinResponse<sh::CCMinWrapper<Vec<min_api::BalanceDistribution>>>, Error> {
+        self.runtime.block_on(self.backend.get_balance_distribution(to_timestamp, limit))
+    }
+
+    /// # Historical OHLCV+ \[Day, Hour, Minute\] (Indices & Ref. Rates)
+    ///
+    /// Blocking version of [`CoinDesk::get_indices_ohlcv`].
+    pub fn get_indices_ohlcv(&self, instrument: &str, to_timestamp: Option<i64>, limit: Option<usize>, market: IndicesMarket, unit: Unit) -> Result<CoinDeskResponse<Vec<IndicesOHLCV>>, Error> {
+        self.runtime.block_on(self.backend.get_indices_ohlcv(instrument, to_timestamp, limit, market, unit))
+    }
+
+    /// # Historical OHLCV+ \[Day, Hour, Minute\] (Indices & Ref. Rates) - Time Range
+    ///
+    /// Blocking version of [`CoinDesk::get_indices_ohlcv_range`].
+    pub fn get_indices_ohlcv_range(&self, instrument: &str, from: impl IntoTimestamp, to: impl IntoTimestamp, market: IndicesMarket, unit: Unit) -> Result<Vec<IndicesOHLCV>, Error> {
+        self.runtime.block_on(self.backend.get_indices_ohlcv_range(instrument, from, to, market, unit))
+    }
+
+    /// # Historical OHLCV+ \[Day, Hour, Minute\] (Spot)
+    ///
+    /// Blocking version of [`CoinDesk::get_spot_ohlcv`].
+    pub fn get_spot_ohlcv(&self, instrument: &str, to_timestamp: Option<i64>, limit: Option<usize>, market: SpotMarket, unit: Unit) -> Result<CoinDeskResponse<Vec<SpotOHLCV>>, Error> {
+        self.runtime.block_on(self.backend.get_spot_ohlcv(instrument, to_timestamp, limit, market, unit))
+    }
+
+    /// # Historical OHLCV+ \[Day, Hour, Minute\] (Spot) - Time Range
+    ///
+    /// Blocking version of [`CoinDesk::get_spot_ohlcv_range`].
+    pub fn get_spot_ohlcv_range(&self, instrument: &str, from: impl IntoTimestamp, to: impl IntoTimestamp, market: SpotMarket, unit: Unit) -> Result<Vec<SpotOHLCV>, Error> {
+        self.runtime.block_on(self.backend.get_spot_ohlcv_range(instrument, from, to, market, unit))
+    }
+
+    /// # Instrument Metadata (Spot)
+    ///
+    /// Blocking version of [`CoinDesk::get_spot_instrument_metadata`].
+    pub fn get_spot_instrument_metadata(&self, instruments: &[String], market: SpotMarket) -> Result<CoinDeskResponse<HashMap<String, SpotInstrumentMetdata>>, Error> {
+        self.runtime.block_on(self.backend.get_spot_instrument_metadata(instruments, market))
+    }
+
+    /// # Markets (Spot) - V2
+    ///
+    /// Blocking version of [`CoinDesk::get_spot_markets_v2`].
+    pub fn get_spot_markets_v2(&self, markets: Vec<SpotMarket>) -> Result<CoinDeskResponse<HashMap<String, SpotMarkets>>, Error> {
+        self.runtime.block_on(self.backend.get_spot_markets_v2(markets))
+    }
+
+    /// # Markets + Instruments \[Mapped\] (Spot)
+    ///
+    /// Blocking version of [`CoinDesk::get_spot_markets_instruments`].
+    pub fn get_spot_markets_instruments(&self, instruments: &[String], market: SpotMarket, instrument_status: SpotInstrumentStatus) -> Result<CoinDeskResponse<HashMap<String, SpotMarketsInstruments>>, Error> {
+        self.runtime.block_on(self.backend.get_spot_markets_instruments(instruments, market, instrument_status))
+    }
+
+    /// # Historical OHLCV+ \[Day, Hour, Minute\] (Futures)
+    ///
+    /// Blocking version of [`CoinDesk::get_futures_ohlcv`].
+    pub fn get_futures_ohlcv(&self, instrument: &str, to_timestamp: Option<i64>, limit: Option<usize>, market: FuturesMarket, unit: Unit) -> Result<CoinDeskResponse<Vec<FuturesOHLCV>>, Error> {
+        self.runtime.block_on(self.backend.get_futures_ohlcv(instrument, to_timestamp, limit, market, unit))
+    }
+
+    /// # Historical OHLCV+ \[Day, Hour, Minute\] (Futures) - Time Range
+    ///
+    /// Blocking version of [`CoinDesk::get_futures_ohlcv_range`].
+    pub fn get_futures_ohlcv_range(&self, instrument: &str, from: impl IntoTimestamp, to: impl IntoTimestamp, market: FuturesMarket, unit: Unit) -> Result<Vec<FuturesOHLCV>, Error> {
+        self.runtime.block_on(self.backend.get_futures_ohlcv_range(instrument, from, to, market, unit))
+    }
+
+    /// # Instrument Metadata (Futures)
+    ///
+    /// Blocking version of [`CoinDesk::get_futures_instrument_metadata`].
+    pub fn get_futures_instrument_metadata(&self, instruments: &[String], market: FuturesMarket) -> Result<CoinDeskResponse<HashMap<String, FuturesInstrumentMetadata>>, Error> {
+        self.runtime.block_on(self.backend.get_futures_instrument_metadata(instruments, market))
+    }
+
+    /// # Markets (Futures)
+    ///
+    /// Blocking version of [`CoinDesk::get_futures_markets_v2`].
+    pub fn get_futures_markets_v2(&self, markets: Vec<FuturesMarket>) -> Result<CoinDeskResponse<HashMap<String, FuturesMarkets>>, Error> {
+        self.runtime.block_on(self.backend.get_futures_markets_v2(markets))
+    }
+
+    /// # Historical OHLCV+ \[Day, Hour, Minute\] (Options)
+    ///
+    /// Blocking version of [`CoinDesk::get_options_ohlcv`].
+    pub fn get_options_ohlcv(&self, instrument: &str, to_timestamp: Option<i64>, limit: Option<usize>, market: OptionsMarket, unit: Unit) -> Result<CoinDeskResponse<Vec<OptionsOHLCV>>, Error> {
+        self.runtime.block_on(self.backend.get_options_ohlcv(instrument, to_timestamp, limit, market, unit))
+    }
+
+    /// # Instrument Metadata (Options)
+    ///
+    /// Blocking version of [`CoinDesk::get_options_instrument_metadata`].
+    pub fn get_options_instrument_metadata(&self, instruments: &[String], market: OptionsMarket) -> Result<CoinDeskResponse<HashMap<String, OptionsInstrumentMetadata>>, Error> {
+        self.runtime.block_on(self.backend.get_options_instrument_metadata(instruments, market))
+    }
+
+    /// # Markets (Options)
+    ///
+    /// Blocking version of [`CoinDesk::get_options_markets_v2`].
+    pub fn get_options_markets_v2(&self, markets: Vec<OptionsMarket>) -> Result<CoinDeskResponse<HashMap<String, OptionsMarkets>>, Error> {
+        self.runtime.block_on(self.backend.get_options_markets_v2(markets))
+    }
+
+    /// # Historical OHLCV+ \[Day, Hour, Minute\] (Derivatives Indices)
+    ///
+    /// Blocking version of [`CoinDesk::get_der_indices_ohlcv`].
+    pub fn get_der_indices_ohlcv(&self, instrument: &str, to_timestamp: Option<i64>, limit: Option<usize>, market: DerIndicesMarket, unit: Unit) -> Result<CoinDeskResponse<Vec<DerIndicesOHLCV>>, Error> {
+        self.runtime.block_on(self.backend.get_der_indices_ohlcv(instrument, to_timestamp, limit, market, unit))
+    }
+
+    /// # Markets (Derivatives Indices)
+    ///
+    /// Blocking version of [`CoinDesk::get_der_indices_markets_v2`].
+    pub fn get_der_indices_markets_v2(&self, markets: Vec<DerIndicesMarket>) -> Result<CoinDeskResponse<HashMap<String, DerIndicesMarkets>>, Error> {
+        self.runtime.block_on(self.backend.get_der_indices_markets_v2(markets))
+    }
+
+    /// # Historical OHLCV+ (Swap) \[Day, Hour, Minute\] (On-Chain DEX)
+    ///
+    /// Blocking version of [`CoinDesk::get_ocdex_ohlcv`].
+    pub fn get_ocdex_ohlcv(&self, instrument: &str, to_timestamp: Option<i64>, limit: Option<usize>, market: OCDEXMarket, unit: Unit) -> Result<CoinDeskResponse<Vec<OCDEXOHLCV>>, Error> {
+        self.runtime.block_on(self.backend.get_ocdex_ohlcv(instrument, to_timestamp, limit, market, unit))
+    }
+
+    /// # Markets (On-Chain DEX)
+    ///
+    /// Blocking version of [`CoinDesk::get_ocdex_markets_v2`].
+    pub fn get_ocdex_markets_v2(&self, markets: Vec<OCDEXMarket>) -> Result<CoinDeskResponse<HashMap<String, OCDEXMarkets>>, Error> {
+        self.runtime.block_on(self.backend.get_ocdex_markets_v2(markets))
+    }
+
+    /// # ETH Blocks \[Full Processed\] (On-Chain Core)
+    ///
+    /// Blocking version of [`CoinDesk::get_occore_eth_block`].
+    pub fn get_occore_eth_block(&self, block_number: i64) -> Result<CoinDeskResponse<OCCoreETHBlock>, Error> {
+        self.runtime.block_on(self.backend.get_occore_eth_block(block_number))
+    }
+
+    /// # Assets Summary By Chain (On-Chain Core)
+    ///
+    /// Blocking version of [`CoinDesk::get_occore_assets_by_chain`].
+    pub fn get_occore_assets_by_chain(&self, chain_asset: &str) -> Result<CoinDeskResponse<OCCoreAssetByChain>, Error> {
+        self.runtime.block_on(self.backend.get_occore_assets_by_chain(chain_asset))
+    }
+
+    /// # Asset By Address Lookup (On-Chain Core)
+    ///
+    /// Blocking version of [`CoinDesk::get_occore_asset_by_address`].
+    pub fn get_occore_asset_by_address(&self, chain_asset: &str, address: &str, quote_asset: &str) -> Result<CoinDeskResponse<OCCoreAssetByAddress>, Error> {
+        self.runtime.block_on(self.backend.get_occore_asset_by_address(chain_asset, address, quote_asset))
+    }
+
+    /// # Historical Supply Day (On-Chain Core)
+    ///
+    /// Blocking version of [`CoinDesk::get_occore_supply`].
+    pub fn get_occore_supply(&self, asset: &str, to_timestamp: Option<i64>, limit: Option<usize>) -> Result<CoinDeskResponse<Vec<OCCoreSupply>>, Error> {
+        self.runtime.block_on(self.backend.get_occore_supply(asset, to_timestamp, limit))
+    }
+
+    /// # Historical Supply Day (On-Chain Core) - Time Range
+    ///
+    /// Blocking version of [`CoinDesk::get_occore_supply_range`].
+    pub fn get_occore_supply_range(&self, asset: &str, from: impl IntoTimestamp, to: impl IntoTimestamp) -> Result<Vec<OCCoreSupply>, Error> {
+        self.runtime.block_on(self.backend.get_occore_supply_range(asset, from, to))
+    }
+
+    /// # Full Asset Metadata (Asset) - V2
+    ///
+    /// Blocking version of [`CoinDesk::get_asset_metadata_v2`].
+    pub fn get_asset_metadata_v2(&self, assets: Vec<String>) -> Result<CoinDeskResponse<HashMap<String, AssetMetadata>>, Error> {
+        self.runtime.block_on(self.backend.get_asset_metadata_v2(assets))
+    }
+
+    /// # Significant Asset Events (Asset)
+    ///
+    /// Blocking version of [`CoinDesk::get_asset_events`].
+    pub fn get_asset_events(&self, asset: &str, to_timestamp: Option<i64>, limit: Option<usize>) -> Result<CoinDeskResponse<Vec<AssetEvent>>, Error> {
+        self.runtime.block_on(self.backend.get_asset_events(asset, to_timestamp, limit))
+    }
+
+    /// # Historical Social \[Code Repository Day\] (Asset)
+    ///
+    /// Blocking version of [`CoinDesk::get_asset_code_repo`].
+    pub fn get_asset_code_repo(&self, asset: &str, to_timestamp: Option<i64>, limit: Option<usize>) -> Result<CoinDeskResponse<Vec<AssetCodeRepoMetrics>>, Error> {
+        self.runtime.block_on(self.backend.get_asset_code_repo(asset, to_timestamp, limit))
+    }
+
+    /// # Historical Social \[Code Repository Day\] (Asset) - Time Range
+    ///
+    /// Blocking version of [`CoinDesk::get_asset_code_repo_range`].
+    pub fn get_asset_code_repo_range(&self, asset: &str, from: impl IntoTimestamp, to: impl IntoTimestamp) -> Result<Vec<AssetCodeRepoMetrics>, Error> {
+        self.runtime.block_on(self.backend.get_asset_code_repo_range(asset, from, to))
+    }
+
+    /// # Historical Social \[Discord Day\] (Asset)
+    ///
+    /// Blocking version of [`CoinDesk::get_asset_discord`].
+    pub fn get_asset_discord(&self, asset: &str, to_timestamp: Option<i64>, limit: Option<usize>) -> Result<CoinDeskResponse<Vec<AssetDiscord>>, Error> {
+        self.runtime.block_on(self.backend.get_asset_discord(asset, to_timestamp, limit))
+    }
+
+    /// # Historical Social \[Discord Day\] (Asset) - Time Range
+    ///
+    /// Blocking version of [`CoinDesk::get_asset_discord_range`].
+    pub fn get_asset_discord_range(&self, asset: &str, from: impl IntoTimestamp, to: impl IntoTimestamp) -> Result<Vec<AssetDiscord>, Error> {
+        self.runtime.block_on(self.backend.get_asset_discord_range(asset, from, to))
+    }
+
+    /// # Historical Social \[Reddit Day\] (Asset)
+    ///
+    /// Blocking version of [`CoinDesk::get_asset_reddit`].
+    pub fn get_asset_reddit(&self, asset: &str, to_timestamp: Option<i64>, limit: Option<usize>) -> Result<CoinDeskResponse<Vec<AssetReddit>>, Error> {
+        self.runtime.block_on(self.backend.get_asset_reddit(asset, to_timestamp, limit))
+    }
+
+    /// # Historical Social \[Reddit Day\] (Asset) - Time Range
+    ///
+    /// Blocking version of [`CoinDesk::get_asset_reddit_range`].
+    pub fn get_asset_reddit_range(&self, asset: &str, from: impl IntoTimestamp, to: impl IntoTimestamp) -> Result<Vec<AssetReddit>, Error> {
+        self.runtime.block_on(self.backend.get_asset_reddit_range(asset, from, to))
+    }
+
+    /// # Historical Social \[Telegram Day\] (Asset)
+    ///
+    /// Blocking version of [`CoinDesk::get_asset_telegram`].
+    pub fn get_asset_telegram(&self, asset: &str, to_timestamp: Option<i64>, limit: Option<usize>) -> Result<CoinDeskResponse<Vec<AssetTelegram>>, Error> {
+        self.runtime.block_on(self.backend.get_asset_telegram(asset, to_timestamp, limit))
+    }
+
+    /// # Historical Social \[Telegram Day\] (Asset) - Time Range
+    ///
+    /// Blocking version of [`CoinDesk::get_asset_telegram_range`].
+    pub fn get_asset_telegram_range(&self, asset: &str, from: impl IntoTimestamp, to: impl IntoTimestamp) -> Result<Vec<AssetTelegram>, Error> {
+        self.runtime.block_on(self.backend.get_asset_telegram_range(asset, from, to))
+    }
+
+    /// # Historical Social \[X (Twitter) Day\] (Asset)
+    ///
+    /// Blocking version of [`CoinDesk::get_asset_twitter`].
+    pub fn get_asset_twitter(&self, asset: &str, to_timestamp: Option<i64>, limit: Option<usize>) -> Result<CoinDeskResponse<Vec<AssetTwitter>>, Error> {
+        self.runtime.block_on(self.backend.get_asset_twitter(asset, to_timestamp, limit))
+    }
+
+    /// # Historical Social \[X (Twitter) Day\] (Asset) - Time Range
+    ///
+    /// Blocking version of [`CoinDesk::get_asset_twitter_range`].
+    pub fn get_asset_twitter_range(&self, asset: &str, from: impl IntoTimestamp, to: impl IntoTimestamp) -> Result<Vec<AssetTwitter>, Error> {
+        self.runtime.block_on(self.backend.get_asset_twitter_range(asset, from, to))
+    }
+
+    /// # Latest Articles (News)
+    ///
+    /// Blocking version of [`CoinDesk::get_news_latest_articles`].
+    pub fn get_news_latest_articles(&self, language: NewsLang, source_id: NewsSourceID, categories: Option<Vec<String>>,
+                                    exclude_categories: Option<Vec<String>>, to_timestamp: Option<i64>, limit: Option<usize>) -> Result<CoinDeskResponse<Vec<NewsLatestArticle>>, Error> {
+        self.runtime.block_on(self.backend.get_news_latest_articles(language, source_id, categories, exclude_categories, to_timestamp, limit))
+    }
+
+    /// # Sources (News)
+    ///
+    /// Blocking version of [`CoinDesk::get_news_sources`].
+    pub fn get_news_sources(&self, language: NewsLang, source_type: NewsSourceType, status: NewsStatus) -> Result<CoinDeskResponse<Vec<NewsSource>>, Error> {
+        self.runtime.block_on(self.backend.get_news_sources(language, source_type, status))
+    }
+
+    /// # Categories (News)
+    ///
+    /// Blocking version of [`CoinDesk::get_news_categories`].
+    pub fn get_news_categories(&self, status: NewsStatus) -> Result<CoinDeskResponse<Vec<NewsCategory>>, Error> {
+        self.runtime.block_on(self.backend.get_news_categories(status))
+    }
+
+    /// # MktCap Historical OHLCV \[All Assets Day\] (Overview)
+    ///
+    /// Blocking version of [`CoinDesk::get_overview_mktcap_ohlcv`].
+    pub fn get_overview_mktcap_ohlcv(&self, to_timestamp: Option<i64>, limit: Option<usize>) -> Result<CoinDeskResponse<Vec<OverviewMktCapOHLCV>>, Error> {
+        self.runtime.block_on(self.backend.get_overview_mktcap_ohlcv(to_timestamp, limit))
+    }
+
+    /// # MktCap Historical OHLCV \[All Assets Day\] (Overview) - Time Range
+    ///
+    /// Blocking version of [`CoinDesk::get_overview_mktcap_ohlcv_range`].
+    pub fn get_overview_mktcap_ohlcv_range(&self, from: impl IntoTimestamp, to: impl IntoTimestamp) -> Result<Vec<OverviewMktCapOHLCV>, Error> {
+        self.runtime.block_on(self.backend.get_overview_mktcap_ohlcv_range(from, to))
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+
+    #[test]
+    fn unit_test_blocking_backend() -> () {
+        use crate::{BaseURL, Unit, SpotMarket};
+        use crate::blocking::CoinDeskBlocking;
+        use crate::test_utils::{MockServer, MockResponse};
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        let server: MockServer = runtime.block_on(MockServer::start(vec![MockResponse::ok(include_str!("../tests/fixtures/spot_ohlcv.json"))]));
+        let mut backend: CoinDeskBlocking = CoinDeskBlocking::new().unwrap();
+        backend.update_api_key(String::from("xxxxxxx"));
+        backend.backend_mut().update_base_url(BaseURL { data_api: server.url.clone(), ..Default::default() });
+        let ohlcv = backend.get_spot_ohlcv("BTC-USD", None, Some(2), SpotMarket::KRAKEN, Unit::Day).unwrap();
+        assert_eq!(ohlcv.data.unwrap().len(), 2);
+        assert_eq!(server.requests().len(), 1);
+    }
+}
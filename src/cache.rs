@@ -0,0 +1,338 @@
+use std::{fmt::Debug, fs, path::PathBuf, sync::Mutex};
+use std::collections::{BTreeMap, HashMap};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use crate::error::Error;
+use crate::{Unit, APIEndpointTrait};
+
+
+/// Storage of the raw response bodies returned by CoinDesk API.
+///
+/// Note: The cache is best-effort, the implementations should not fail the request if the entry cannot be read or written.
+pub trait Cache: Debug + Send + Sync {
+    /// Returns the response body stored under the key, if it exists and has not expired.
+    fn get(&self, key: &str) -> Option<String>;
+
+    /// Stores the response body under the key.
+    ///
+    /// # Input
+    /// - `key`: Cache key (Endpoint URL with the normalized query string)
+    /// - `value`: Response body
+    /// - `ttl`: Time after which the entry expires
+    fn insert(&self, key: &str, value: &str, ttl: Duration) -> ();
+
+    /// Removes the entry stored under the key.
+    fn remove(&self, key: &str) -> ();
+
+    /// Removes all entries from the cache.
+    fn clear(&self) -> ();
+}
+
+
+#[derive(Clone, Debug, PartialEq)]
+/// Time-to-live rules of the cached responses.
+///
+/// The TTL of the response is taken from the rule with the longest URL prefix that matches the endpoint URL, or from `default_ttl` if no rule matches.
+/// A TTL of `None` means that the responses are not cached.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::{sync::Arc, time::Duration};
+/// use ccdata_api::{CoinDesk, APIEndpoint, Unit, CachePolicy, MemoryCache};
+///
+/// let mut backend: CoinDesk = CoinDesk::new();
+///
+/// let policy: CachePolicy = CachePolicy { default_ttl: None, ..Default::default() }
+///     // Closed daily candles do not change
+///     .with_endpoint_ttl(&APIEndpoint::SpotOHLCV, &Unit::Day, Some(Duration::from_secs(86_400)))
+///     // Latest metadata is only reused for a short time
+///     .with_endpoint_ttl(&APIEndpoint::SpotInstrumentMetadata, &Unit::NA, Some(Duration::from_secs(60)));
+/// backend.update_cache(Some(Arc::new(MemoryCache::new(1_000))));
+/// backend.update_cache_policy(policy);
+/// ```
+pub struct CachePolicy {
+    /// TTL of the responses from the endpoints that do not match any of the rules
+    pub default_ttl: Option<Duration>,
+    /// URL prefixes of the endpoints and their TTLs
+    pub rules: Vec<(String, Option<Duration>)>,
+}
+
+impl Default for CachePolicy {
+    fn default() -> Self {
+        Self { default_ttl: Some(Duration::from_secs(300)), rules: Vec::new(), }
+    }
+}
+
+impl CachePolicy {
+    /// Adds the TTL rule for the endpoint URLs that start with the URL prefix.
+    ///
+    /// # Input
+    /// - `url_prefix`: Prefix of the endpoint URL (e.g., `https://data-api.coindesk.com/spot/v1/historical`)
+    /// - `ttl`: TTL of the responses (`None` disables caching)
+    pub fn with_ttl(mut self, url_prefix: &str, ttl: Option<Duration>) -> Self {
+        self.rules.push((url_prefix.to_owned(), ttl));
+        self
+    }
+
+    /// Adds the TTL rule for the endpoint.
+    ///
+    /// # Input
+    /// - `endpoint`: API endpoint
+    /// - `unit`: Unit of the interval between successive data points (Only used by historical endpoints)
+    /// - `ttl`: TTL of the responses (`None` disables caching)
+    pub fn with_endpoint_ttl<E: APIEndpointTrait>(self, endpoint: &E, unit: &Unit, ttl: Option<Duration>) -> Self {
+        self.with_ttl(&endpoint.url(unit), ttl)
+    }
+
+    /// Returns the TTL of the responses from the endpoint URL.
+    pub fn ttl(&self, url: &str) -> Option<Duration> {
+        self.rules.iter()
+            .filter(|(prefix, _)| url.starts_with(prefix.as_str()) )
+            .max_by_key(|(prefix, _)| prefix.len() )
+            .map_or(self.default_ttl, |(_, ttl)| *ttl )
+    }
+}
+
+
+/// Creates the cache key from the endpoint URL and the query string.
+///
+/// Note: The parameters are sorted so that the same request always has the same key, and the API key is never part of the key.
+pub(crate) fn cache_key(url: &str, query: &str) -> String {
+    let mut params: Vec<&str> = query.split('&').filter(|v| !v.is_empty() && !v.starts_with("api_key=") ).collect();
+    params.sort_unstable();
+    format!("{}?{}", url, params.join("&"))
+}
+
+
+#[derive(Clone, Debug)]
+struct MemoryEntry {
+    value: String,
+    expires_at: Instant,
+    last_used: u64,
+}
+
+#[derive(Debug, Default)]
+struct MemoryCacheState {
+    entries: HashMap<String, MemoryEntry>,
+    /// Keys ordered by the last use
+    usage: BTreeMap<u64, String>,
+    counter: u64,
+}
+
+impl MemoryCacheState {
+    fn touch(&mut self, key: &str) -> () {
+        self.counter += 1;
+        let counter: u64 = self.counter;
+        if let Some(entry) = self.entries.get_mut(key) {
+            self.usage.remove(&entry.last_used);
+            entry.last_used = counter;
+            self.usage.insert(counter, key.to_owned());
+        }
+    }
+
+    fn remove(&mut self, key: &str) -> () {
+        if let Some(entry) = self.entries.remove(key) {
+            self.usage.remove(&entry.last_used);
+        }
+    }
+}
+
+
+#[derive(Debug)]
+/// In-memory cache that evicts the least recently used entries once the capacity is reached.
+pub struct MemoryCache {
+    capacity: usize,
+    state: Mutex<MemoryCacheState>,
+}
+
+impl MemoryCache {
+    /// Creates an in-memory cache.
+    ///
+    /// # Input
+    /// - `capacity`: Maximum number of entries stored in the cache
+    pub fn new(capacity: usize) -> Self {
+        Self { capacity, state: Mutex::new(MemoryCacheState::default()), }
+    }
+
+    /// Returns the maximum number of entries stored in the cache.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the number of entries stored in the cache (including the expired entries that have not been evicted yet).
+    pub fn len(&self) -> usize {
+        self.state.lock().unwrap_or_else(|e| e.into_inner() ).entries.len()
+    }
+
+    /// Checks whether the cache is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl Cache for MemoryCache {
+    fn get(&self, key: &str) -> Option<String> {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner() );
+        if state.entries.get(key)?.expires_at <= Instant::now() {
+            state.remove(key);
+            return None;
+        }
+        state.touch(key);
+        state.entries.get(key).map(|v| v.value.clone() )
+    }
+
+    fn insert(&self, key: &str, value: &str, ttl: Duration) -> () {
+        if self.capacity == 0 {
+            return;
+        }
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner() );
+        state.remove(key);
+        while self.capacity <= state.entries.len() {
+            match state.usage.pop_first() {
+                Some((_, oldest)) => { state.entries.remove(&oldest); },
+                None => break,
+            }
+        }
+        let expires_at: Instant = Instant::now() + ttl;
+        state.entries.insert(key.to_owned(), MemoryEntry { value: value.to_owned(), expires_at, last_used: 0, });
+        state.touch(key);
+    }
+
+    fn remove(&self, key: &str) -> () {
+        self.state.lock().unwrap_or_else(|e| e.into_inner() ).remove(key);
+    }
+
+    fn clear(&self) -> () {
+        *self.state.lock().unwrap_or_else(|e| e.into_inner() ) = MemoryCacheState::default();
+    }
+}
+
+
+#[derive(Clone, Debug)]
+/// File-backed cache that stores every entry in a separate file inside the directory, so that the cache persists between the runs.
+///
+/// Note: Expired entries are removed when they are read.
+pub struct FileCache {
+    directory: PathBuf,
+}
+
+impl FileCache {
+    /// Creates a file-backed cache, creating the directory if it does not exist.
+    ///
+    /// # Input
+    /// - `directory`: Directory where the cached responses are stored
+    pub fn new<P: Into<PathBuf>>(directory: P) -> Result<Self, Error> {
+        let directory: PathBuf = directory.into();
+        fs::create_dir_all(&directory)?;
+        Ok(Self { directory })
+    }
+
+    /// Returns the directory where the cached responses are stored.
+    pub fn directory(&self) -> &PathBuf {
+        &self.directory
+    }
+
+    fn path(&self, key: &str) -> PathBuf {
+        self.directory.join(format!("{:016x}.cache", fnv1a(key.as_bytes())))
+    }
+}
+
+impl Cache for FileCache {
+    fn get(&self, key: &str) -> Option<String> {
+        let path: PathBuf = self.path(key);
+        let contents: String = fs::read_to_string(&path).ok()?;
+        // File layout: expiry timestamp (ms), key, response body
+        let mut parts = contents.splitn(3, '\n');
+        let expires_at: u128 = parts.next()?.parse().ok()?;
+        if parts.next()? != key {
+            return None;
+        }
+        if expires_at <= unix_millis() {
+            let _ = fs::remove_file(&path);
+            return None;
+        }
+        parts.next().map(str::to_owned)
+    }
+
+    fn insert(&self, key: &str, value: &str, ttl: Duration) -> () {
+        let expires_at: u128 = unix_millis() + ttl.as_millis();
+        let _ = fs::write(self.path(key), format!("{}\n{}\n{}", expires_at, key, value));
+    }
+
+    fn remove(&self, key: &str) -> () {
+        let _ = fs::remove_file(self.path(key));
+    }
+
+    fn clear(&self) -> () {
+        if let Ok(entries) = fs::read_dir(&self.directory) {
+            entries.flatten()
+                .filter(|v| v.path().extension().is_some_and(|e| e == "cache") )
+                .for_each(|v| { let _ = fs::remove_file(v.path()); });
+        }
+    }
+}
+
+
+/// Current Unix time in milliseconds.
+fn unix_millis() -> u128 {
+    SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_millis()
+}
+
+
+/// 64-bit FNV-1a hash (Stable between the runs, unlike the hasher of the standard library).
+pub(crate) fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf29ce484222325, |hash, b| (hash ^ *b as u64).wrapping_mul(0x100000001b3) )
+}
+
+
+#[cfg(test)]
+mod tests {
+
+    #[test]
+    fn unit_test_cache_policy() -> () {
+        use std::time::Duration;
+        use crate::{APIEndpoint, Unit};
+        use crate::cache::{CachePolicy, cache_key};
+        let policy: CachePolicy = CachePolicy::default()
+            .with_ttl("https://data-api.coindesk.com/spot", Some(Duration::from_secs(10)))
+            .with_endpoint_ttl(&APIEndpoint::SpotOHLCV, &Unit::Day, None);
+        assert_eq!(policy.ttl("https://data-api.coindesk.com/spot/v1/historical/days"), None);
+        assert_eq!(policy.ttl("https://data-api.coindesk.com/spot/v1/historical/hours"), Some(Duration::from_secs(10)));
+        assert_eq!(policy.ttl("https://data-api.coindesk.com/news/v1/article/list"), Some(Duration::from_secs(300)));
+        assert_eq!(cache_key("https://x.com/a", "&limit=5&api_key=xxx&market=kraken"), "https://x.com/a?limit=5&market=kraken");
+        assert_eq!(cache_key("https://x.com/a", "&market=kraken&limit=5"), cache_key("https://x.com/a", "&limit=5&market=kraken"));
+    }
+
+    #[test]
+    fn unit_test_memory_cache() -> () {
+        use std::time::Duration;
+        use crate::cache::{Cache, MemoryCache};
+        let cache: MemoryCache = MemoryCache::new(2);
+        cache.insert("a", "1", Duration::from_secs(60));
+        cache.insert("b", "2", Duration::from_secs(60));
+        assert_eq!(cache.get("a"), Some(String::from("1")));
+        // "b" is the least recently used entry
+        cache.insert("c", "3", Duration::from_secs(60));
+        assert_eq!(cache.get("b"), None);
+        assert_eq!(cache.len(), 2);
+        cache.insert("d", "4", Duration::ZERO);
+        assert_eq!(cache.get("d"), None);
+        cache.clear();
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn unit_test_file_cache() -> () {
+        use std::time::Duration;
+        use crate::cache::{Cache, FileCache};
+        let directory = std::env::temp_dir().join(format!("ccdata_api_cache_{}", std::process::id()));
+        let cache: FileCache = FileCache::new(&directory).unwrap();
+        cache.insert("a", "{\"Data\":[]}\n", Duration::from_secs(60));
+        assert_eq!(FileCache::new(&directory).unwrap().get("a"), Some(String::from("{\"Data\":[]}\n")));
+        cache.insert("b", "2", Duration::ZERO);
+        assert_eq!(cache.get("b"), None);
+        cache.clear();
+        assert_eq!(cache.get("a"), None);
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+}
@@ -0,0 +1,193 @@
+use serde::{Serialize, Deserialize};
+use crate::pagination::Timestamped;
+use crate::schemas::Numeric;
+use crate::schemas::data_api::indices_and_reference_rates::IndicesOHLCV;
+use crate::schemas::data_api::spot::SpotOHLCV;
+use crate::schemas::data_api::futures::FuturesOHLCV;
+use crate::schemas::data_api::options::OptionsOHLCV;
+use crate::schemas::data_api::derivatives_indices::DerIndicesOHLCV;
+use crate::schemas::data_api::on_chain_dex::OCDEXOHLCV;
+use crate::schemas::data_api::overview::OverviewMktCapOHLCV;
+
+
+/// Candle (OHLCV data point) of any market segment, which allows the analytics, storage and export code to be written once for all segments.
+///
+/// # Examples
+///
+/// ```rust
+/// use ccdata_api::{Candle, Numeric, SpotOHLCV, FuturesOHLCV};
+///
+/// fn closes<C: Candle>(candles: &[C]) -> Vec<Numeric> {
+///     candles.iter().map(|v| v.close() ).collect()
+/// }
+///
+/// let spot: Vec<SpotOHLCV> = vec![SpotOHLCV { close: Numeric::from(100), ..Default::default() }];
+/// let futures: Vec<FuturesOHLCV> = vec![FuturesOHLCV { close: Numeric::from(101), ..Default::default() }];
+/// assert_eq!(closes(&spot), vec![Numeric::from(100)]);
+/// assert_eq!(closes(&futures), vec![Numeric::from(101)]);
+/// ```
+pub trait Candle: Timestamped {
+    /// Returns the open price of the interval.
+    fn open(&self) -> Numeric;
+
+    /// Returns the highest price of the interval.
+    fn high(&self) -> Numeric;
+
+    /// Returns the lowest price of the interval.
+    fn low(&self) -> Numeric;
+
+    /// Returns the close price of the interval.
+    fn close(&self) -> Numeric;
+
+    /// Returns the volume of the interval in the base asset (`None` if the segment does not report it).
+    fn volume(&self) -> Option<Numeric>;
+
+    /// Returns the volume of the interval in the quote asset (`None` if the segment does not report it).
+    fn quote_volume(&self) -> Option<Numeric>;
+}
+
+macro_rules! impl_candle {
+    ($($t:ty => ($volume:expr, $quote_volume:expr)),*) => {
+        $(
+            impl Candle for $t {
+                fn open(&self) -> Numeric {
+                    self.open
+                }
+
+                fn high(&self) -> Numeric {
+                    self.high
+                }
+
+                fn low(&self) -> Numeric {
+                    self.low
+                }
+
+                fn close(&self) -> Numeric {
+                    self.close
+                }
+
+                fn volume(&self) -> Option<Numeric> {
+                    let volume: fn(&Self) -> Option<Numeric> = $volume;
+                    volume(self)
+                }
+
+                fn quote_volume(&self) -> Option<Numeric> {
+                    let quote_volume: fn(&Self) -> Option<Numeric> = $quote_volume;
+                    quote_volume(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_candle!(
+    SpotOHLCV => (|v| Some(v.volume), |v| Some(v.quote_volume)),
+    FuturesOHLCV => (|v| Some(v.volume), |v| Some(v.quote_volume)),
+    OptionsOHLCV => (|v| Some(v.volume), |v| Some(v.quote_volume)),
+    IndicesOHLCV => (|v| Some(v.volume), |v| Some(v.quote)),
+    DerIndicesOHLCV => (|_| None, |_| None),
+    OCDEXOHLCV => (|v| Some(v.volume), |v| Some(v.quote_volume)),
+    // Top-tier volume of all assets is reported in USD
+    OverviewMktCapOHLCV => (|_| None, |v| Some(v.top_tier_volume))
+);
+
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+/// Normalized candle that contains the fields shared by the OHLCV schemas of all market segments.
+///
+/// # Examples
+///
+/// ```rust
+/// use ccdata_api::{OHLCV, Numeric, SpotOHLCV};
+///
+/// let spot: SpotOHLCV = SpotOHLCV { timestamp: 1728860400, close: Numeric::from(100), ..Default::default() };
+/// let candle: OHLCV = OHLCV::from(&spot);
+/// assert_eq!(candle.timestamp, 1728860400);
+/// assert_eq!(candle.close, Numeric::from(100));
+/// ```
+pub struct OHLCV {
+    /// Timestamp (in seconds) of the beginning of the interval
+    pub timestamp: i64,
+    /// Open price of the interval
+    pub open: Numeric,
+    /// Highest price of the interval
+    pub high: Numeric,
+    /// Lowest price of the interval
+    pub low: Numeric,
+    /// Close price of the interval
+    pub close: Numeric,
+    /// Volume of the interval in the base asset
+    pub volume: Option<Numeric>,
+    /// Volume of the interval in the quote asset
+    pub quote_volume: Option<Numeric>,
+}
+
+impl Timestamped for OHLCV {
+    fn timestamp(&self) -> i64 {
+        self.timestamp
+    }
+}
+
+impl Candle for OHLCV {
+    fn open(&self) -> Numeric {
+        self.open
+    }
+
+    fn high(&self) -> Numeric {
+        self.high
+    }
+
+    fn low(&self) -> Numeric {
+        self.low
+    }
+
+    fn close(&self) -> Numeric {
+        self.close
+    }
+
+    fn volume(&self) -> Option<Numeric> {
+        self.volume
+    }
+
+    fn quote_volume(&self) -> Option<Numeric> {
+        self.quote_volume
+    }
+}
+
+impl<C: Candle> From<&C> for OHLCV {
+    fn from(value: &C) -> Self {
+        Self {
+            timestamp: value.timestamp(), open: value.open(), high: value.high(), low: value.low(), close: value.close(), volume: value.volume(),
+            quote_volume: value.quote_volume(),
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+
+    #[test]
+    fn unit_test_candle() -> () {
+        use crate::candle::{Candle, OHLCV};
+        use crate::schemas::Numeric;
+        use crate::schemas::data_api::indices_and_reference_rates::IndicesOHLCV;
+        use crate::schemas::data_api::derivatives_indices::DerIndicesOHLCV;
+        use crate::schemas::data_api::overview::OverviewMktCapOHLCV;
+        let index: IndicesOHLCV = IndicesOHLCV {
+            timestamp: 3600, open: Numeric::from(1), high: Numeric::from(4), low: Numeric::from(1), close: Numeric::from(3),
+            volume: Numeric::from(10), quote: Numeric::from(25), ..Default::default()
+        };
+        let candle: OHLCV = OHLCV::from(&index);
+        assert_eq!(candle, OHLCV {
+            timestamp: 3600, open: Numeric::from(1), high: Numeric::from(4), low: Numeric::from(1), close: Numeric::from(3),
+            volume: Some(Numeric::from(10)), quote_volume: Some(Numeric::from(25)),
+        });
+        assert_eq!(OHLCV::from(&candle), candle);
+        // Segments without volumes
+        let der_index: DerIndicesOHLCV = DerIndicesOHLCV { close: Numeric::from(2), ..Default::default() };
+        assert_eq!((der_index.close(), der_index.volume(), der_index.quote_volume()), (Numeric::from(2), None, None));
+        let mktcap: OverviewMktCapOHLCV = OverviewMktCapOHLCV { top_tier_volume: Numeric::from(7), ..Default::default() };
+        assert_eq!((mktcap.volume(), mktcap.quote_volume()), (None, Some(Numeric::from(7))));
+    }
+}
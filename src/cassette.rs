@@ -0,0 +1,156 @@
+use std::{fmt::Display, fs, path::PathBuf};
+use serde::{Serialize, Deserialize};
+use reqwest::{StatusCode, header::HeaderMap};
+use crate::error::Error;
+use crate::cache::fnv1a;
+use crate::utils::RawResponse;
+
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// Mode of the cassette.
+pub enum CassetteMode {
+    /// Requests are sent to CoinDesk API and the responses are saved to the cassette directory
+    Record,
+    /// Responses are served from the cassette directory without sending any requests
+    Replay,
+}
+
+impl Display for CassetteMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Record => write!(f, "record"),
+            Self::Replay => write!(f, "replay"),
+        }
+    }
+}
+
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+/// Request/response pair stored in the cassette directory.
+struct CassetteEntry {
+    /// Endpoint URL with the normalized query string (Does not contain the API key)
+    request: String,
+    /// HTTP status code of the response
+    status: u16,
+    /// Response body
+    body: String,
+}
+
+
+#[derive(Clone, Debug)]
+/// Record/replay storage of the API responses that allows running the code offline and deterministically (e.g., tests in CI).
+///
+/// Every request/response pair is stored as a separate JSON file in the cassette directory. The stored request consists of
+/// the endpoint URL and the normalized query parameters, the API key is never stored.
+///
+/// # Examples
+///
+/// ```rust
+/// use ccdata_api::{CoinDesk, Cassette, CassetteMode};
+///
+/// let mut backend: CoinDesk = CoinDesk::new();
+///
+/// // Responses are served from the directory, so the backend does not need the API key
+/// let cassette: Cassette = Cassette::new(CassetteMode::Replay, "tests/cassettes").unwrap();
+/// backend.update_cassette(Some(cassette));
+/// ```
+pub struct Cassette {
+    mode: CassetteMode,
+    directory: PathBuf,
+}
+
+impl Cassette {
+    /// Creates a cassette, creating the directory if it does not exist in the record mode.
+    ///
+    /// # Input
+    /// - `mode`: Record or replay mode
+    /// - `directory`: Directory where the request/response pairs are stored
+    pub fn new<P: Into<PathBuf>>(mode: CassetteMode, directory: P) -> Result<Self, Error> {
+        let directory: PathBuf = directory.into();
+        if mode == CassetteMode::Record {
+            fs::create_dir_all(&directory)?;
+        }
+        Ok(Self { mode, directory, })
+    }
+
+    /// Creates a cassette from the `CCDATA_API_CASSETTE` environment variable (`record` or `replay`).
+    ///
+    /// Note: Returns `None` if the `CCDATA_API_CASSETTE` environment variable is not set.
+    ///
+    /// # Input
+    /// - `directory`: Directory where the request/response pairs are stored
+    pub fn from_env<P: Into<PathBuf>>(directory: P) -> Result<Option<Self>, Error> {
+        let _ = dotenv::dotenv();
+        let mode: CassetteMode = match std::env::var("CCDATA_API_CASSETTE") {
+            Ok(v) => match v.trim().to_lowercase().as_str() {
+                "record" => CassetteMode::Record,
+                "replay" => CassetteMode::Replay,
+                _ => return Err(Error::InvalidCassetteMode(v)),
+            },
+            Err(_) => return Ok(None),
+        };
+        Ok(Some(Self::new(mode, directory)?))
+    }
+
+    /// Returns the mode of the cassette.
+    pub fn mode(&self) -> CassetteMode {
+        self.mode
+    }
+
+    /// Returns the directory where the request/response pairs are stored.
+    pub fn directory(&self) -> &PathBuf {
+        &self.directory
+    }
+
+    /// Path of the file that stores the response to the request.
+    ///
+    /// Note: The file name starts with the endpoint path so that the cassettes are easy to find (e.g., `spot_v1_historical_days-<hash>.json`).
+    fn path(&self, request: &str) -> PathBuf {
+        let endpoint: &str = request.split('?').next().unwrap_or_default();
+        let endpoint: &str = endpoint.split_once("://").map_or(endpoint, |(_, v)| v.split_once('/').map_or("", |(_, path)| path ) );
+        let name: String = endpoint.chars().map(|c| if c.is_ascii_alphanumeric() { c } else { '_' } ).collect();
+        self.directory.join(format!("{}-{:016x}.json", name, fnv1a(request.as_bytes())))
+    }
+
+    /// Saves the response to the request.
+    pub(crate) fn record(&self, request: &str, response: &RawResponse) -> Result<(), Error> {
+        let entry: CassetteEntry = CassetteEntry { request: request.to_owned(), status: response.status.as_u16(), body: response.body.clone(), };
+        fs::write(self.path(request), serde_json::to_string_pretty(&entry)?)?;
+        Ok(())
+    }
+
+    /// Reads the recorded response to the request.
+    pub(crate) fn replay(&self, request: &str) -> Result<RawResponse, Error> {
+        let contents: String = fs::read_to_string(self.path(request)).map_err(|_| Error::MissingCassette { request: request.to_owned(), } )?;
+        let entry: CassetteEntry = serde_json::from_str(&contents)?;
+        if entry.request != request {
+            return Err(Error::MissingCassette { request: request.to_owned(), });
+        }
+        let status: StatusCode = StatusCode::from_u16(entry.status).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+        Ok(RawResponse { status, headers: HeaderMap::new(), body: entry.body, })
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+
+    #[test]
+    fn unit_test_cassette() -> () {
+        use reqwest::{StatusCode, header::HeaderMap};
+        use crate::error::Error;
+        use crate::utils::RawResponse;
+        use crate::cassette::{Cassette, CassetteMode};
+        let directory = std::env::temp_dir().join(format!("ccdata_api_cassette_{}", std::process::id()));
+        let request: &str = "https://data-api.coindesk.com/spot/v1/historical/days?instrument=BTC-USD&market=kraken";
+        let recorder: Cassette = Cassette::new(CassetteMode::Record, &directory).unwrap();
+        recorder.record(request, &RawResponse { status: StatusCode::OK, headers: HeaderMap::new(), body: String::from("{\"Data\":[]}"), }).unwrap();
+        assert!(recorder.path(request).file_name().unwrap().to_str().unwrap().starts_with("spot_v1_historical_days-"));
+        let player: Cassette = Cassette::new(CassetteMode::Replay, &directory).unwrap();
+        let response: RawResponse = player.replay(request).unwrap();
+        assert_eq!(response.status, StatusCode::OK);
+        assert_eq!(response.body, "{\"Data\":[]}");
+        assert!(matches!(player.replay("https://data-api.coindesk.com/news/v1/source/list?"), Err(Error::MissingCassette { .. })));
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+}
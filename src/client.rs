@@ -0,0 +1,136 @@
+use std::time::Duration;
+use reqwest::{Client, ClientBuilder, Proxy, Certificate};
+use crate::error::Error;
+
+
+/// Default user agent sent with every request.
+pub const DEFAULT_USER_AGENT: &str = concat!("ccdata-api/", env!("CARGO_PKG_VERSION"));
+
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+/// HTTP protocol version preference of the client.
+pub enum HTTPVersion {
+    #[default]
+    /// Negotiate the protocol version with the server (HTTP/2 is used via ALPN when available)
+    Auto,
+    /// Only use HTTP/1
+    HTTP1Only,
+    /// Only use HTTP/2 (prior knowledge)
+    HTTP2PriorKnowledge,
+}
+
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+/// Method of passing the API key to CoinDesk API.
+pub enum AuthMethod {
+    #[default]
+    /// API key is sent in the `Authorization: Apikey ...` header
+    Header,
+    /// API key is added to the query string of the URL as the `api_key` parameter
+    ///
+    /// Note: The API key will be visible to any proxy between the client and CoinDesk API.
+    QueryString,
+}
+
+
+#[derive(Clone, Debug)]
+/// Configuration of the HTTP client owned by the `CoinDesk` backend.
+///
+/// The client built from this configuration is reused for every request, which enables connection pooling
+/// across the calls made by the backend.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::time::Duration;
+/// use ccdata_api::{CoinDesk, ClientConfig};
+///
+/// let config: ClientConfig = ClientConfig {
+///     timeout: Some(Duration::from_secs(60)),
+///     proxy: Some(String::from("socks5h://127.0.0.1:1080")),
+///     ..Default::default()
+/// };
+/// let backend: CoinDesk = CoinDesk::with_client_config(config).unwrap();
+/// ```
+pub struct ClientConfig {
+    /// Total timeout of a request (from connecting until the response body has been read)
+    pub timeout: Option<Duration>,
+    /// Timeout of the connect phase of a request
+    pub connect_timeout: Option<Duration>,
+    /// Timeout for idle sockets being kept alive in the connection pool
+    pub pool_idle_timeout: Option<Duration>,
+    /// Maximum number of idle connections kept alive per host
+    pub pool_max_idle_per_host: Option<usize>,
+    /// Proxy URL used for all requests (e.g., `http://proxy:8080`, `socks5h://127.0.0.1:1080`)
+    pub proxy: Option<String>,
+    /// Value of the `User-Agent` header
+    pub user_agent: Option<String>,
+    /// Additional PEM-encoded root certificates to trust (e.g., a corporate CA)
+    pub root_certificates: Vec<Vec<u8>>,
+    /// HTTP protocol version preference
+    pub http_version: HTTPVersion,
+}
+
+impl Default for ClientConfig {
+    fn default() -> Self {
+        Self {
+            timeout: Some(Duration::from_secs(30)),
+            connect_timeout: Some(Duration::from_secs(10)),
+            pool_idle_timeout: Some(Duration::from_secs(90)),
+            pool_max_idle_per_host: None,
+            proxy: None,
+            user_agent: Some(DEFAULT_USER_AGENT.to_owned()),
+            root_certificates: Vec::new(),
+            http_version: HTTPVersion::Auto,
+        }
+    }
+}
+
+impl ClientConfig {
+    /// Builds a `reqwest::Client` from the configuration.
+    pub fn build_client(&self) -> Result<Client, Error> {
+        let mut builder: ClientBuilder = Client::builder().pool_idle_timeout(self.pool_idle_timeout);
+        if let Some(timeout) = self.timeout {
+            builder = builder.timeout(timeout);
+        }
+        if let Some(connect_timeout) = self.connect_timeout {
+            builder = builder.connect_timeout(connect_timeout);
+        }
+        if let Some(max_idle) = self.pool_max_idle_per_host {
+            builder = builder.pool_max_idle_per_host(max_idle);
+        }
+        if let Some(proxy) = &self.proxy {
+            builder = builder.proxy(Proxy::all(proxy.as_str())?);
+        }
+        if let Some(user_agent) = &self.user_agent {
+            builder = builder.user_agent(user_agent.as_str());
+        }
+        for pem in &self.root_certificates {
+            builder = builder.add_root_certificate(Certificate::from_pem(pem)?);
+        }
+        builder = match self.http_version {
+            HTTPVersion::Auto => builder,
+            HTTPVersion::HTTP1Only => builder.http1_only(),
+            HTTPVersion::HTTP2PriorKnowledge => builder.http2_prior_knowledge(),
+        };
+        Ok(builder.build()?)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+
+    #[test]
+    fn unit_test_build_client() -> () {
+        use crate::client::{ClientConfig, HTTPVersion};
+        let config: ClientConfig = ClientConfig {
+            proxy: Some(String::from("socks5h://127.0.0.1:1080")),
+            http_version: HTTPVersion::HTTP1Only,
+            ..Default::default()
+        };
+        assert!(config.build_client().is_ok());
+        let config: ClientConfig = ClientConfig { proxy: Some(String::from("not a proxy url")), ..Default::default() };
+        assert!(config.build_client().is_err());
+    }
+}
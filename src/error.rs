@@ -1,37 +1,82 @@
 // Error types
-use std::{error::Error as ErrorTrait, env::VarError};
+use std::{error::Error as ErrorTrait, env::VarError, io::Error as IOError};
 use dotenv::Error as DotenvError;
 use serde_json::Error as SerdeJSONError;
 use reqwest::Error as ReqwestError;
+use url::ParseError as URLParseError;
 // Dependencies
-use std::{fmt::Display, convert::From};
+use std::{fmt::Display, convert::From, time::Duration};
+use crate::utils::redact_url;
+use crate::schemas::CCError;
 
 
 #[derive(Debug)]
 pub enum Error {
     NoAPIKey,
+    InvalidAPIKey,
+    // API errors
+    /// Response with an unsuccessful HTTP status code that does not contain a CoinDesk error object
+    Http { status: u16, body: String, },
+    /// Error object returned by CoinDesk API
+    Api(Box<CCError>),
+    /// Response with `429 Too Many Requests` status code
+    RateLimited { retry_after: Option<Duration>, },
+    /// Response with `401 Unauthorized` or `403 Forbidden` status code
+    Unauthorized(Option<Box<CCError>>),
+    /// Response with `404 Not Found` status code
+    NotFound(Option<Box<CCError>>),
+    // Cassette errors
+    /// Request has no recorded response in the cassette directory
+    MissingCassette { request: String, },
+    /// Value of the `CCDATA_API_CASSETTE` environment variable is neither `record` nor `replay`
+    InvalidCassetteMode(String),
     // Std errors
     VarError(VarError),
+    IOError(IOError),
     // Dotenv errors
     DotenvError(DotenvError),
     // Serde JSON errors
     SerdeJSONError(SerdeJSONError),
     // Reqwest errors
     ReqwestError(ReqwestError),
+    // URL errors
+    URLParseError(URLParseError),
 }
 
 impl Display for Error {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Self::NoAPIKey => write!(f, "No API Key: No API key is defined."),
+            Self::InvalidAPIKey => write!(f, "Invalid API Key: API key contains characters that are not allowed in the HTTP header."),
+            // API errors
+            Self::Http { status, body } => write!(f, "HTTP Error: Status code {status}: {body}"),
+            Self::Api(e) => write!(f, "API Error: {}", e),
+            Self::RateLimited { retry_after } => match retry_after {
+                Some(v) => write!(f, "Rate Limited: Too many requests, retry after {} seconds.", v.as_secs_f64()),
+                None => write!(f, "Rate Limited: Too many requests."),
+            },
+            Self::Unauthorized(e) => match e {
+                Some(e) => write!(f, "Unauthorized: {}", e),
+                None => write!(f, "Unauthorized: API key is missing, invalid or does not have access to the requested data."),
+            },
+            Self::NotFound(e) => match e {
+                Some(e) => write!(f, "Not Found: {}", e),
+                None => write!(f, "Not Found: The requested data does not exist."),
+            },
+            // Cassette errors
+            Self::MissingCassette { request } => write!(f, "Missing Cassette: No recorded response for the request {request}."),
+            Self::InvalidCassetteMode(v) => write!(f, "Invalid Cassette Mode: Expected `record` or `replay`, found `{v}`."),
             // Std errors
             Self::VarError(e) => write!(f, "Var Error: {}", e.to_string()),
+            Self::IOError(e) => write!(f, "IO Error: {}", e),
             // Dotenv errors
             Self::DotenvError(e) => write!(f, "Dotenv Error: {}", e.to_string()),
             // Serde JSON errors
             Self::SerdeJSONError(e) => write!(f, "Serde JSON Error: {}", e.to_string()), 
             // Reqwest errors
             Self::ReqwestError(e) => write!(f, "Reqwest Error: {}", e.to_string()),
+            // URL errors
+            Self::URLParseError(e) => write!(f, "URL Parse Error: {}", e),
         }
     }
 }
@@ -44,6 +89,12 @@ impl From<VarError> for Error {
     }
 }
 
+impl From<IOError> for Error {
+    fn from(value: IOError) -> Self {
+        Self::IOError(value)
+    }
+}
+
 impl From<DotenvError> for Error {
     fn from(value: DotenvError) -> Self {
         Self::DotenvError(value)
@@ -57,7 +108,15 @@ impl From<SerdeJSONError> for Error {
 }
 
 impl From<ReqwestError> for Error {
-    fn from(value: ReqwestError) -> Self {
+    fn from(mut value: ReqwestError) -> Self {
+        // Prevent the API key from leaking into error messages
+        if let Some(url) = value.url_mut() { redact_url(url) }
         Self::ReqwestError(value)
     }
-}
\ No newline at end of file
+}
+
+impl From<URLParseError> for Error {
+    fn from(value: URLParseError) -> Self {
+        Self::URLParseError(value)
+    }
+}
@@ -0,0 +1,309 @@
+use std::collections::BTreeMap;
+use crate::Unit;
+use crate::pagination::Timestamped;
+use crate::candle::OHLCV;
+use crate::resample::Resample;
+use crate::schemas::Numeric;
+use crate::schemas::data_api::indices_and_reference_rates::IndicesOHLCV;
+use crate::schemas::data_api::spot::SpotOHLCV;
+use crate::schemas::data_api::futures::FuturesOHLCV;
+use crate::schemas::data_api::options::OptionsOHLCV;
+use crate::schemas::data_api::derivatives_indices::DerIndicesOHLCV;
+use crate::schemas::data_api::on_chain_dex::OCDEXOHLCV;
+use crate::schemas::data_api::overview::OverviewMktCapOHLCV;
+
+
+/// Candle that can be carried forward into an interval without any data (e.g., an exchange outage).
+pub trait FillGap: Resample {
+    /// Returns a candle of the given interval with the open, high, low and close prices set to the close price of the candle,
+    /// and with zero volumes and trade counts.
+    ///
+    /// Note: Fields that describe the instrument (e.g., `market`, `instrument`, `base`) are copied from the candle, and the first/last trades are left empty.
+    ///
+    /// # Input
+    /// - `timestamp`: Timestamp (in seconds) of the beginning of the interval without any data
+    fn forward_fill(&self, timestamp: i64) -> Self;
+}
+
+macro_rules! impl_fill_gap {
+    ($($t:ty => [$($field:ident),*]),*) => {
+        $(
+            impl FillGap for $t {
+                fn forward_fill(&self, timestamp: i64) -> Self {
+                    Self {
+                        timestamp, open: self.close, high: self.close, low: self.close, close: self.close,
+                        $( $field: self.$field.clone(), )*
+                        ..Default::default()
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_fill_gap!(
+    SpotOHLCV => [unit, type_, market, instrument, mapped_instrument, base, quote, base_id, quote_id, transform_function],
+    FuturesOHLCV => [
+        unit, type_, market, instrument, mapped_instrument, index_underlying, quote_currency, settlement_currency, contract_currency,
+        denomination_type, index_underlying_id, quote_currency_id, settlement_currency_id, contract_currency_id, transform_function
+    ],
+    OptionsOHLCV => [
+        unit, type_, market, instrument, mapped_instrument, index_underlying, quote_currency, settlement_currency, contract_currency,
+        strike_currency, index_underlying_id, quote_currency_id, settlement_currency_id, contract_currency_id, strike_currency_id, transform_function
+    ],
+    IndicesOHLCV => [unit, type_, market, instrument],
+    DerIndicesOHLCV => [unit, type_, market, instrument],
+    OverviewMktCapOHLCV => [unit, type_]
+);
+
+impl FillGap for OCDEXOHLCV {
+    fn forward_fill(&self, timestamp: i64) -> Self {
+        Self {
+            timestamp, open: self.close, high: self.close, low: self.close, close: self.close, unit: self.unit.clone(), type_: self.type_.clone(),
+            market: self.market.clone(), instrument: self.instrument.clone(), mapped_instrument: self.mapped_instrument.clone(),
+            base: self.base.clone(), quote: self.quote.clone(), base_id: self.base_id, quote_id: self.quote_id,
+            transform_function: self.transform_function.clone(),
+            // Volumes of the swaps with unknown side are stored as strings
+            volume_unknown: Numeric::default().to_string(), quote_volume_unknown: Numeric::default().to_string(),
+            ..Default::default()
+        }
+    }
+}
+
+impl FillGap for OHLCV {
+    fn forward_fill(&self, timestamp: i64) -> Self {
+        Self {
+            timestamp, open: self.close, high: self.close, low: self.close, close: self.close,
+            // Volumes stay missing if the segment does not report them
+            volume: self.volume.map(|_| Numeric::default() ), quote_volume: self.quote_volume.map(|_| Numeric::default() ),
+        }
+    }
+}
+
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+/// Policy that determines how the intervals without any data are filled by [`Grid::fill`].
+///
+/// Note: To refetch the missing intervals from CoinDesk API, use `CoinDesk::refetch_gaps`.
+pub enum FillPolicy {
+    #[default]
+    /// Carries the close price of the previous candle forward with zero volume (See `FillGap::forward_fill`)
+    ForwardFill,
+    /// Leaves the intervals without any data as `None`
+    Null,
+}
+
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+/// Irregularities of a series found by [`Grid::detect`].
+pub struct GapReport {
+    /// Ranges of the missing intervals as the timestamps of the first and the last missing interval (inclusive)
+    pub missing: Vec<(i64, i64)>,
+    /// Timestamps that occur more than once in the series
+    pub duplicates: Vec<i64>,
+    /// Positions of the entries whose timestamp is lower than the timestamp of the previous entry
+    pub out_of_order: Vec<usize>,
+    /// Timestamps that are not aligned to the grid
+    pub misaligned: Vec<i64>,
+}
+
+impl GapReport {
+    /// Returns `true` if the series is ordered, has no duplicates and covers every interval of the grid between its first and last entries.
+    pub fn is_regular(&self) -> bool {
+        self.missing.is_empty() && self.duplicates.is_empty() && self.out_of_order.is_empty() && self.misaligned.is_empty()
+    }
+}
+
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// Regular grid of intervals that a historical series (e.g., OHLCV data) is expected to follow.
+///
+/// The intervals are aligned to the Unix epoch: the interval that contains the timestamp `t` starts at `floor(t / step) * step`.
+///
+/// # Examples
+///
+/// ```rust
+/// use ccdata_api::{Grid, GapReport, FillPolicy, Unit, SpotOHLCV};
+///
+/// let hours: Vec<SpotOHLCV> = [0, 3_600, 14_400].map(|timestamp| SpotOHLCV { timestamp, ..Default::default() } ).to_vec();
+///
+/// let grid: Grid = Grid::from_unit(&Unit::Hour, 1).unwrap();
+/// let report: GapReport = grid.detect(&hours);
+/// assert_eq!(report.missing, vec![(7_200, 10_800)]);
+///
+/// let filled: Vec<Option<SpotOHLCV>> = grid.fill(&hours, FillPolicy::Null);
+/// assert_eq!(filled.iter().map(|v| v.is_some() ).collect::<Vec<bool>>(), vec![true, true, false, false, true]);
+/// ```
+pub struct Grid {
+    step: i64,
+}
+
+impl Grid {
+    /// Creates a grid with intervals of the given width.
+    ///
+    /// # Input
+    /// - `step`: Width of the intervals in seconds (Must be positive)
+    pub fn new(step: i64) -> Self {
+        assert!(0 < step, "Width of the intervals must be positive.");
+        Self { step, }
+    }
+
+    /// Creates a grid of the data returned by a request with the given `unit` and `aggregate` parameters.
+    ///
+    /// Note: Returns `None` for `Unit::NA`, which does not define an interval.
+    ///
+    /// # Input
+    /// - `unit`: Unit of the interval between successive data points
+    /// - `aggregate`: Number of data points aggregated into a single data point (`1` if the request does not set `aggregate`)
+    pub fn from_unit(unit: &Unit, aggregate: u32) -> Option<Self> {
+        let seconds: i64 = match unit {
+            Unit::Day => 86_400,
+            Unit::Hour => 3_600,
+            Unit::Minute => 60,
+            Unit::NA => return None,
+        };
+        Some(Self::new(seconds * i64::from(aggregate.max(1))))
+    }
+
+    /// Returns the width of the intervals in seconds.
+    pub fn step(&self) -> i64 {
+        self.step
+    }
+
+    /// Returns `true` if the timestamp is the beginning of an interval.
+    ///
+    /// # Input
+    /// - `timestamp`: Timestamp in seconds
+    pub fn is_aligned(&self, timestamp: i64) -> bool {
+        timestamp.rem_euclid(self.step) == 0
+    }
+
+    /// Checks the series against the grid, reporting the missing intervals, duplicates, out-of-order entries and misaligned timestamps.
+    ///
+    /// Note: Missing intervals are only reported between the first and the last aligned timestamps of the series.
+    ///
+    /// # Input
+    /// - `series`: Data points of the same instrument (Any type that implements `Timestamped`)
+    pub fn detect<T: Timestamped>(&self, series: &[T]) -> GapReport {
+        let mut report: GapReport = GapReport::default();
+        let mut counts: BTreeMap<i64, usize> = BTreeMap::new();
+        let mut previous: Option<i64> = None;
+        for (i, v) in series.iter().enumerate() {
+            let timestamp: i64 = v.timestamp();
+            if previous.is_some_and(|p| timestamp < p ) {
+                report.out_of_order.push(i);
+            }
+            previous = Some(timestamp);
+            *counts.entry(timestamp).or_insert(0) += 1;
+        }
+        let mut last: Option<i64> = None;
+        for (timestamp, count) in counts {
+            if 1 < count {
+                report.duplicates.push(timestamp);
+            }
+            if !self.is_aligned(timestamp) {
+                report.misaligned.push(timestamp);
+                continue;
+            }
+            if let Some(last) = last {
+                if last + self.step < timestamp {
+                    report.missing.push((last + self.step, timestamp - self.step));
+                }
+            }
+            last = Some(timestamp);
+        }
+        report
+    }
+
+    /// Returns one entry per interval of the grid between the first and the last aligned timestamps of the series, ordered by timestamp,
+    /// with the intervals without any data filled according to the policy.
+    ///
+    /// Note: The series is ordered by timestamp before it is filled. Of the duplicates, the last entry of the series is kept, and the entries
+    /// with misaligned timestamps are dropped (use `Resampler` to align them to the grid first).
+    ///
+    /// # Input
+    /// - `series`: Candles of the same instrument (Any type that implements `FillGap`)
+    /// - `policy`: Policy that determines how the intervals without any data are filled
+    pub fn fill<C: FillGap>(&self, series: &[C], policy: FillPolicy) -> Vec<Option<C>> {
+        let candles: BTreeMap<i64, &C> = series.iter().filter(|v| self.is_aligned(v.timestamp()) ).map(|v| (v.timestamp(), v) ).collect();
+        let mut filled: Vec<Option<C>> = Vec::new();
+        let mut previous: Option<(i64, &C)> = None;
+        for (timestamp, candle) in candles {
+            if let Some((last, last_candle)) = previous {
+                for gap in ((last + self.step)..timestamp).step_by(self.step as usize) {
+                    filled.push(match policy {
+                        FillPolicy::ForwardFill => Some(last_candle.forward_fill(gap)),
+                        FillPolicy::Null => None,
+                    });
+                }
+            }
+            filled.push(Some(candle.clone()));
+            previous = Some((timestamp, candle));
+        }
+        filled
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+
+    #[test]
+    fn unit_test_detect_gaps() -> () {
+        use crate::Unit;
+        use crate::gaps::{Grid, GapReport};
+        use crate::schemas::data_api::futures::FuturesOHLCV;
+        let candle = |timestamp: i64| -> FuturesOHLCV { FuturesOHLCV { timestamp, ..Default::default() } };
+        assert_eq!(Grid::from_unit(&Unit::Minute, 5).unwrap().step(), 300);
+        assert_eq!(Grid::from_unit(&Unit::NA, 1), None);
+        let grid: Grid = Grid::from_unit(&Unit::Hour, 1).unwrap();
+        // Regular series
+        let hours: Vec<FuturesOHLCV> = (0..5).map(|i| candle(3_600 * i) ).collect();
+        assert!(grid.detect(&hours).is_regular());
+        assert!(grid.detect(&Vec::<FuturesOHLCV>::new()).is_regular());
+        // Missing, duplicate, out-of-order and misaligned entries
+        let hours: Vec<FuturesOHLCV> = vec![candle(0), candle(3_600), candle(18_000), candle(3_600), candle(21_600), candle(22_000), candle(36_000)];
+        let report: GapReport = grid.detect(&hours);
+        assert!(!report.is_regular());
+        assert_eq!(report, GapReport {
+            missing: vec![(7_200, 14_400), (25_200, 32_400)], duplicates: vec![3_600], out_of_order: vec![3], misaligned: vec![22_000],
+        });
+    }
+
+    #[test]
+    fn unit_test_fill_gaps() -> () {
+        use crate::candle::OHLCV;
+        use crate::gaps::{Grid, FillPolicy};
+        use crate::schemas::Numeric;
+        use crate::schemas::data_api::spot::SpotOHLCV;
+        let n = |v: i32| -> Numeric { Numeric::from(v) };
+        let candle = |timestamp: i64, close: i32| -> SpotOHLCV {
+            SpotOHLCV {
+                timestamp, market: String::from("kraken"), instrument: String::from("XBT-USD"), open: n(close - 1), high: n(close + 1), low: n(close - 2),
+                close: n(close), volume: n(10), quote_volume: n(100), total_trades: 5, first_trade_timestamp: Some(timestamp), ..Default::default()
+            }
+        };
+        let grid: Grid = Grid::new(60);
+        let minutes: Vec<SpotOHLCV> = vec![candle(180, 12), candle(0, 10), candle(60, 11), candle(60, 9)];
+        // Null
+        let filled: Vec<Option<SpotOHLCV>> = grid.fill(&minutes, FillPolicy::Null);
+        assert_eq!(filled.len(), 4);
+        assert!(filled[2].is_none());
+        // Last duplicate is kept
+        assert_eq!(filled[1].as_ref().unwrap().close, n(9));
+        // Forward fill
+        let filled: Vec<SpotOHLCV> = grid.fill(&minutes, FillPolicy::ForwardFill).into_iter().map(|v| v.unwrap() ).collect();
+        assert_eq!(filled.iter().map(|v| v.timestamp ).collect::<Vec<i64>>(), vec![0, 60, 120, 180]);
+        let gap: &SpotOHLCV = &filled[2];
+        assert_eq!((gap.market.as_str(), gap.instrument.as_str()), ("kraken", "XBT-USD"));
+        assert_eq!((gap.open, gap.high, gap.low, gap.close), (n(9), n(9), n(9), n(9)));
+        assert_eq!((gap.volume, gap.quote_volume, gap.total_trades, gap.first_trade_timestamp), (n(0), n(0), 0, None));
+        // Normalized candles
+        let candles: Vec<OHLCV> = vec![
+            OHLCV { timestamp: 0, close: n(3), volume: Some(n(5)), ..Default::default() },
+            OHLCV { timestamp: 120, close: n(4), volume: Some(n(5)), ..Default::default() },
+        ];
+        let filled: Vec<Option<OHLCV>> = grid.fill(&candles, FillPolicy::ForwardFill);
+        assert_eq!(filled[1], Some(OHLCV { timestamp: 60, open: n(3), high: n(3), low: n(3), close: n(3), volume: Some(n(0)), quote_volume: None, }));
+    }
+}
@@ -15,16 +15,28 @@
 //! # Errors
 //!
 //! The REST API functions in the crate will error if the data received does not fit into the pre-defined schemas provided
-//! in the crate. If you encounter any errors, please open an issue on GitHub with the parameters that you have used (e.g., asset symbol,
-//! timestamp, limit, etc.). **Do not provide your API key or any personal data!**
+//! in the crate. Fields that depend on the `groups` parameter take their default values if their groups were not requested, but the identity
+//! and timestamp fields (e.g., `TIMESTAMP`, `MARKET`, `INSTRUMENT`) are required. If you encounter any errors, please open an issue on GitHub
+//! with the parameters that you have used (e.g., asset symbol, timestamp, limit, etc.). **Do not provide your API key or any personal data!**
+//!
+//! Responses with unsuccessful HTTP status codes are converted into typed errors: `Error::Unauthorized` (`401`/`403`), `Error::NotFound` (`404`),
+//! `Error::RateLimited` (`429`), `Error::Api` (CoinDesk error object) and `Error::Http` (any other status code). Use `CoinDeskResponse::into_result`
+//! to convert a response with a populated `Err` object into an error.
 //! 
 //! # Deprecated API Endpoints
 //! 
 //! Some API endpoints have been deprecated by CoinDesk, who strongly recommend migrating to newer alternatives suggested in their API documentation.
 //! 
 //! # Features
-//! - `debug`: If this feature is enabled, you can set `CCDATA_API_DEBUG` environment variable to `true`, which will print the response body
-//! for every request to the command line.
+//! - `tracing`: Emits a `tracing` span for every API call with the endpoint, unit, parameters (without the API key), HTTP status code, latency,
+//!   body size and number of retries. Response bodies are logged at the `TRACE` level.
+//! - `debug`: Alias of the `tracing` feature (Response bodies are logged at the `TRACE` level instead of being printed to the command line).
+//! - `blocking`: Enables `CoinDeskBlocking`, the blocking (synchronous) version of the backend.
+//! - `chrono`: Time parameters accept `chrono::DateTime`, and `Timestamped::datetime_utc` returns the timestamps of the data points as `DateTime<Utc>`.
+//! - `time`: Time parameters accept `time::OffsetDateTime`, and `Timestamped::offset_datetime` returns the timestamps of the data points
+//!   as `OffsetDateTime`.
+//! - `decimal`: Price, volume, size and supply fields of the schemas (`Numeric`) are deserialized into `rust_decimal::Decimal` without losing
+//!   precision (Enables the `arbitrary_precision` feature of `serde_json`).
 //!
 //! # Examples
 //!
@@ -92,12 +104,34 @@
 
 // Re-Exports
 pub use self::backend::CoinDesk;
-pub use self::utils::{Group, AssetLookupPriority, Param, call_api_endpoint};
+#[cfg(feature = "blocking")]
+pub use self::blocking::CoinDeskBlocking;
+pub use self::client::{ClientConfig, HTTPVersion, AuthMethod};
+pub use self::retry::{RetryPolicy, RetryEvent, RetryReason};
+pub use self::rate_limit::{RateLimiter, RateLimitWindow};
+pub use self::cache::{Cache, CachePolicy, MemoryCache, FileCache};
+pub use self::cassette::{Cassette, CassetteMode};
+pub use self::middleware::{Middleware, MiddlewareFuture};
+pub use self::timestamp::IntoTimestamp;
+pub use self::pagination::{Timestamped, HistoricalEndpoint};
+pub use self::candle::{Candle, OHLCV};
+pub use self::resample::{Resample, Resampler};
+pub use self::gaps::{FillGap, FillPolicy, GapReport, Grid};
+pub use self::batch::OHLCVEndpoint;
+pub use self::request::{
+    Endpoint, BalanceDistributionRequest, IndicesOHLCVRequest, SpotOHLCVRequest, SpotInstrumentMetadataRequest, SpotMarketsRequest,
+    SpotMarketsInstrumentsRequest, FuturesOHLCVRequest, FuturesInstrumentMetadataRequest, FuturesMarketsRequest, OptionsOHLCVRequest,
+    OptionsInstrumentMetadataRequest, OptionsMarketsRequest, DerIndicesOHLCVRequest, DerIndicesMarketsRequest, OCDEXOHLCVRequest,
+    OCDEXMarketsRequest, OCCoreETHBlockRequest, OCCoreAssetsByChainRequest, OCCoreAssetByAddressRequest, OCCoreSupplyRequest,
+    AssetMetadataRequest, AssetEventsRequest, AssetCodeRepoRequest, AssetDiscordRequest, AssetRedditRequest, AssetTelegramRequest,
+    AssetTwitterRequest, NewsLatestArticlesRequest, NewsSourcesRequest, NewsCategoriesRequest, OverviewMktCapOHLCVRequest,
+};
+pub use self::utils::{Group, AssetLookupPriority, Param, RawResponse, call_api_endpoint, call_backend_endpoint};
 // Min-API Re-Exports
 pub use self::schemas::{CCMinResponse, CCMinWrapper, CCRateLimit, CCMaxCalls, CCCallsMade};
 pub use self::schemas::min_api::{BalanceDistribution, SupplyBand};
 // Data-API Re-Exports
-pub use self::schemas::{CoinDeskResponse, CCError, CCErrorOtherInfo};
+pub use self::schemas::{CoinDeskResponse, CCError, CCErrorOtherInfo, Numeric};
 pub use self::schemas::data_api::indices_and_reference_rates::{IndicesMarket, IndicesOHLCV};
 pub use self::schemas::data_api::spot::{SpotMarket, SpotInstrumentStatus, SpotOHLCV, SpotInstrumentMetdata, SpotMarkets, SpotMarketsInstruments};
 pub use self::schemas::data_api::futures::{FuturesMarket, FuturesOHLCV, FuturesInstrumentMetadata, FuturesMarkets};
@@ -113,7 +147,24 @@ pub use self::schemas::data_api::overview::OverviewMktCapOHLCV;
 pub mod error;
 pub mod schemas;
 pub mod utils;
+pub mod client;
+pub mod retry;
+pub mod rate_limit;
+pub mod cache;
+pub mod cassette;
+pub mod middleware;
+pub mod request;
+pub mod timestamp;
+pub mod pagination;
+pub mod candle;
+pub mod resample;
+pub mod gaps;
+pub mod batch;
 pub mod backend;
+#[cfg(feature = "blocking")]
+pub mod blocking;
+#[cfg(test)]
+mod test_utils;
 
 
 use std::fmt::Display;
@@ -145,6 +196,60 @@ impl Display for Unit {
 }
 
 
+/// Default URL of the CoinDesk Data API.
+pub const DATA_API_URL: &str = "https://data-api.coindesk.com";
+/// Default URL of the CoinDesk Min-API (Legacy API).
+pub const MIN_API_URL: &str = "https://min-api.cryptocompare.com";
+
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+/// Base URLs of the CoinDesk API hosts used by the backend.
+///
+/// Overriding the base URLs allows the backend to send requests to a local mock server, a caching proxy or a staging mirror
+/// instead of the CoinDesk API hosts.
+///
+/// # Examples
+///
+/// ```rust
+/// use ccdata_api::{CoinDesk, BaseURL};
+///
+/// let mut backend: CoinDesk = CoinDesk::new();
+/// backend.update_base_url(BaseURL { data_api: String::from("http://127.0.0.1:8080"), ..Default::default() });
+///
+/// assert_eq!(backend.base_url().rebase("https://data-api.coindesk.com/spot/v1/historical/days"), "http://127.0.0.1:8080/spot/v1/historical/days");
+/// ```
+pub struct BaseURL {
+    /// Base URL that replaces the default Data API URL (`https://data-api.coindesk.com`)
+    pub data_api: String,
+    /// Base URL that replaces the default Min-API URL (`https://min-api.cryptocompare.com`)
+    pub min_api: String,
+}
+
+impl Default for BaseURL {
+    fn default() -> Self {
+        Self { data_api: DATA_API_URL.to_owned(), min_api: MIN_API_URL.to_owned(), }
+    }
+}
+
+impl BaseURL {
+    /// Replaces the default CoinDesk host of the URL with the configured base URL.
+    ///
+    /// Note: URLs that do not start with either of the default CoinDesk hosts are returned unchanged.
+    ///
+    /// # Input
+    /// - `url`: URL produced by the API endpoint
+    pub fn rebase(&self, url: &str) -> String {
+        if let Some(path) = url.strip_prefix(DATA_API_URL) {
+            format!("{}{}", self.data_api.trim_end_matches('/'), path)
+        } else if let Some(path) = url.strip_prefix(MIN_API_URL) {
+            format!("{}{}", self.min_api.trim_end_matches('/'), path)
+        } else {
+            url.to_owned()
+        }
+    }
+}
+
+
 /// Trait that defines all required methods for API endpoint URL construction (prior to adding non-default parameters).
 /// This is useful for defining custom API endpoints that are not included with the crate.
 pub trait APIEndpointTrait {
@@ -318,47 +423,47 @@ impl APIEndpoint {
     fn resolve_url(&self) -> String {
         match self {
             // Legacy API
-            Self::BalanceDistribution => String::from("https://min-api.cryptocompare.com/data/blockchain/balancedistribution/histo/day"),
+            Self::BalanceDistribution => format!("{MIN_API_URL}/data/blockchain/balancedistribution/histo/day"),
             // Indices & Reference Rates
-            Self::IndicesOHLCV => String::from("https://data-api.coindesk.com/index/cc/v1/historical"),
+            Self::IndicesOHLCV => format!("{DATA_API_URL}/index/cc/v1/historical"),
             // Spot
-            Self::SpotOHLCV => String::from("https://data-api.coindesk.com/spot/v1/historical"),
-            Self::SpotInstrumentMetadata => String::from("https://data-api.coindesk.com/spot/v1/latest/instrument/metadata"),
-            Self::SpotMarketsV2 => String::from("https://data-api.coindesk.com/spot/v2/markets"),
-            Self::SpotMarketsInstruments => String::from("https://data-api.coindesk.com/spot/v1/markets/instruments"),
+            Self::SpotOHLCV => format!("{DATA_API_URL}/spot/v1/historical"),
+            Self::SpotInstrumentMetadata => format!("{DATA_API_URL}/spot/v1/latest/instrument/metadata"),
+            Self::SpotMarketsV2 => format!("{DATA_API_URL}/spot/v2/markets"),
+            Self::SpotMarketsInstruments => format!("{DATA_API_URL}/spot/v1/markets/instruments"),
             // Futures
-            Self::FuturesOHLCV => String::from("https://data-api.coindesk.com/futures/v1/historical"),
-            Self::FuturesInstrumentMetadata => String::from("https://data-api.coindesk.com/futures/v1/latest/instrument/metadata"),
-            Self::FuturesMarketsV2 => String::from("https://data-api.coindesk.com/futures/v2/markets"),
+            Self::FuturesOHLCV => format!("{DATA_API_URL}/futures/v1/historical"),
+            Self::FuturesInstrumentMetadata => format!("{DATA_API_URL}/futures/v1/latest/instrument/metadata"),
+            Self::FuturesMarketsV2 => format!("{DATA_API_URL}/futures/v2/markets"),
             // Options
-            Self::OptionsOHLCV => String::from("https://data-api.coindesk.com/options/v1/historical"),
-            Self::OptionsInstrumentMetadata => String::from("https://data-api.coindesk.com/options/v1/latest/instrument/metadata"),
-            Self::OptionsMarketsV2 => String::from("https://data-api.coindesk.com/options/v2/markets"),
+            Self::OptionsOHLCV => format!("{DATA_API_URL}/options/v1/historical"),
+            Self::OptionsInstrumentMetadata => format!("{DATA_API_URL}/options/v1/latest/instrument/metadata"),
+            Self::OptionsMarketsV2 => format!("{DATA_API_URL}/options/v2/markets"),
             // Derivatives Indices
-            Self::DerIndicesOHLCV => String::from("https://data-api.coindesk.com/index/v1/historical"),
-            Self::DerIndicesMarketsV2 => String::from("https://data-api.coindesk.com/index/v2/markets"),
+            Self::DerIndicesOHLCV => format!("{DATA_API_URL}/index/v1/historical"),
+            Self::DerIndicesMarketsV2 => format!("{DATA_API_URL}/index/v2/markets"),
             // On-Chain DEX
-            Self::OCDEXOHLCV => String::from("https://data-api.coindesk.com/onchain/v1/amm/historical/swap"),
-            Self::OCDEXMarketsV2 => String::from("https://data-api.coindesk.com/onchain/v2/amm/markets"),
+            Self::OCDEXOHLCV => format!("{DATA_API_URL}/onchain/v1/amm/historical/swap"),
+            Self::OCDEXMarketsV2 => format!("{DATA_API_URL}/onchain/v2/amm/markets"),
             // On-Chain Core
-            Self::OCCoreETHBlocks => String::from("https://data-api.coindesk.com/onchain/v1/block/2"),
-            Self::OCCoreAssetsByChain => String::from("https://data-api.coindesk.com/onchain/v3/summary/by/chain"),
-            Self::OCCoreAssetByAddress => String::from("https://data-api.coindesk.com/onchain/v2/data/by/address"),
-            Self::OCCoreSupply => String::from("https://data-api.coindesk.com/onchain/v2/historical/supply/days"),
+            Self::OCCoreETHBlocks => format!("{DATA_API_URL}/onchain/v1/block/2"),
+            Self::OCCoreAssetsByChain => format!("{DATA_API_URL}/onchain/v3/summary/by/chain"),
+            Self::OCCoreAssetByAddress => format!("{DATA_API_URL}/onchain/v2/data/by/address"),
+            Self::OCCoreSupply => format!("{DATA_API_URL}/onchain/v2/historical/supply/days"),
             // Asset
-            Self::AssetMetadataV2 => String::from("https://data-api.coindesk.com/asset/v2/metadata"),
-            Self::AssetEvents => String::from("https://data-api.coindesk.com/asset/v1/events"),
-            Self::AssetCodeRepo => String::from("https://data-api.coindesk.com/asset/v1/historical/code-repository/days"),
-            Self::AssetDiscord => String::from("https://data-api.coindesk.com/asset/v1/historical/discord/days"),
-            Self::AssetReddit => String::from("https://data-api.coindesk.com/asset/v1/historical/reddit/days"),
-            Self::AssetTelegram => String::from("https://data-api.coindesk.com/asset/v1/historical/telegram/days"),
-            Self::AssetTwitter => String::from("https://data-api.coindesk.com/asset/v1/historical/twitter/days"),
+            Self::AssetMetadataV2 => format!("{DATA_API_URL}/asset/v2/metadata"),
+            Self::AssetEvents => format!("{DATA_API_URL}/asset/v1/events"),
+            Self::AssetCodeRepo => format!("{DATA_API_URL}/asset/v1/historical/code-repository/days"),
+            Self::AssetDiscord => format!("{DATA_API_URL}/asset/v1/historical/discord/days"),
+            Self::AssetReddit => format!("{DATA_API_URL}/asset/v1/historical/reddit/days"),
+            Self::AssetTelegram => format!("{DATA_API_URL}/asset/v1/historical/telegram/days"),
+            Self::AssetTwitter => format!("{DATA_API_URL}/asset/v1/historical/twitter/days"),
             // News
-            Self::NewsLatestArticles => String::from("https://data-api.coindesk.com/news/v1/article/list"),
-            Self::NewsSources => String::from("https://data-api.coindesk.com/news/v1/source/list"),
-            Self::NewsCategories => String::from("https://data-api.coindesk.com/news/v1/category/list"),
+            Self::NewsLatestArticles => format!("{DATA_API_URL}/news/v1/article/list"),
+            Self::NewsSources => format!("{DATA_API_URL}/news/v1/source/list"),
+            Self::NewsCategories => format!("{DATA_API_URL}/news/v1/category/list"),
             // Overview
-            Self::OverviewMktCapOHLCV => String::from("https://data-api.coindesk.com/overview/v1/historical/marketcap/all/assets/days"),
+            Self::OverviewMktCapOHLCV => format!("{DATA_API_URL}/overview/v1/historical/marketcap/all/assets/days"),
         }
     }
 
@@ -0,0 +1,73 @@
+use std::{future::Future, pin::Pin};
+use reqwest::Request;
+use crate::error::Error;
+use crate::utils::RawResponse;
+
+
+/// Future returned by the hooks of the middleware.
+pub type MiddlewareFuture<'a> = Pin<Box<dyn Future<Output = Result<(), Error>> + Send + 'a>>;
+
+
+/// Hooks that are applied to every request sent to CoinDesk API (both Data API and Min-API).
+///
+/// Middleware can be used to add headers, count credits, log the requests, throttle the requests, inject faults or modify the URLs.
+/// The hooks are asynchronous, so they can wait (e.g., for a shared rate limiter) or perform I/O before returning. Returning an error from
+/// any of the hooks aborts the call with that error.
+///
+/// Note: The key of the cache and the cassette is computed from the request after the `before_request` hooks are applied, so the requests
+/// whose URL or query is modified by the middleware are cached and recorded under the modified URL.
+///
+/// Note: The `after_response` hooks are also applied to the responses served from the cache and from the cassette in the replay mode.
+/// The cache only stores the response body, so the cached responses have the status code `200` and no headers.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::sync::{Arc, atomic::{AtomicUsize, Ordering}};
+/// use reqwest::{Request, header::HeaderValue};
+/// use ccdata_api::{CoinDesk, Middleware, MiddlewareFuture, RawResponse};
+///
+/// #[derive(Default)]
+/// struct CallCounter {
+///     calls: AtomicUsize,
+/// }
+///
+/// impl Middleware for CallCounter {
+///     fn before_request<'a>(&'a self, request: &'a mut Request) -> MiddlewareFuture<'a> {
+///         Box::pin(async move {
+///             request.headers_mut().insert("X-Client", HeaderValue::from_static("research"));
+///             Ok(())
+///         })
+///     }
+///
+///     fn after_response<'a>(&'a self, _response: &'a mut RawResponse) -> MiddlewareFuture<'a> {
+///         Box::pin(async move {
+///             self.calls.fetch_add(1, Ordering::Relaxed);
+///             Ok(())
+///         })
+///     }
+/// }
+///
+/// let mut backend: CoinDesk = CoinDesk::new();
+/// backend.add_middleware(Arc::new(CallCounter::default()));
+/// assert_eq!(backend.middleware().len(), 1);
+/// ```
+pub trait Middleware: Send + Sync {
+    /// Invoked once before the response is looked up in the cache and the cassette, and before the request is sent (the retries of the request
+    /// do not invoke the hook again).
+    ///
+    /// # Input
+    /// - `request`: Request that is about to be sent (e.g., its URL and headers can be modified)
+    fn before_request<'a>(&'a self, _request: &'a mut Request) -> MiddlewareFuture<'a> {
+        Box::pin(async { Ok(()) })
+    }
+
+    /// Invoked for every response received from CoinDesk API (or served from the cache or the cassette), before the response is checked
+    /// for retries and errors.
+    ///
+    /// # Input
+    /// - `response`: Response that has been received (e.g., its status code or body can be modified)
+    fn after_response<'a>(&'a self, _response: &'a mut RawResponse) -> MiddlewareFuture<'a> {
+        Box::pin(async { Ok(()) })
+    }
+}
@@ -0,0 +1,199 @@
+#[cfg(feature = "chrono")]
+use chrono::{DateTime, Utc};
+#[cfg(feature = "time")]
+use time::OffsetDateTime;
+use crate::error::Error;
+use crate::request::{
+    Endpoint, IndicesOHLCVRequest, SpotOHLCVRequest, FuturesOHLCVRequest, OptionsOHLCVRequest, DerIndicesOHLCVRequest, OCDEXOHLCVRequest,
+    OCCoreSupplyRequest, AssetCodeRepoRequest, AssetDiscordRequest, AssetRedditRequest, AssetTelegramRequest, AssetTwitterRequest,
+    OverviewMktCapOHLCVRequest,
+};
+use crate::schemas::CoinDeskResponse;
+use crate::schemas::data_api::indices_and_reference_rates::IndicesOHLCV;
+use crate::schemas::data_api::spot::SpotOHLCV;
+use crate::schemas::data_api::futures::FuturesOHLCV;
+use crate::schemas::data_api::options::OptionsOHLCV;
+use crate::schemas::data_api::derivatives_indices::DerIndicesOHLCV;
+use crate::schemas::data_api::on_chain_dex::OCDEXOHLCV;
+use crate::schemas::data_api::on_chain_core::OCCoreSupply;
+use crate::schemas::data_api::asset::{AssetCodeRepoMetrics, AssetDiscord, AssetReddit, AssetTelegram, AssetTwitter};
+use crate::schemas::data_api::on_chain_core::OCCoreETHBlock;
+use crate::schemas::data_api::asset::AssetEvent;
+use crate::schemas::data_api::news::NewsLatestArticle;
+use crate::schemas::data_api::overview::OverviewMktCapOHLCV;
+
+
+/// Data point of a time series.
+pub trait Timestamped {
+    /// Returns the timestamp (in seconds) of the beginning of the interval of the data point.
+    fn timestamp(&self) -> i64;
+
+    #[cfg(feature = "chrono")]
+    /// Returns the timestamp of the data point as `DateTime<Utc>` (`None` if the timestamp is out of the range supported by `chrono`).
+    fn datetime_utc(&self) -> Option<DateTime<Utc>> {
+        crate::timestamp::datetime_utc(self.timestamp())
+    }
+
+    #[cfg(feature = "time")]
+    /// Returns the timestamp of the data point as `OffsetDateTime` (`None` if the timestamp is out of the range supported by `time`).
+    fn offset_datetime(&self) -> Option<OffsetDateTime> {
+        crate::timestamp::offset_datetime(self.timestamp())
+    }
+}
+
+macro_rules! impl_timestamped {
+    ($($t:ty => $field:ident),*) => {
+        $(
+            impl Timestamped for $t {
+                fn timestamp(&self) -> i64 {
+                    self.$field
+                }
+            }
+        )*
+    };
+}
+
+impl_timestamped!(
+    IndicesOHLCV => timestamp, SpotOHLCV => timestamp, FuturesOHLCV => timestamp, OptionsOHLCV => timestamp, DerIndicesOHLCV => timestamp,
+    OCDEXOHLCV => timestamp, OCCoreETHBlock => timestamp, OCCoreSupply => timestamp, AssetEvent => announced_on,
+    AssetCodeRepoMetrics => timestamp, AssetDiscord => timestamp, AssetReddit => timestamp, AssetTelegram => timestamp,
+    AssetTwitter => timestamp, NewsLatestArticle => published_on, OverviewMktCapOHLCV => timestamp
+);
+
+
+/// Request to a historical API endpoint that returns at most `limit` data points ending at `to_timestamp`.
+///
+/// The time range of the request can be paginated by moving `to_timestamp` backwards (e.g., `CoinDesk::send_range`).
+pub trait HistoricalEndpoint<T: Timestamped>: Endpoint<Response = CoinDeskResponse<Vec<T>>> + Clone {
+    /// Updates the final timestamp up to which the data will be extracted.
+    ///
+    /// # Input
+    /// - `new_to_timestamp`: New final timestamp up to which the data will be extracted
+    fn update_to_timestamp(&mut self, new_to_timestamp: Option<i64>) -> ();
+}
+
+macro_rules! impl_historical_endpoint {
+    ($($r:ty => $t:ty),*) => {
+        $(
+            impl HistoricalEndpoint<$t> for $r {
+                fn update_to_timestamp(&mut self, new_to_timestamp: Option<i64>) -> () {
+                    self.to_timestamp = new_to_timestamp;
+                }
+            }
+        )*
+    };
+}
+
+impl_historical_endpoint!(
+    IndicesOHLCVRequest => IndicesOHLCV, SpotOHLCVRequest => SpotOHLCV, FuturesOHLCVRequest => FuturesOHLCV, OptionsOHLCVRequest => OptionsOHLCV,
+    DerIndicesOHLCVRequest => DerIndicesOHLCV, OCDEXOHLCVRequest => OCDEXOHLCV, OCCoreSupplyRequest => OCCoreSupply,
+    AssetCodeRepoRequest => AssetCodeRepoMetrics, AssetDiscordRequest => AssetDiscord, AssetRedditRequest => AssetReddit,
+    AssetTelegramRequest => AssetTelegram, AssetTwitterRequest => AssetTwitter, OverviewMktCapOHLCVRequest => OverviewMktCapOHLCV
+);
+
+
+#[derive(Clone, Copy, Debug)]
+/// State of the pagination over the time range, which walks `to_timestamp` backwards page by page.
+pub(crate) struct RangeCursor {
+    /// Initial timestamp from which the data will be extracted
+    from: i64,
+    /// Final timestamp of the next page (`None` if there are no more pages)
+    next_to_timestamp: Option<i64>,
+}
+
+impl RangeCursor {
+    pub(crate) fn new(from: i64, to: i64) -> Self {
+        Self { from, next_to_timestamp: (from <= to).then_some(to), }
+    }
+
+    /// Returns the final timestamp of the next page, or `None` if the time range has been covered.
+    pub(crate) fn next_to_timestamp(&self) -> Option<i64> {
+        self.next_to_timestamp
+    }
+
+    /// Stops the pagination (e.g., after an error).
+    pub(crate) fn stop(&mut self) -> () {
+        self.next_to_timestamp = None;
+    }
+
+    /// Moves the cursor past the page and returns the data points of the page that are within the time range and have not been returned
+    /// with the previous pages.
+    ///
+    /// Note: The pagination stops at the start of the time range, or at the first available timestamp reported by CoinDesk API
+    /// (`CCErrorOtherInfo.first`), which is sent either with a successful response or with a `404 Not Found` response.
+    pub(crate) fn advance<T: Timestamped>(&mut self, response: Result<CoinDeskResponse<Vec<T>>, Error>) -> Result<Vec<T>, Error> {
+        let to_timestamp: i64 = match self.next_to_timestamp.take() {
+            Some(v) => v,
+            None => return Ok(Vec::new()),
+        };
+        let response: CoinDeskResponse<Vec<T>> = match response {
+            Ok(v) => v,
+            // No data for the requested range
+            Err(Error::NotFound(Some(e))) if e.other_info.as_ref().is_some_and(|v| v.first.or(v.first_bucket).is_some() ) => {
+                CoinDeskResponse { data: None, error: Some(*e), }
+            },
+            Err(e) => return Err(e),
+        };
+        let first: Option<i64> = response.error.as_ref()
+            .and_then(|e| e.other_info.as_ref() )
+            .and_then(|v| v.first.or(v.first_bucket) );
+        let data: Vec<T> = match (response.data, response.error) {
+            (Some(data), _) if !data.is_empty() => data,
+            // No data before the first available timestamp
+            (_, Some(_)) if first.is_some() => return Ok(Vec::new()),
+            (_, Some(e)) => return Err(Error::Api(Box::new(e))),
+            (_, None) => return Ok(Vec::new()),
+        };
+        let earliest: i64 = data.iter().map(|v| v.timestamp() ).min().unwrap_or(self.from);
+        let reached_first: bool = first.is_some_and(|v| earliest <= v );
+        // The next page ends right before the earliest data point of the page
+        if self.from < earliest && !reached_first && earliest <= to_timestamp {
+            self.next_to_timestamp = Some(earliest - 1);
+        }
+        // Data points after `to_timestamp` have already been returned with the previous page
+        Ok(data.into_iter().filter(|v| (self.from..=to_timestamp).contains(&v.timestamp()) ).collect())
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+
+    #[test]
+    fn unit_test_range_cursor() -> () {
+        use crate::error::Error;
+        use crate::pagination::RangeCursor;
+        use crate::schemas::{CoinDeskResponse, CCError, CCErrorOtherInfo};
+        use crate::schemas::data_api::spot::SpotOHLCV;
+        let page = |timestamps: &[i64]| -> Result<CoinDeskResponse<Vec<SpotOHLCV>>, Error> {
+            Ok(CoinDeskResponse { data: Some(timestamps.iter().map(|v| SpotOHLCV { timestamp: *v, ..Default::default() } ).collect()), error: None, })
+        };
+        // Pagination stops at the start of the time range
+        let mut cursor: RangeCursor = RangeCursor::new(250, 500);
+        assert_eq!(cursor.next_to_timestamp(), Some(500));
+        assert_eq!(cursor.advance(page(&[400, 500])).unwrap().len(), 2);
+        assert_eq!(cursor.next_to_timestamp(), Some(399));
+        assert_eq!(cursor.advance(page(&[200, 300])).unwrap().len(), 1);
+        assert_eq!(cursor.next_to_timestamp(), None);
+        // Pagination stops at the first available timestamp
+        let mut cursor: RangeCursor = RangeCursor::new(0, 500);
+        cursor.advance(page(&[400, 500])).unwrap();
+        let other_info: CCErrorOtherInfo = CCErrorOtherInfo { first: Some(400), ..Default::default() };
+        let error: CCError = CCError { type_: 2, message: String::from("No data"), other_info: Some(other_info), };
+        assert!(cursor.advance::<SpotOHLCV>(Ok(CoinDeskResponse { data: Some(vec![]), error: Some(error.clone()), })).unwrap().is_empty());
+        assert_eq!(cursor.next_to_timestamp(), None);
+        // First available timestamp sent with `404 Not Found` response
+        let mut cursor: RangeCursor = RangeCursor::new(0, 500);
+        cursor.advance(page(&[400, 500])).unwrap();
+        assert!(cursor.advance::<SpotOHLCV>(Err(Error::NotFound(Some(Box::new(error.clone()))))).unwrap().is_empty());
+        assert_eq!(cursor.next_to_timestamp(), None);
+        let mut cursor: RangeCursor = RangeCursor::new(0, 500);
+        assert!(matches!(cursor.advance::<SpotOHLCV>(Err(Error::NotFound(None))), Err(Error::NotFound(None))));
+        // Errors without the first available timestamp are returned
+        let mut cursor: RangeCursor = RangeCursor::new(0, 500);
+        let error: CCError = CCError { other_info: None, ..error };
+        assert!(cursor.advance::<SpotOHLCV>(Ok(CoinDeskResponse { data: None, error: Some(error), })).is_err());
+        // Empty time range
+        assert_eq!(RangeCursor::new(500, 0).next_to_timestamp(), None);
+    }
+}
@@ -0,0 +1,313 @@
+use std::{fmt::Display, sync::Mutex, time::{Duration, Instant}};
+use reqwest::header::HeaderMap;
+use crate::schemas::{CCMaxCalls, CCRateLimit};
+
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+/// Time window of the CoinDesk API quota.
+pub enum RateLimitWindow {
+    Second,
+    Minute,
+    Hour,
+    Day,
+    /// Month window (approximated as 30 days)
+    Month,
+}
+
+impl Display for RateLimitWindow {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Second => write!(f, "second"),
+            Self::Minute => write!(f, "minute"),
+            Self::Hour => write!(f, "hour"),
+            Self::Day => write!(f, "day"),
+            Self::Month => write!(f, "month"),
+        }
+    }
+}
+
+impl RateLimitWindow {
+    /// All quota windows.
+    pub const ALL: [RateLimitWindow; 5] = [Self::Second, Self::Minute, Self::Hour, Self::Day, Self::Month];
+
+    /// Returns the duration of the window.
+    pub fn duration(&self) -> Duration {
+        match self {
+            Self::Second => Duration::from_secs(1),
+            Self::Minute => Duration::from_secs(60),
+            Self::Hour => Duration::from_secs(3_600),
+            Self::Day => Duration::from_secs(86_400),
+            Self::Month => Duration::from_secs(30 * 86_400),
+        }
+    }
+}
+
+
+#[derive(Clone, Debug)]
+/// Token bucket of a single quota window.
+struct Bucket {
+    window: RateLimitWindow,
+    capacity: f64,
+    tokens: f64,
+    updated_at: Instant,
+}
+
+impl Bucket {
+    fn new(window: RateLimitWindow, max_calls: u64) -> Self {
+        Self { window, capacity: max_calls as f64, tokens: max_calls as f64, updated_at: Instant::now(), }
+    }
+
+    /// Number of tokens added to the bucket per second.
+    fn refill_rate(&self) -> f64 {
+        self.capacity / self.window.duration().as_secs_f64()
+    }
+
+    fn refill(&mut self, now: Instant) -> () {
+        let elapsed: f64 = now.saturating_duration_since(self.updated_at).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.refill_rate()).min(self.capacity);
+        self.updated_at = now;
+    }
+
+    /// Time until the bucket has at least one token.
+    fn wait_time(&self) -> Duration {
+        if 1.0 <= self.tokens {
+            Duration::ZERO
+        } else {
+            Duration::from_secs_f64((1.0 - self.tokens) / self.refill_rate())
+        }
+    }
+}
+
+
+#[derive(Debug, Default)]
+/// Client-side rate limiter that throttles the requests according to the CoinDesk API quotas.
+///
+/// The limiter keeps a token bucket per quota window (second, minute, hour, day, month) and a request is only sent when every
+/// window has a token available. The limiter can be shared between several backends (e.g., parallel collectors using the same API key)
+/// by wrapping it in `Arc`.
+///
+/// The limits and the remaining calls are updated from the `X-RateLimit-Limit-<Window>` and `X-RateLimit-Remaining-<Window>` response headers,
+/// and from the `RateLimit` object of Min-API responses, when CoinDesk API provides them.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::sync::Arc;
+/// use ccdata_api::{CoinDesk, CCMaxCalls, RateLimiter, RateLimitWindow};
+///
+/// let mut backend: CoinDesk = CoinDesk::new();
+///
+/// // Limits of the API plan
+/// let max_calls: CCMaxCalls = CCMaxCalls { second: 20, minute: 300, hour: 3_000, day: 7_500, month: 50_000 };
+/// let rate_limiter: Arc<RateLimiter> = Arc::new(RateLimiter::from_max_calls(&max_calls));
+/// backend.update_rate_limiter(Some(rate_limiter.clone()));
+///
+/// assert_eq!(rate_limiter.limit(RateLimitWindow::Minute), Some(300));
+/// ```
+pub struct RateLimiter {
+    buckets: Mutex<Vec<Bucket>>,
+}
+
+impl RateLimiter {
+    /// Creates a rate limiter without any quota windows.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a rate limiter from the maximum number of calls per window of the API plan.
+    ///
+    /// Note: Windows with the maximum number of calls of `0` or less are not limited.
+    ///
+    /// # Input
+    /// - `max_calls`: Maximum number of calls per second, minute, hour, day and month
+    pub fn from_max_calls(max_calls: &CCMaxCalls) -> Self {
+        let rate_limiter: Self = Self::new();
+        rate_limiter.update_max_calls(max_calls);
+        rate_limiter
+    }
+
+    /// Adds (or replaces) the limit for the quota window.
+    ///
+    /// Note: The limit of `0` is ignored.
+    ///
+    /// # Input
+    /// - `window`: Quota window
+    /// - `max_calls`: Maximum number of calls in the window
+    pub fn with_limit(self, window: RateLimitWindow, max_calls: u64) -> Self {
+        self.set_limit(window, max_calls);
+        self
+    }
+
+    /// Returns the maximum number of calls in the quota window.
+    pub fn limit(&self, window: RateLimitWindow) -> Option<u64> {
+        let buckets = self.buckets.lock().unwrap_or_else(|e| e.into_inner() );
+        buckets.iter().find(|v| v.window == window ).map(|v| v.capacity as u64 )
+    }
+
+    /// Returns the number of calls that can currently be made in the quota window.
+    pub fn remaining(&self, window: RateLimitWindow) -> Option<u64> {
+        let mut buckets = self.buckets.lock().unwrap_or_else(|e| e.into_inner() );
+        let now: Instant = Instant::now();
+        buckets.iter_mut().find(|v| v.window == window ).map(|v| { v.refill(now); v.tokens.max(0.0) as u64 })
+    }
+
+    /// Sets the maximum number of calls in the quota window.
+    ///
+    /// Note: The limit of `0` is ignored since no request could ever be made in the window.
+    ///
+    /// # Input
+    /// - `window`: Quota window
+    /// - `max_calls`: Maximum number of calls in the window
+    pub fn set_limit(&self, window: RateLimitWindow, max_calls: u64) -> () {
+        if max_calls == 0 {
+            return;
+        }
+        let mut buckets = self.buckets.lock().unwrap_or_else(|e| e.into_inner() );
+        match buckets.iter_mut().find(|v| v.window == window ) {
+            Some(bucket) => {
+                bucket.refill(Instant::now());
+                bucket.capacity = max_calls as f64;
+                bucket.tokens = bucket.tokens.min(bucket.capacity);
+            },
+            None => buckets.push(Bucket::new(window, max_calls)),
+        }
+    }
+
+    /// Limits the number of calls that can currently be made in the quota window to the number reported by CoinDesk API.
+    ///
+    /// # Input
+    /// - `window`: Quota window
+    /// - `remaining`: Number of calls remaining in the window
+    pub fn set_remaining(&self, window: RateLimitWindow, remaining: u64) -> () {
+        let mut buckets = self.buckets.lock().unwrap_or_else(|e| e.into_inner() );
+        if let Some(bucket) = buckets.iter_mut().find(|v| v.window == window ) {
+            bucket.refill(Instant::now());
+            bucket.tokens = bucket.tokens.min(remaining as f64);
+        }
+    }
+
+    /// Updates the limits from the maximum number of calls per window.
+    ///
+    /// # Input
+    /// - `max_calls`: Maximum number of calls per second, minute, hour, day and month
+    pub fn update_max_calls(&self, max_calls: &CCMaxCalls) -> () {
+        for (window, v) in RateLimitWindow::ALL.into_iter().zip(max_calls_per_window(max_calls)) {
+            if 0 < v { self.set_limit(window, v as u64) }
+        }
+    }
+
+    /// Updates the limits and the remaining calls from the `RateLimit` object of Min-API response.
+    ///
+    /// # Input
+    /// - `rate_limit`: Rate limit information returned by CoinDesk API
+    pub fn update_from_rate_limit(&self, rate_limit: &CCRateLimit) -> () {
+        let max_calls: CCMaxCalls = match rate_limit.max_calls {
+            Some(v) => v,
+            None => return,
+        };
+        self.update_max_calls(&max_calls);
+        if let Some(calls_made) = rate_limit.calls_made {
+            let made: [i32; 5] = [calls_made.second, calls_made.minute, calls_made.hour, calls_made.day, calls_made.month];
+            for ((window, max), made) in RateLimitWindow::ALL.into_iter().zip(max_calls_per_window(&max_calls)).zip(made) {
+                if 0 < max { self.set_remaining(window, (max - made).max(0) as u64) }
+            }
+        }
+    }
+
+    /// Updates the limits and the remaining calls from the `X-RateLimit-Limit-<Window>` and `X-RateLimit-Remaining-<Window>` headers.
+    ///
+    /// # Input
+    /// - `headers`: Headers of the response returned by CoinDesk API
+    pub fn update_from_headers(&self, headers: &HeaderMap) -> () {
+        let header = |name: String| -> Option<u64> { headers.get(name)?.to_str().ok()?.trim().parse().ok() };
+        for window in RateLimitWindow::ALL {
+            if let Some(limit) = header(format!("x-ratelimit-limit-{window}")) {
+                self.set_limit(window, limit);
+            }
+            if let Some(remaining) = header(format!("x-ratelimit-remaining-{window}")) {
+                self.set_remaining(window, remaining);
+            }
+        }
+    }
+
+    /// Takes a token from every quota window if all of them have a token available, otherwise returns the time to wait.
+    pub fn try_acquire(&self) -> Result<(), Duration> {
+        let mut buckets = self.buckets.lock().unwrap_or_else(|e| e.into_inner() );
+        let now: Instant = Instant::now();
+        buckets.iter_mut().for_each(|v| v.refill(now) );
+        let wait: Duration = buckets.iter().map(|v| v.wait_time() ).max().unwrap_or_default();
+        if !wait.is_zero() {
+            return Err(wait);
+        }
+        buckets.iter_mut().for_each(|v| v.tokens -= 1.0 );
+        Ok(())
+    }
+
+    /// Waits until a request can be made without exceeding any of the quotas and takes a token from every quota window.
+    pub async fn acquire(&self) -> () {
+        while let Err(wait) = self.try_acquire() {
+            tokio::time::sleep(wait).await;
+        }
+    }
+}
+
+
+/// Returns the maximum number of calls in the order of `RateLimitWindow::ALL`.
+fn max_calls_per_window(max_calls: &CCMaxCalls) -> [i32; 5] {
+    [max_calls.second, max_calls.minute, max_calls.hour, max_calls.day, max_calls.month]
+}
+
+
+#[cfg(test)]
+mod tests {
+
+    #[test]
+    fn unit_test_token_bucket() -> () {
+        use crate::rate_limit::{RateLimiter, RateLimitWindow};
+        let rate_limiter: RateLimiter = RateLimiter::new().with_limit(RateLimitWindow::Second, 2).with_limit(RateLimitWindow::Month, 100);
+        assert!(rate_limiter.try_acquire().is_ok());
+        assert!(rate_limiter.try_acquire().is_ok());
+        let wait = rate_limiter.try_acquire().unwrap_err();
+        assert!(wait.as_secs_f64() <= 0.5);
+        assert_eq!(rate_limiter.remaining(RateLimitWindow::Month), Some(98));
+    }
+
+    #[test]
+    fn unit_test_rate_limit_updates() -> () {
+        use reqwest::header::{HeaderMap, HeaderValue};
+        use crate::schemas::{CCRateLimit, CCMaxCalls, CCCallsMade};
+        use crate::rate_limit::{RateLimiter, RateLimitWindow};
+        let rate_limiter: RateLimiter = RateLimiter::new();
+        let rate_limit: CCRateLimit = CCRateLimit {
+            calls_made: Some(CCCallsMade { second: 1, minute: 10, hour: 10, day: 10, month: 10, total_calls: 10 }),
+            max_calls: Some(CCMaxCalls { second: 20, minute: 300, hour: 3_000, day: 7_500, month: 50_000 }),
+        };
+        rate_limiter.update_from_rate_limit(&rate_limit);
+        assert_eq!(rate_limiter.limit(RateLimitWindow::Hour), Some(3_000));
+        assert_eq!(rate_limiter.remaining(RateLimitWindow::Minute), Some(290));
+        let mut headers: HeaderMap = HeaderMap::new();
+        headers.insert("X-RateLimit-Limit-Second", HeaderValue::from_static("5"));
+        headers.insert("X-RateLimit-Remaining-Second", HeaderValue::from_static("0"));
+        rate_limiter.update_from_headers(&headers);
+        assert_eq!(rate_limiter.limit(RateLimitWindow::Second), Some(5));
+        assert!(rate_limiter.try_acquire().is_err());
+    }
+
+    #[test]
+    fn unit_test_zero_rate_limit() -> () {
+        use reqwest::header::{HeaderMap, HeaderValue};
+        use crate::rate_limit::{RateLimiter, RateLimitWindow};
+        let rate_limiter: RateLimiter = RateLimiter::new().with_limit(RateLimitWindow::Minute, 0);
+        assert_eq!(rate_limiter.limit(RateLimitWindow::Minute), None);
+        let mut headers: HeaderMap = HeaderMap::new();
+        headers.insert("X-RateLimit-Limit-Hour", HeaderValue::from_static("10"));
+        headers.insert("X-RateLimit-Limit-Second", HeaderValue::from_static("0"));
+        rate_limiter.update_from_headers(&headers);
+        assert_eq!(rate_limiter.limit(RateLimitWindow::Second), None);
+        let mut headers: HeaderMap = HeaderMap::new();
+        headers.insert("X-RateLimit-Limit-Hour", HeaderValue::from_static("0"));
+        rate_limiter.update_from_headers(&headers);
+        assert_eq!(rate_limiter.limit(RateLimitWindow::Hour), Some(10));
+        assert!(rate_limiter.try_acquire().is_ok());
+    }
+}
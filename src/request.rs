@@ -0,0 +1,1810 @@
+use std::collections::HashMap;
+use serde::de::DeserializeOwned;
+use crate::{Unit, APIEndpoint, APIEndpointTrait};
+use crate::utils::{Group, AssetLookupPriority, Param};
+use crate::timestamp::IntoTimestamp;
+use crate::schemas::{CoinDeskResponse, CCMinResponse, CCMinWrapper};
+use crate::schemas::min_api::BalanceDistribution;
+use crate::schemas::data_api::indices_and_reference_rates::{IndicesMarket, IndicesOHLCV};
+use crate::schemas::data_api::spot::{SpotMarket, SpotInstrumentStatus, SpotOHLCV, SpotInstrumentMetdata, SpotMarkets, SpotMarketsInstruments};
+use crate::schemas::data_api::futures::{FuturesMarket, FuturesOHLCV, FuturesInstrumentMetadata, FuturesMarkets};
+use crate::schemas::data_api::options::{OptionsMarket, OptionsOHLCV, OptionsInstrumentMetadata, OptionsMarkets};
+use crate::schemas::data_api::derivatives_indices::{DerIndicesMarket, DerIndicesOHLCV, DerIndicesMarkets};
+use crate::schemas::data_api::on_chain_dex::{OCDEXMarket, OCDEXOHLCV, OCDEXMarkets};
+use crate::schemas::data_api::on_chain_core::{OCCoreETHBlock, OCCoreAssetByChain, OCCoreAssetByAddress, OCCoreSupply};
+use crate::schemas::data_api::asset::{AssetMetadata, AssetEvent, AssetCodeRepoMetrics, AssetDiscord, AssetReddit, AssetTelegram, AssetTwitter};
+use crate::schemas::data_api::news::{NewsStatus, NewsLang, NewsSourceID, NewsLatestArticle, NewsSourceType, NewsSource, NewsCategory};
+use crate::schemas::data_api::overview::OverviewMktCapOHLCV;
+
+
+/// Request to a CoinDesk API endpoint that is paired with the schema of the endpoint's response.
+///
+/// Every request is sent with `CoinDesk::send`, which deserializes the response into `Response`, so a request cannot be
+/// paired with the wrong schema.
+///
+/// # Examples
+///
+/// ```rust
+/// use serde::Deserialize;
+/// use ccdata_api::{CoinDeskResponse, APIEndpoint, Endpoint, Param, Unit};
+///
+/// #[derive(Debug, Deserialize)]
+/// struct Close {
+///     #[serde(rename = "CLOSE")]
+///     close: f64,
+/// }
+///
+/// /// Daily close prices of the instrument on Kraken.
+/// struct KrakenCloseRequest {
+///     instrument: String,
+/// }
+///
+/// impl Endpoint for KrakenCloseRequest {
+///     type API = APIEndpoint;
+///     type Response = CoinDeskResponse<Vec<Close>>;
+///
+///     fn endpoint(&self) -> APIEndpoint { APIEndpoint::SpotOHLCV }
+///
+///     fn unit(&self) -> Unit { Unit::Day }
+///
+///     fn params(&self) -> Vec<Param<'_>> {
+///         vec![Param::Instrument { v: &self.instrument, }, Param::Market { v: String::from("kraken"), }, Param::Limit { v: Some(10), }]
+///     }
+/// }
+///
+/// let request: KrakenCloseRequest = KrakenCloseRequest { instrument: String::from("BTC-USD"), };
+/// assert_eq!(request.params().len(), 3);
+/// ```
+pub trait Endpoint {
+    /// API endpoint that the request is sent to (`APIEndpoint` or a custom type that implements `APIEndpointTrait`).
+    type API: APIEndpointTrait;
+    /// Schema that the response is deserialized into.
+    type Response: DeserializeOwned;
+
+    /// Returns the API endpoint that the request is sent to.
+    fn endpoint(&self) -> Self::API;
+
+    /// Returns the unit for data to be binned by.
+    fn unit(&self) -> Unit {
+        Unit::NA
+    }
+
+    /// Returns the list of parameters of the request.
+    fn params(&self) -> Vec<Param<'_>>;
+
+    /// Returns the additional parameters to add to the request (e.g., `&param=value`).
+    fn additional_params(&self) -> Option<String> {
+        None
+    }
+}
+
+
+/// Implements the builder setters of the parameters that are shared by the request structs.
+///
+/// Note: `market` and `language` take the type of the parameter, and `aggregate` and `fill` take the description of the parameter,
+/// which depends on the units supported by the API endpoint.
+macro_rules! impl_setters {
+    ($r:ty { $($setter:ident $(: $arg:tt)?),* $(,)? }) => {
+        impl $r {
+            $( impl_setters!(@setter $setter $($arg)?); )*
+        }
+    };
+    (@setter to_timestamp) => {
+        /// Sets the `to_timestamp` parameter (Final timestamp up to which the data will be extracted).
+        pub fn to_timestamp(mut self, to_timestamp: impl IntoTimestamp) -> Self {
+            self.to_timestamp = Some(to_timestamp.into_timestamp());
+            self
+        }
+    };
+    (@setter limit) => {
+        /// Sets the `limit` parameter (Maximum number of datapoints per API endpoint call).
+        pub fn limit(mut self, limit: usize) -> Self {
+            self.limit = Some(limit);
+            self
+        }
+    };
+    (@setter market $t:ty) => {
+        /// Sets the `market` parameter (Market name).
+        pub fn market(mut self, market: $t) -> Self {
+            self.market = market;
+            self
+        }
+    };
+    (@setter unit) => {
+        /// Sets the `unit` parameter (Unit of the interval between successive data points).
+        pub fn unit(mut self, unit: Unit) -> Self {
+            self.unit = unit;
+            self
+        }
+    };
+    (@setter aggregate $doc:literal) => {
+        #[doc = concat!("Sets the `aggregate` parameter (", $doc, ").")]
+        pub fn aggregate(mut self, aggregate: u32) -> Self {
+            self.aggregate = Some(aggregate);
+            self
+        }
+    };
+    (@setter fill $doc:literal) => {
+        #[doc = concat!("Sets the `fill` parameter (", $doc, ").")]
+        pub fn fill(mut self, fill: bool) -> Self {
+            self.fill = Some(fill);
+            self
+        }
+    };
+    (@setter apply_mapping) => {
+        /// Sets the `apply_mapping` parameter (Determines if provided instrument values are converted according to internal mappings).
+        pub fn apply_mapping(mut self, apply_mapping: bool) -> Self {
+            self.apply_mapping = Some(apply_mapping);
+            self
+        }
+    };
+    (@setter asset_lookup_priority) => {
+        /// Sets the `asset_lookup_priority` parameter (Matching priority for the asset key provided in the asset parameter).
+        pub fn asset_lookup_priority(mut self, asset_lookup_priority: AssetLookupPriority) -> Self {
+            self.asset_lookup_priority = Some(asset_lookup_priority);
+            self
+        }
+    };
+    (@setter groups) => {
+        /// Sets the `groups` parameter (Filtering groups that override the default groups of the API endpoint).
+        ///
+        /// Note: The identity and timestamp fields of the response schemas are required, so the groups should include the group
+        /// that provides them (e.g., `Group::Id`).
+        pub fn groups(mut self, groups: impl IntoIterator<Item = Group>) -> Self {
+            self.groups = Some(groups.into_iter().collect());
+            self
+        }
+    };
+    (@setter language $t:ty) => {
+        /// Sets the `language` parameter (Language of the news).
+        pub fn language(mut self, language: $t) -> Self {
+            self.language = language;
+            self
+        }
+    };
+}
+
+
+
+#[derive(Clone, Debug, Default)]
+/// Request for Balance Distribution Daily (Blockchain Data).
+///
+/// Note: The API endpoint has been deprecated by CoinDesk.
+///
+/// Note: Sent by [`CoinDesk::get_balance_distribution`](crate::CoinDesk::get_balance_distribution).
+pub struct BalanceDistributionRequest {
+    /// Final timestamp up to which the data will be extracted
+    pub to_timestamp: Option<i64>,
+    /// Maximum number of datapoints per API endpoint call
+    pub limit: Option<usize>,
+}
+
+impl BalanceDistributionRequest {
+    /// Creates a request with the default values of the parameters.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl_setters!(BalanceDistributionRequest { to_timestamp, limit });
+
+#[allow(deprecated)]
+impl Endpoint for BalanceDistributionRequest {
+    type API = APIEndpoint;
+    type Response = CCMinResponse<CCMinWrapper<Vec<BalanceDistribution>>>;
+
+    fn endpoint(&self) -> APIEndpoint {
+        APIEndpoint::BalanceDistribution
+    }
+
+    fn params(&self) -> Vec<Param<'_>> {
+        vec![Param::Symbol { v: "BTC", }, Param::Limit { v: self.limit, }, Param::ToTs { v: self.to_timestamp, }]
+    }
+}
+
+
+#[derive(Clone, Debug)]
+/// Request for Historical OHLCV+ \[Day, Hour, Minute\] (Indices & Ref. Rates).
+///
+/// Note: Sent by [`CoinDesk::get_indices_ohlcv`](crate::CoinDesk::get_indices_ohlcv).
+pub struct IndicesOHLCVRequest {
+    /// Instrument symbol
+    pub instrument: String,
+    /// Final timestamp up to which the data will be extracted
+    pub to_timestamp: Option<i64>,
+    /// Maximum number of datapoints per API endpoint call
+    pub limit: Option<usize>,
+    /// Market name
+    pub market: IndicesMarket,
+    /// Unit of the interval between successive data points
+    pub unit: Unit,
+    /// Number of data points to aggregate into a single data point (e.g., `5` with the minutely unit returns 5-minute data)
+    pub aggregate: Option<u32>,
+    /// Determines if the intervals without any trades are filled with the data of the previous interval
+    pub fill: Option<bool>,
+    /// Determines if provided instrument values are converted according to internal mappings
+    pub apply_mapping: Option<bool>,
+    /// Filtering groups (Overrides the default groups of the API endpoint)
+    pub groups: Option<Vec<Group>>,
+}
+
+impl IndicesOHLCVRequest {
+    /// Creates a request with the default values of the optional parameters.
+    ///
+    /// # Input
+    /// - `instrument`: Instrument symbol
+    pub fn new(instrument: &str) -> Self {
+        Self {
+            instrument: instrument.to_owned(), to_timestamp: None, limit: None, market: Default::default(), unit: Default::default(),
+            aggregate: None, fill: None, apply_mapping: None, groups: None,
+        }
+    }
+}
+
+impl_setters!(IndicesOHLCVRequest {
+    to_timestamp, limit, market: IndicesMarket, unit,
+    aggregate: "Number of data points to aggregate into a single data point, e.g., `5` with the minutely unit returns 5-minute data",
+    fill: "Determines if the intervals without any trades are filled with the data of the previous interval", apply_mapping, groups
+});
+
+impl Endpoint for IndicesOHLCVRequest {
+    type API = APIEndpoint;
+    type Response = CoinDeskResponse<Vec<IndicesOHLCV>>;
+
+    fn endpoint(&self) -> APIEndpoint {
+        APIEndpoint::IndicesOHLCV
+    }
+
+    fn unit(&self) -> Unit {
+        self.unit
+    }
+
+    fn params(&self) -> Vec<Param<'_>> {
+        let mut params: Vec<Param> = vec![
+            Param::Instrument { v: &self.instrument, }, Param::Limit { v: self.limit, }, Param::ToTimestamp { v: self.to_timestamp, },
+            Param::Market { v: self.market.to_string(), }, Param::Aggregate { v: self.aggregate, }, Param::Fill { v: self.fill, },
+        ];
+        if let Some(v) = self.apply_mapping {
+            params.push(Param::ApplyMapping { v, });
+        }
+        if let Some(v) = &self.groups {
+            params.push(Param::Groups { v: Some(v.clone()), });
+        }
+        params
+    }
+}
+
+
+#[derive(Clone, Debug)]
+/// Request for Historical OHLCV+ \[Day, Hour, Minute\] (Spot).
+///
+/// Note: Sent by [`CoinDesk::get_spot_ohlcv`](crate::CoinDesk::get_spot_ohlcv).
+///
+/// # Examples
+///
+/// ```rust
+/// use ccdata_api::{SpotOHLCVRequest, SpotMarket, Group, Unit};
+///
+/// let request: SpotOHLCVRequest = SpotOHLCVRequest::new("BTC-USD")
+///     .market(SpotMarket::KRAKEN)
+///     .unit(Unit::Minute)
+///     .aggregate(5)
+///     .fill(false)
+///     .groups([Group::Id, Group::OHLC, Group::Volume]);
+///
+/// assert_eq!(request.aggregate, Some(5));
+/// assert_eq!(request.limit, None);
+/// ```
+pub struct SpotOHLCVRequest {
+    /// Instrument symbol
+    pub instrument: String,
+    /// Final timestamp up to which the data will be extracted
+    pub to_timestamp: Option<i64>,
+    /// Maximum number of datapoints per API endpoint call
+    pub limit: Option<usize>,
+    /// Market name
+    pub market: SpotMarket,
+    /// Unit of the interval between successive data points
+    pub unit: Unit,
+    /// Number of data points to aggregate into a single data point (e.g., `5` with the minutely unit returns 5-minute data)
+    pub aggregate: Option<u32>,
+    /// Determines if the intervals without any trades are filled with the data of the previous interval
+    pub fill: Option<bool>,
+    /// Determines if provided instrument values are converted according to internal mappings
+    pub apply_mapping: Option<bool>,
+    /// Filtering groups (Overrides the default groups of the API endpoint)
+    pub groups: Option<Vec<Group>>,
+}
+
+impl SpotOHLCVRequest {
+    /// Creates a request with the default values of the optional parameters.
+    ///
+    /// # Input
+    /// - `instrument`: Instrument symbol
+    pub fn new(instrument: &str) -> Self {
+        Self {
+            instrument: instrument.to_owned(), to_timestamp: None, limit: None, market: Default::default(), unit: Default::default(),
+            aggregate: None, fill: None, apply_mapping: None, groups: None,
+        }
+    }
+}
+
+impl_setters!(SpotOHLCVRequest {
+    to_timestamp, limit, market: SpotMarket, unit,
+    aggregate: "Number of data points to aggregate into a single data point, e.g., `5` with the minutely unit returns 5-minute data",
+    fill: "Determines if the intervals without any trades are filled with the data of the previous interval", apply_mapping, groups
+});
+
+impl Endpoint for SpotOHLCVRequest {
+    type API = APIEndpoint;
+    type Response = CoinDeskResponse<Vec<SpotOHLCV>>;
+
+    fn endpoint(&self) -> APIEndpoint {
+        APIEndpoint::SpotOHLCV
+    }
+
+    fn unit(&self) -> Unit {
+        self.unit
+    }
+
+    fn params(&self) -> Vec<Param<'_>> {
+        let mut params: Vec<Param> = vec![
+            Param::Instrument { v: &self.instrument, }, Param::Limit { v: self.limit, }, Param::ToTimestamp { v: self.to_timestamp, },
+            Param::Market { v: self.market.to_string(), }, Param::Aggregate { v: self.aggregate, }, Param::Fill { v: self.fill, },
+        ];
+        if let Some(v) = self.apply_mapping {
+            params.push(Param::ApplyMapping { v, });
+        }
+        if let Some(v) = &self.groups {
+            params.push(Param::Groups { v: Some(v.clone()), });
+        }
+        params
+    }
+}
+
+
+#[derive(Clone, Debug)]
+/// Request for Instrument Metadata (Spot).
+///
+/// Note: Sent by [`CoinDesk::get_spot_instrument_metadata`](crate::CoinDesk::get_spot_instrument_metadata).
+pub struct SpotInstrumentMetadataRequest {
+    /// List of instrument symbols
+    pub instruments: Vec<String>,
+    /// Market name
+    pub market: SpotMarket,
+    /// Determines if provided instrument values are converted according to internal mappings
+    pub apply_mapping: Option<bool>,
+    /// Filtering groups (Overrides the default groups of the API endpoint)
+    pub groups: Option<Vec<Group>>,
+}
+
+impl SpotInstrumentMetadataRequest {
+    /// Creates a request with the default values of the optional parameters.
+    ///
+    /// # Input
+    /// - `instruments`: List of instrument symbols
+    pub fn new(instruments: impl IntoIterator<Item = impl ToString>) -> Self {
+        Self {
+            instruments: instruments.into_iter().map(|v| v.to_string() ).collect(), market: Default::default(), apply_mapping: None,
+            groups: None,
+        }
+    }
+}
+
+impl_setters!(SpotInstrumentMetadataRequest { market: SpotMarket, apply_mapping, groups });
+
+impl Endpoint for SpotInstrumentMetadataRequest {
+    type API = APIEndpoint;
+    type Response = CoinDeskResponse<HashMap<String, SpotInstrumentMetdata>>;
+
+    fn endpoint(&self) -> APIEndpoint {
+        APIEndpoint::SpotInstrumentMetadata
+    }
+
+    fn params(&self) -> Vec<Param<'_>> {
+        let mut params: Vec<Param> = vec![Param::Instruments { v: &self.instruments, }, Param::Market { v: self.market.to_string(), }];
+        if let Some(v) = self.apply_mapping {
+            params.push(Param::ApplyMapping { v, });
+        }
+        if let Some(v) = &self.groups {
+            params.push(Param::Groups { v: Some(v.clone()), });
+        }
+        params
+    }
+}
+
+
+#[derive(Clone, Debug)]
+/// Request for Markets (Spot) - V2.
+///
+/// Note: Sent by [`CoinDesk::get_spot_markets_v2`](crate::CoinDesk::get_spot_markets_v2).
+pub struct SpotMarketsRequest {
+    /// Markets' names
+    pub markets: Vec<SpotMarket>,
+}
+
+impl SpotMarketsRequest {
+    /// Creates a request with the default values of the optional parameters.
+    ///
+    /// # Input
+    /// - `markets`: Markets' names
+    pub fn new(markets: impl IntoIterator<Item = SpotMarket>) -> Self {
+        Self { markets: markets.into_iter().collect(), }
+    }
+}
+
+impl Endpoint for SpotMarketsRequest {
+    type API = APIEndpoint;
+    type Response = CoinDeskResponse<HashMap<String, SpotMarkets>>;
+
+    fn endpoint(&self) -> APIEndpoint {
+        APIEndpoint::SpotMarketsV2
+    }
+
+    fn params(&self) -> Vec<Param<'_>> {
+        vec![Param::Markets { v: self.markets.iter().map(|v| v.to_string() ).collect(), }]
+    }
+}
+
+
+#[derive(Clone, Debug)]
+/// Request for Markets + Instruments \[Mapped\] (Spot).
+///
+/// Note: Sent by [`CoinDesk::get_spot_markets_instruments`](crate::CoinDesk::get_spot_markets_instruments).
+pub struct SpotMarketsInstrumentsRequest {
+    /// List of instrument symbols
+    pub instruments: Vec<String>,
+    /// Market name
+    pub market: SpotMarket,
+    /// Status of the instrument (e.g., `ACTIVE`, `EXPIRED`)
+    pub instrument_status: SpotInstrumentStatus,
+}
+
+impl SpotMarketsInstrumentsRequest {
+    /// Creates a request with the default values of the optional parameters.
+    ///
+    /// # Input
+    /// - `instruments`: List of instrument symbols
+    pub fn new(instruments: impl IntoIterator<Item = impl ToString>) -> Self {
+        Self {
+            instruments: instruments.into_iter().map(|v| v.to_string() ).collect(), market: Default::default(),
+            instrument_status: Default::default(),
+        }
+    }
+
+    /// Sets the `instrument_status` parameter (Status of the instrument, e.g., `ACTIVE` or `EXPIRED`).
+    pub fn instrument_status(mut self, instrument_status: SpotInstrumentStatus) -> Self {
+        self.instrument_status = instrument_status;
+        self
+    }
+}
+
+impl_setters!(SpotMarketsInstrumentsRequest { market: SpotMarket });
+
+impl Endpoint for SpotMarketsInstrumentsRequest {
+    type API = APIEndpoint;
+    type Response = CoinDeskResponse<HashMap<String, SpotMarketsInstruments>>;
+
+    fn endpoint(&self) -> APIEndpoint {
+        APIEndpoint::SpotMarketsInstruments
+    }
+
+    fn params(&self) -> Vec<Param<'_>> {
+        vec![Param::Instruments { v: &self.instruments, }, Param::Market { v: self.market.to_string(), }, Param::InstrumentStatus { v: self.instrument_status, }]
+    }
+}
+
+
+#[derive(Clone, Debug)]
+/// Request for Historical OHLCV+ \[Day, Hour, Minute\] (Futures).
+///
+/// Note: Sent by [`CoinDesk::get_futures_ohlcv`](crate::CoinDesk::get_futures_ohlcv).
+pub struct FuturesOHLCVRequest {
+    /// Instrument symbol
+    pub instrument: String,
+    /// Final timestamp up to which the data will be extracted
+    pub to_timestamp: Option<i64>,
+    /// Maximum number of datapoints per API endpoint call
+    pub limit: Option<usize>,
+    /// Market name
+    pub market: FuturesMarket,
+    /// Unit of the interval between successive data points
+    pub unit: Unit,
+    /// Number of data points to aggregate into a single data point (e.g., `5` with the minutely unit returns 5-minute data)
+    pub aggregate: Option<u32>,
+    /// Determines if the intervals without any trades are filled with the data of the previous interval
+    pub fill: Option<bool>,
+    /// Determines if provided instrument values are converted according to internal mappings
+    pub apply_mapping: Option<bool>,
+    /// Filtering groups (Overrides the default groups of the API endpoint)
+    pub groups: Option<Vec<Group>>,
+}
+
+impl FuturesOHLCVRequest {
+    /// Creates a request with the default values of the optional parameters.
+    ///
+    /// # Input
+    /// - `instrument`: Instrument symbol
+    pub fn new(instrument: &str) -> Self {
+        Self {
+            instrument: instrument.to_owned(), to_timestamp: None, limit: None, market: Default::default(), unit: Default::default(),
+            aggregate: None, fill: None, apply_mapping: None, groups: None,
+        }
+    }
+}
+
+impl_setters!(FuturesOHLCVRequest {
+    to_timestamp, limit, market: FuturesMarket, unit,
+    aggregate: "Number of data points to aggregate into a single data point, e.g., `5` with the minutely unit returns 5-minute data",
+    fill: "Determines if the intervals without any trades are filled with the data of the previous interval", apply_mapping, groups
+});
+
+impl Endpoint for FuturesOHLCVRequest {
+    type API = APIEndpoint;
+    type Response = CoinDeskResponse<Vec<FuturesOHLCV>>;
+
+    fn endpoint(&self) -> APIEndpoint {
+        APIEndpoint::FuturesOHLCV
+    }
+
+    fn unit(&self) -> Unit {
+        self.unit
+    }
+
+    fn params(&self) -> Vec<Param<'_>> {
+        let mut params: Vec<Param> = vec![
+            Param::Instrument { v: &self.instrument, }, Param::Limit { v: self.limit, }, Param::ToTimestamp { v: self.to_timestamp, },
+            Param::Market { v: self.market.to_string(), }, Param::Aggregate { v: self.aggregate, }, Param::Fill { v: self.fill, },
+        ];
+        if let Some(v) = self.apply_mapping {
+            params.push(Param::ApplyMapping { v, });
+        }
+        if let Some(v) = &self.groups {
+            params.push(Param::Groups { v: Some(v.clone()), });
+        }
+        params
+    }
+}
+
+
+#[derive(Clone, Debug)]
+/// Request for Instrument Metadata (Futures).
+///
+/// Note: Sent by [`CoinDesk::get_futures_instrument_metadata`](crate::CoinDesk::get_futures_instrument_metadata).
+pub struct FuturesInstrumentMetadataRequest {
+    /// List of instrument symbols
+    pub instruments: Vec<String>,
+    /// Market name
+    pub market: FuturesMarket,
+    /// Determines if provided instrument values are converted according to internal mappings
+    pub apply_mapping: Option<bool>,
+    /// Filtering groups (Overrides the default groups of the API endpoint)
+    pub groups: Option<Vec<Group>>,
+}
+
+impl FuturesInstrumentMetadataRequest {
+    /// Creates a request with the default values of the optional parameters.
+    ///
+    /// # Input
+    /// - `instruments`: List of instrument symbols
+    pub fn new(instruments: impl IntoIterator<Item = impl ToString>) -> Self {
+        Self {
+            instruments: instruments.into_iter().map(|v| v.to_string() ).collect(), market: Default::default(), apply_mapping: None,
+            groups: None,
+        }
+    }
+}
+
+impl_setters!(FuturesInstrumentMetadataRequest { market: FuturesMarket, apply_mapping, groups });
+
+impl Endpoint for FuturesInstrumentMetadataRequest {
+    type API = APIEndpoint;
+    type Response = CoinDeskResponse<HashMap<String, FuturesInstrumentMetadata>>;
+
+    fn endpoint(&self) -> APIEndpoint {
+        APIEndpoint::FuturesInstrumentMetadata
+    }
+
+    fn params(&self) -> Vec<Param<'_>> {
+        let mut params: Vec<Param> = vec![Param::Instruments { v: &self.instruments, }, Param::Market { v: self.market.to_string(), }];
+        if let Some(v) = self.apply_mapping {
+            params.push(Param::ApplyMapping { v, });
+        }
+        if let Some(v) = &self.groups {
+            params.push(Param::Groups { v: Some(v.clone()), });
+        }
+        params
+    }
+}
+
+
+#[derive(Clone, Debug)]
+/// Request for Markets (Futures).
+///
+/// Note: Sent by [`CoinDesk::get_futures_markets_v2`](crate::CoinDesk::get_futures_markets_v2).
+pub struct FuturesMarketsRequest {
+    /// Markets' names
+    pub markets: Vec<FuturesMarket>,
+}
+
+impl FuturesMarketsRequest {
+    /// Creates a request with the default values of the optional parameters.
+    ///
+    /// # Input
+    /// - `markets`: Markets' names
+    pub fn new(markets: impl IntoIterator<Item = FuturesMarket>) -> Self {
+        Self { markets: markets.into_iter().collect(), }
+    }
+}
+
+impl Endpoint for FuturesMarketsRequest {
+    type API = APIEndpoint;
+    type Response = CoinDeskResponse<HashMap<String, FuturesMarkets>>;
+
+    fn endpoint(&self) -> APIEndpoint {
+        APIEndpoint::FuturesMarketsV2
+    }
+
+    fn params(&self) -> Vec<Param<'_>> {
+        vec![Param::Markets { v: self.markets.iter().map(|v| v.to_string() ).collect(), }]
+    }
+}
+
+
+#[derive(Clone, Debug)]
+/// Request for Historical OHLCV+ \[Day, Hour, Minute\] (Options).
+///
+/// Note: Sent by [`CoinDesk::get_options_ohlcv`](crate::CoinDesk::get_options_ohlcv).
+pub struct OptionsOHLCVRequest {
+    /// Instrument symbol
+    pub instrument: String,
+    /// Final timestamp up to which the data will be extracted
+    pub to_timestamp: Option<i64>,
+    /// Maximum number of datapoints per API endpoint call
+    pub limit: Option<usize>,
+    /// Market name
+    pub market: OptionsMarket,
+    /// Unit of the interval between successive data points
+    pub unit: Unit,
+    /// Number of data points to aggregate into a single data point (e.g., `5` with the minutely unit returns 5-minute data)
+    pub aggregate: Option<u32>,
+    /// Determines if the intervals without any trades are filled with the data of the previous interval
+    pub fill: Option<bool>,
+    /// Determines if provided instrument values are converted according to internal mappings
+    pub apply_mapping: Option<bool>,
+    /// Filtering groups (Overrides the default groups of the API endpoint)
+    pub groups: Option<Vec<Group>>,
+}
+
+impl OptionsOHLCVRequest {
+    /// Creates a request with the default values of the optional parameters.
+    ///
+    /// # Input
+    /// - `instrument`: Instrument symbol
+    pub fn new(instrument: &str) -> Self {
+        Self {
+            instrument: instrument.to_owned(), to_timestamp: None, limit: None, market: Default::default(), unit: Default::default(),
+            aggregate: None, fill: None, apply_mapping: None, groups: None,
+        }
+    }
+}
+
+impl_setters!(OptionsOHLCVRequest {
+    to_timestamp, limit, market: OptionsMarket, unit,
+    aggregate: "Number of data points to aggregate into a single data point, e.g., `5` with the minutely unit returns 5-minute data",
+    fill: "Determines if the intervals without any trades are filled with the data of the previous interval", apply_mapping, groups
+});
+
+impl Endpoint for OptionsOHLCVRequest {
+    type API = APIEndpoint;
+    type Response = CoinDeskResponse<Vec<OptionsOHLCV>>;
+
+    fn endpoint(&self) -> APIEndpoint {
+        APIEndpoint::OptionsOHLCV
+    }
+
+    fn unit(&self) -> Unit {
+        self.unit
+    }
+
+    fn params(&self) -> Vec<Param<'_>> {
+        let mut params: Vec<Param> = vec![
+            Param::Instrument { v: &self.instrument, }, Param::Limit { v: self.limit, }, Param::ToTimestamp { v: self.to_timestamp, },
+            Param::Market { v: self.market.to_string(), }, Param::Aggregate { v: self.aggregate, }, Param::Fill { v: self.fill, },
+        ];
+        if let Some(v) = self.apply_mapping {
+            params.push(Param::ApplyMapping { v, });
+        }
+        if let Some(v) = &self.groups {
+            params.push(Param::Groups { v: Some(v.clone()), });
+        }
+        params
+    }
+}
+
+
+#[derive(Clone, Debug)]
+/// Request for Instrument Metadata (Options).
+///
+/// Note: Sent by [`CoinDesk::get_options_instrument_metadata`](crate::CoinDesk::get_options_instrument_metadata).
+pub struct OptionsInstrumentMetadataRequest {
+    /// List of instrument symbols
+    pub instruments: Vec<String>,
+    /// Market name
+    pub market: OptionsMarket,
+    /// Determines if provided instrument values are converted according to internal mappings
+    pub apply_mapping: Option<bool>,
+    /// Filtering groups (Overrides the default groups of the API endpoint)
+    pub groups: Option<Vec<Group>>,
+}
+
+impl OptionsInstrumentMetadataRequest {
+    /// Creates a request with the default values of the optional parameters.
+    ///
+    /// # Input
+    /// - `instruments`: List of instrument symbols
+    pub fn new(instruments: impl IntoIterator<Item = impl ToString>) -> Self {
+        Self {
+            instruments: instruments.into_iter().map(|v| v.to_string() ).collect(), market: Default::default(), apply_mapping: None,
+            groups: None,
+        }
+    }
+}
+
+impl_setters!(OptionsInstrumentMetadataRequest { market: OptionsMarket, apply_mapping, groups });
+
+impl Endpoint for OptionsInstrumentMetadataRequest {
+    type API = APIEndpoint;
+    type Response = CoinDeskResponse<HashMap<String, OptionsInstrumentMetadata>>;
+
+    fn endpoint(&self) -> APIEndpoint {
+        APIEndpoint::OptionsInstrumentMetadata
+    }
+
+    fn params(&self) -> Vec<Param<'_>> {
+        let mut params: Vec<Param> = vec![Param::Instruments { v: &self.instruments, }, Param::Market { v: self.market.to_string(), }];
+        if let Some(v) = self.apply_mapping {
+            params.push(Param::ApplyMapping { v, });
+        }
+        if let Some(v) = &self.groups {
+            params.push(Param::Groups { v: Some(v.clone()), });
+        }
+        params
+    }
+}
+
+
+#[derive(Clone, Debug)]
+/// Request for Markets (Options).
+///
+/// Note: Sent by [`CoinDesk::get_options_markets_v2`](crate::CoinDesk::get_options_markets_v2).
+pub struct OptionsMarketsRequest {
+    /// Markets' names
+    pub markets: Vec<OptionsMarket>,
+}
+
+impl OptionsMarketsRequest {
+    /// Creates a request with the default values of the optional parameters.
+    ///
+    /// # Input
+    /// - `markets`: Markets' names
+    pub fn new(markets: impl IntoIterator<Item = OptionsMarket>) -> Self {
+        Self { markets: markets.into_iter().collect(), }
+    }
+}
+
+impl Endpoint for OptionsMarketsRequest {
+    type API = APIEndpoint;
+    type Response = CoinDeskResponse<HashMap<String, OptionsMarkets>>;
+
+    fn endpoint(&self) -> APIEndpoint {
+        APIEndpoint::OptionsMarketsV2
+    }
+
+    fn params(&self) -> Vec<Param<'_>> {
+        vec![Param::Markets { v: self.markets.iter().map(|v| v.to_string() ).collect(), }]
+    }
+}
+
+
+#[derive(Clone, Debug)]
+/// Request for Historical OHLCV+ \[Day, Hour, Minute\] (Derivatives Indices).
+///
+/// Note: Sent by [`CoinDesk::get_der_indices_ohlcv`](crate::CoinDesk::get_der_indices_ohlcv).
+pub struct DerIndicesOHLCVRequest {
+    /// Instrument symbol
+    pub instrument: String,
+    /// Final timestamp up to which the data will be extracted
+    pub to_timestamp: Option<i64>,
+    /// Maximum number of datapoints per API endpoint call
+    pub limit: Option<usize>,
+    /// Market name
+    pub market: DerIndicesMarket,
+    /// Unit of the interval between successive data points
+    pub unit: Unit,
+    /// Number of data points to aggregate into a single data point (e.g., `5` with the minutely unit returns 5-minute data)
+    pub aggregate: Option<u32>,
+    /// Determines if the intervals without any trades are filled with the data of the previous interval
+    pub fill: Option<bool>,
+    /// Determines if provided instrument values are converted according to internal mappings
+    pub apply_mapping: Option<bool>,
+    /// Filtering groups (Overrides the default groups of the API endpoint)
+    pub groups: Option<Vec<Group>>,
+}
+
+impl DerIndicesOHLCVRequest {
+    /// Creates a request with the default values of the optional parameters.
+    ///
+    /// # Input
+    /// - `instrument`: Instrument symbol
+    pub fn new(instrument: &str) -> Self {
+        Self {
+            instrument: instrument.to_owned(), to_timestamp: None, limit: None, market: Default::default(), unit: Default::default(),
+            aggregate: None, fill: None, apply_mapping: None, groups: None,
+        }
+    }
+}
+
+impl_setters!(DerIndicesOHLCVRequest {
+    to_timestamp, limit, market: DerIndicesMarket, unit,
+    aggregate: "Number of data points to aggregate into a single data point, e.g., `5` with the minutely unit returns 5-minute data",
+    fill: "Determines if the intervals without any trades are filled with the data of the previous interval", apply_mapping, groups
+});
+
+impl Endpoint for DerIndicesOHLCVRequest {
+    type API = APIEndpoint;
+    type Response = CoinDeskResponse<Vec<DerIndicesOHLCV>>;
+
+    fn endpoint(&self) -> APIEndpoint {
+        APIEndpoint::DerIndicesOHLCV
+    }
+
+    fn unit(&self) -> Unit {
+        self.unit
+    }
+
+    fn params(&self) -> Vec<Param<'_>> {
+        let mut params: Vec<Param> = vec![
+            Param::Instrument { v: &self.instrument, }, Param::Limit { v: self.limit, }, Param::ToTimestamp { v: self.to_timestamp, },
+            Param::Market { v: self.market.to_string(), }, Param::Aggregate { v: self.aggregate, }, Param::Fill { v: self.fill, },
+        ];
+        if let Some(v) = self.apply_mapping {
+            params.push(Param::ApplyMapping { v, });
+        }
+        if let Some(v) = &self.groups {
+            params.push(Param::Groups { v: Some(v.clone()), });
+        }
+        params
+    }
+}
+
+
+#[derive(Clone, Debug)]
+/// Request for Markets (Derivatives Indices).
+///
+/// Note: Sent by [`CoinDesk::get_der_indices_markets_v2`](crate::CoinDesk::get_der_indices_markets_v2).
+pub struct DerIndicesMarketsRequest {
+    /// Markets' names
+    pub markets: Vec<DerIndicesMarket>,
+}
+
+impl DerIndicesMarketsRequest {
+    /// Creates a request with the default values of the optional parameters.
+    ///
+    /// # Input
+    /// - `markets`: Markets' names
+    pub fn new(markets: impl IntoIterator<Item = DerIndicesMarket>) -> Self {
+        Self { markets: markets.into_iter().collect(), }
+    }
+}
+
+impl Endpoint for DerIndicesMarketsRequest {
+    type API = APIEndpoint;
+    type Response = CoinDeskResponse<HashMap<String, DerIndicesMarkets>>;
+
+    fn endpoint(&self) -> APIEndpoint {
+        APIEndpoint::DerIndicesMarketsV2
+    }
+
+    fn params(&self) -> Vec<Param<'_>> {
+        vec![Param::Markets { v: self.markets.iter().map(|v| v.to_string() ).collect(), }]
+    }
+}
+
+
+#[derive(Clone, Debug)]
+/// Request for Historical OHLCV+ (Swap) \[Day, Hour, Minute\] (On-Chain DEX).
+///
+/// Note: Sent by [`CoinDesk::get_ocdex_ohlcv`](crate::CoinDesk::get_ocdex_ohlcv).
+pub struct OCDEXOHLCVRequest {
+    /// Instrument symbol
+    pub instrument: String,
+    /// Final timestamp up to which the data will be extracted
+    pub to_timestamp: Option<i64>,
+    /// Maximum number of datapoints per API endpoint call
+    pub limit: Option<usize>,
+    /// Market name
+    pub market: OCDEXMarket,
+    /// Unit of the interval between successive data points
+    pub unit: Unit,
+    /// Number of data points to aggregate into a single data point (e.g., `5` with the minutely unit returns 5-minute data)
+    pub aggregate: Option<u32>,
+    /// Determines if the intervals without any trades are filled with the data of the previous interval
+    pub fill: Option<bool>,
+    /// Filtering groups (Overrides the default groups of the API endpoint)
+    pub groups: Option<Vec<Group>>,
+}
+
+impl OCDEXOHLCVRequest {
+    /// Creates a request with the default values of the optional parameters.
+    ///
+    /// # Input
+    /// - `instrument`: Instrument symbol
+    pub fn new(instrument: &str) -> Self {
+        Self {
+            instrument: instrument.to_owned(), to_timestamp: None, limit: None, market: Default::default(), unit: Default::default(),
+            aggregate: None, fill: None, groups: None,
+        }
+    }
+}
+
+impl_setters!(OCDEXOHLCVRequest {
+    to_timestamp, limit, market: OCDEXMarket, unit,
+    aggregate: "Number of data points to aggregate into a single data point, e.g., `5` with the minutely unit returns 5-minute data",
+    fill: "Determines if the intervals without any trades are filled with the data of the previous interval", groups
+});
+
+impl Endpoint for OCDEXOHLCVRequest {
+    type API = APIEndpoint;
+    type Response = CoinDeskResponse<Vec<OCDEXOHLCV>>;
+
+    fn endpoint(&self) -> APIEndpoint {
+        APIEndpoint::OCDEXOHLCV
+    }
+
+    fn unit(&self) -> Unit {
+        self.unit
+    }
+
+    fn params(&self) -> Vec<Param<'_>> {
+        let mut params: Vec<Param> = vec![
+            Param::Instrument { v: &self.instrument, }, Param::Limit { v: self.limit, }, Param::ToTimestamp { v: self.to_timestamp, },
+            Param::Market { v: self.market.to_string(), }, Param::Aggregate { v: self.aggregate, }, Param::Fill { v: self.fill, },
+        ];
+        if let Some(v) = &self.groups {
+            params.push(Param::Groups { v: Some(v.clone()), });
+        }
+        params
+    }
+}
+
+
+#[derive(Clone, Debug)]
+/// Request for Markets (On-Chain DEX).
+///
+/// Note: Sent by [`CoinDesk::get_ocdex_markets_v2`](crate::CoinDesk::get_ocdex_markets_v2).
+pub struct OCDEXMarketsRequest {
+    /// Markets' names
+    pub markets: Vec<OCDEXMarket>,
+}
+
+impl OCDEXMarketsRequest {
+    /// Creates a request with the default values of the optional parameters.
+    ///
+    /// # Input
+    /// - `markets`: Markets' names
+    pub fn new(markets: impl IntoIterator<Item = OCDEXMarket>) -> Self {
+        Self { markets: markets.into_iter().collect(), }
+    }
+}
+
+impl Endpoint for OCDEXMarketsRequest {
+    type API = APIEndpoint;
+    type Response = CoinDeskResponse<HashMap<String, OCDEXMarkets>>;
+
+    fn endpoint(&self) -> APIEndpoint {
+        APIEndpoint::OCDEXMarketsV2
+    }
+
+    fn params(&self) -> Vec<Param<'_>> {
+        vec![Param::Markets { v: self.markets.iter().map(|v| v.to_string() ).collect(), }]
+    }
+}
+
+
+#[derive(Clone, Debug)]
+/// Request for ETH Blocks \[Full Processed\] (On-Chain Core).
+///
+/// Note: Sent by [`CoinDesk::get_occore_eth_block`](crate::CoinDesk::get_occore_eth_block).
+pub struct OCCoreETHBlockRequest {
+    /// Block number on the blockchain
+    pub block_number: i64,
+    /// Filtering groups (Overrides the default groups of the API endpoint)
+    pub groups: Option<Vec<Group>>,
+}
+
+impl OCCoreETHBlockRequest {
+    /// Creates a request with the default values of the optional parameters.
+    ///
+    /// # Input
+    /// - `block_number`: Block number on the blockchain
+    pub fn new(block_number: i64) -> Self {
+        Self { block_number, groups: None, }
+    }
+}
+
+impl_setters!(OCCoreETHBlockRequest { groups });
+
+impl Endpoint for OCCoreETHBlockRequest {
+    type API = APIEndpoint;
+    type Response = CoinDeskResponse<OCCoreETHBlock>;
+
+    fn endpoint(&self) -> APIEndpoint {
+        APIEndpoint::OCCoreETHBlocks
+    }
+
+    fn params(&self) -> Vec<Param<'_>> {
+        let mut params: Vec<Param> = vec![Param::OCCoreBlockNumber { v: self.block_number, }];
+        if let Some(v) = &self.groups {
+            params.push(Param::Groups { v: Some(v.clone()), });
+        }
+        params
+    }
+}
+
+
+#[derive(Clone, Debug)]
+/// Request for Assets Summary By Chain (On-Chain Core).
+///
+/// Note: Sent by [`CoinDesk::get_occore_assets_by_chain`](crate::CoinDesk::get_occore_assets_by_chain).
+pub struct OCCoreAssetsByChainRequest {
+    /// Chain asset symbol
+    pub chain_asset: String,
+    /// Matching priority for the asset key provided in the asset parameter
+    pub asset_lookup_priority: Option<AssetLookupPriority>,
+}
+
+impl OCCoreAssetsByChainRequest {
+    /// Creates a request with the default values of the optional parameters.
+    ///
+    /// # Input
+    /// - `chain_asset`: Chain asset symbol
+    pub fn new(chain_asset: &str) -> Self {
+        Self { chain_asset: chain_asset.to_owned(), asset_lookup_priority: None, }
+    }
+}
+
+impl_setters!(OCCoreAssetsByChainRequest { asset_lookup_priority });
+
+impl Endpoint for OCCoreAssetsByChainRequest {
+    type API = APIEndpoint;
+    type Response = CoinDeskResponse<OCCoreAssetByChain>;
+
+    fn endpoint(&self) -> APIEndpoint {
+        APIEndpoint::OCCoreAssetsByChain
+    }
+
+    fn params(&self) -> Vec<Param<'_>> {
+        let mut params: Vec<Param> = vec![Param::ChainAsset { v: &self.chain_asset, }];
+        if let Some(v) = &self.asset_lookup_priority {
+            params.push(Param::AssetLookupPriority { v: v.clone(), });
+        }
+        params
+    }
+}
+
+
+#[derive(Clone, Debug)]
+/// Request for Asset By Address Lookup (On-Chain Core).
+///
+/// Note: Sent by [`CoinDesk::get_occore_asset_by_address`](crate::CoinDesk::get_occore_asset_by_address).
+pub struct OCCoreAssetByAddressRequest {
+    /// Chain asset symbol
+    pub chain_asset: String,
+    /// Blockchain address
+    pub address: String,
+    /// Asset to quote data in
+    pub quote_asset: String,
+    /// Matching priority for the asset key provided in the asset parameter
+    pub asset_lookup_priority: Option<AssetLookupPriority>,
+    /// Filtering groups (Overrides the default groups of the API endpoint)
+    pub groups: Option<Vec<Group>>,
+}
+
+impl OCCoreAssetByAddressRequest {
+    /// Creates a request with the default values of the optional parameters.
+    ///
+    /// # Input
+    /// - `chain_asset`: Chain asset symbol
+    /// - `address`: Blockchain address
+    pub fn new(chain_asset: &str, address: &str) -> Self {
+        Self {
+            chain_asset: chain_asset.to_owned(), address: address.to_owned(), quote_asset: String::from("USD"), asset_lookup_priority: None,
+            groups: None,
+        }
+    }
+
+    /// Sets the `quote_asset` parameter (Asset to quote data in).
+    pub fn quote_asset(mut self, quote_asset: &str) -> Self {
+        self.quote_asset = quote_asset.to_owned();
+        self
+    }
+}
+
+impl_setters!(OCCoreAssetByAddressRequest { asset_lookup_priority, groups });
+
+impl Endpoint for OCCoreAssetByAddressRequest {
+    type API = APIEndpoint;
+    type Response = CoinDeskResponse<OCCoreAssetByAddress>;
+
+    fn endpoint(&self) -> APIEndpoint {
+        APIEndpoint::OCCoreAssetByAddress
+    }
+
+    fn params(&self) -> Vec<Param<'_>> {
+        let mut params: Vec<Param> = vec![Param::ChainAsset { v: &self.chain_asset, }, Param::OCCoreAddress { v: &self.address, }, Param::OCCoreQuoteAsset { v: &self.quote_asset, }];
+        if let Some(v) = &self.asset_lookup_priority {
+            params.push(Param::AssetLookupPriority { v: v.clone(), });
+        }
+        if let Some(v) = &self.groups {
+            params.push(Param::Groups { v: Some(v.clone()), });
+        }
+        params
+    }
+}
+
+
+#[derive(Clone, Debug)]
+/// Request for Historical Supply Day (On-Chain Core).
+///
+/// Note: Sent by [`CoinDesk::get_occore_supply`](crate::CoinDesk::get_occore_supply).
+pub struct OCCoreSupplyRequest {
+    /// Asset symbol
+    pub asset: String,
+    /// Final timestamp up to which the data will be extracted
+    pub to_timestamp: Option<i64>,
+    /// Maximum number of datapoints per API endpoint call
+    pub limit: Option<usize>,
+    /// Matching priority for the asset key provided in the asset parameter
+    pub asset_lookup_priority: Option<AssetLookupPriority>,
+}
+
+impl OCCoreSupplyRequest {
+    /// Creates a request with the default values of the optional parameters.
+    ///
+    /// # Input
+    /// - `asset`: Asset symbol
+    pub fn new(asset: &str) -> Self {
+        Self { asset: asset.to_owned(), to_timestamp: None, limit: None, asset_lookup_priority: None, }
+    }
+}
+
+impl_setters!(OCCoreSupplyRequest { to_timestamp, limit, asset_lookup_priority });
+
+impl Endpoint for OCCoreSupplyRequest {
+    type API = APIEndpoint;
+    type Response = CoinDeskResponse<Vec<OCCoreSupply>>;
+
+    fn endpoint(&self) -> APIEndpoint {
+        APIEndpoint::OCCoreSupply
+    }
+
+    fn params(&self) -> Vec<Param<'_>> {
+        let mut params: Vec<Param> = vec![Param::Asset { v: &self.asset, }, Param::ToTimestamp { v: self.to_timestamp, }, Param::Limit { v: self.limit, }];
+        if let Some(v) = &self.asset_lookup_priority {
+            params.push(Param::AssetLookupPriority { v: v.clone(), });
+        }
+        params
+    }
+}
+
+
+#[derive(Clone, Debug)]
+/// Request for Full Asset Metadata (Asset) - V2.
+///
+/// Note: Sent by [`CoinDesk::get_asset_metadata_v2`](crate::CoinDesk::get_asset_metadata_v2).
+pub struct AssetMetadataRequest {
+    /// Assets' symbols
+    pub assets: Vec<String>,
+    /// Matching priority for the asset key provided in the asset parameter
+    pub asset_lookup_priority: Option<AssetLookupPriority>,
+    /// Asset to quote data in (Overrides the default `USD` quote asset)
+    pub quote_asset: Option<String>,
+    /// Filtering groups (Overrides the default groups of the API endpoint)
+    pub groups: Option<Vec<Group>>,
+}
+
+impl AssetMetadataRequest {
+    /// Creates a request with the default values of the optional parameters.
+    ///
+    /// # Input
+    /// - `assets`: Assets' symbols
+    pub fn new(assets: impl IntoIterator<Item = impl ToString>) -> Self {
+        Self { assets: assets.into_iter().map(|v| v.to_string() ).collect(), asset_lookup_priority: None, quote_asset: None, groups: None, }
+    }
+
+    /// Sets the `quote_asset` parameter (Asset to quote data in, which overrides the default `USD` quote asset).
+    pub fn quote_asset(mut self, quote_asset: &str) -> Self {
+        self.quote_asset = Some(quote_asset.to_owned());
+        self
+    }
+}
+
+impl_setters!(AssetMetadataRequest { asset_lookup_priority, groups });
+
+impl Endpoint for AssetMetadataRequest {
+    type API = APIEndpoint;
+    type Response = CoinDeskResponse<HashMap<String, AssetMetadata>>;
+
+    fn endpoint(&self) -> APIEndpoint {
+        APIEndpoint::AssetMetadataV2
+    }
+
+    fn params(&self) -> Vec<Param<'_>> {
+        let mut params: Vec<Param> = vec![Param::Assets { v: self.assets.clone(), }];
+        if let Some(v) = &self.asset_lookup_priority {
+            params.push(Param::AssetLookupPriority { v: v.clone(), });
+        }
+        if let Some(v) = &self.quote_asset {
+            params.push(Param::QuoteAsset { v, });
+        }
+        if let Some(v) = &self.groups {
+            params.push(Param::Groups { v: Some(v.clone()), });
+        }
+        params
+    }
+}
+
+
+#[derive(Clone, Debug)]
+/// Request for Significant Asset Events (Asset).
+///
+/// Note: Sent by [`CoinDesk::get_asset_events`](crate::CoinDesk::get_asset_events).
+pub struct AssetEventsRequest {
+    /// Asset symbol
+    pub asset: String,
+    /// Final timestamp up to which the data will be extracted
+    pub to_timestamp: Option<i64>,
+    /// Maximum number of datapoints per API endpoint call
+    pub limit: Option<usize>,
+    /// Matching priority for the asset key provided in the asset parameter
+    pub asset_lookup_priority: Option<AssetLookupPriority>,
+}
+
+impl AssetEventsRequest {
+    /// Creates a request with the default values of the optional parameters.
+    ///
+    /// # Input
+    /// - `asset`: Asset symbol
+    pub fn new(asset: &str) -> Self {
+        Self { asset: asset.to_owned(), to_timestamp: None, limit: None, asset_lookup_priority: None, }
+    }
+}
+
+impl_setters!(AssetEventsRequest { to_timestamp, limit, asset_lookup_priority });
+
+impl Endpoint for AssetEventsRequest {
+    type API = APIEndpoint;
+    type Response = CoinDeskResponse<Vec<AssetEvent>>;
+
+    fn endpoint(&self) -> APIEndpoint {
+        APIEndpoint::AssetEvents
+    }
+
+    fn params(&self) -> Vec<Param<'_>> {
+        let mut params: Vec<Param> = vec![Param::Asset { v: &self.asset, }, Param::ToTimestamp { v: self.to_timestamp, }, Param::Limit { v: self.limit, }];
+        if let Some(v) = &self.asset_lookup_priority {
+            params.push(Param::AssetLookupPriority { v: v.clone(), });
+        }
+        params
+    }
+}
+
+
+#[derive(Clone, Debug)]
+/// Request for Historical Social \[Code Repository Day\] (Asset).
+///
+/// Note: Sent by [`CoinDesk::get_asset_code_repo`](crate::CoinDesk::get_asset_code_repo).
+pub struct AssetCodeRepoRequest {
+    /// Asset symbol
+    pub asset: String,
+    /// Final timestamp up to which the data will be extracted
+    pub to_timestamp: Option<i64>,
+    /// Maximum number of datapoints per API endpoint call
+    pub limit: Option<usize>,
+    /// Matching priority for the asset key provided in the asset parameter
+    pub asset_lookup_priority: Option<AssetLookupPriority>,
+    /// Number of days to aggregate into a single data point (e.g., `7` returns weekly data)
+    pub aggregate: Option<u32>,
+    /// Determines if the days without any data are filled with the data of the previous day
+    pub fill: Option<bool>,
+    /// Filtering groups (Overrides the default groups of the API endpoint)
+    pub groups: Option<Vec<Group>>,
+}
+
+impl AssetCodeRepoRequest {
+    /// Creates a request with the default values of the optional parameters.
+    ///
+    /// # Input
+    /// - `asset`: Asset symbol
+    pub fn new(asset: &str) -> Self {
+        Self {
+            asset: asset.to_owned(), to_timestamp: None, limit: None, asset_lookup_priority: None, aggregate: None, fill: None,
+            groups: None,
+        }
+    }
+}
+
+impl_setters!(AssetCodeRepoRequest {
+    to_timestamp, limit, asset_lookup_priority, aggregate: "Number of days to aggregate into a single data point, e.g., `7` returns weekly data",
+    fill: "Determines if the days without any data are filled with the data of the previous day", groups
+});
+
+impl Endpoint for AssetCodeRepoRequest {
+    type API = APIEndpoint;
+    type Response = CoinDeskResponse<Vec<AssetCodeRepoMetrics>>;
+
+    fn endpoint(&self) -> APIEndpoint {
+        APIEndpoint::AssetCodeRepo
+    }
+
+    fn params(&self) -> Vec<Param<'_>> {
+        let mut params: Vec<Param> = vec![
+            Param::Asset { v: &self.asset, }, Param::ToTimestamp { v: self.to_timestamp, }, Param::Limit { v: self.limit, },
+            Param::Aggregate { v: self.aggregate, }, Param::Fill { v: self.fill, },
+        ];
+        if let Some(v) = &self.asset_lookup_priority {
+            params.push(Param::AssetLookupPriority { v: v.clone(), });
+        }
+        if let Some(v) = &self.groups {
+            params.push(Param::Groups { v: Some(v.clone()), });
+        }
+        params
+    }
+}
+
+
+#[derive(Clone, Debug)]
+/// Request for Historical Social \[Discord Day\] (Asset).
+///
+/// Note: Sent by [`CoinDesk::get_asset_discord`](crate::CoinDesk::get_asset_discord).
+pub struct AssetDiscordRequest {
+    /// Asset symbol
+    pub asset: String,
+    /// Final timestamp up to which the data will be extracted
+    pub to_timestamp: Option<i64>,
+    /// Maximum number of datapoints per API endpoint call
+    pub limit: Option<usize>,
+    /// Matching priority for the asset key provided in the asset parameter
+    pub asset_lookup_priority: Option<AssetLookupPriority>,
+    /// Number of days to aggregate into a single data point (e.g., `7` returns weekly data)
+    pub aggregate: Option<u32>,
+    /// Determines if the days without any data are filled with the data of the previous day
+    pub fill: Option<bool>,
+    /// Filtering groups (Overrides the default groups of the API endpoint)
+    pub groups: Option<Vec<Group>>,
+}
+
+impl AssetDiscordRequest {
+    /// Creates a request with the default values of the optional parameters.
+    ///
+    /// # Input
+    /// - `asset`: Asset symbol
+    pub fn new(asset: &str) -> Self {
+        Self {
+            asset: asset.to_owned(), to_timestamp: None, limit: None, asset_lookup_priority: None, aggregate: None, fill: None,
+            groups: None,
+        }
+    }
+}
+
+impl_setters!(AssetDiscordRequest {
+    to_timestamp, limit, asset_lookup_priority, aggregate: "Number of days to aggregate into a single data point, e.g., `7` returns weekly data",
+    fill: "Determines if the days without any data are filled with the data of the previous day", groups
+});
+
+impl Endpoint for AssetDiscordRequest {
+    type API = APIEndpoint;
+    type Response = CoinDeskResponse<Vec<AssetDiscord>>;
+
+    fn endpoint(&self) -> APIEndpoint {
+        APIEndpoint::AssetDiscord
+    }
+
+    fn params(&self) -> Vec<Param<'_>> {
+        let mut params: Vec<Param> = vec![
+            Param::Asset { v: &self.asset, }, Param::ToTimestamp { v: self.to_timestamp, }, Param::Limit { v: self.limit, },
+            Param::Aggregate { v: self.aggregate, }, Param::Fill { v: self.fill, },
+        ];
+        if let Some(v) = &self.asset_lookup_priority {
+            params.push(Param::AssetLookupPriority { v: v.clone(), });
+        }
+        if let Some(v) = &self.groups {
+            params.push(Param::Groups { v: Some(v.clone()), });
+        }
+        params
+    }
+}
+
+
+#[derive(Clone, Debug)]
+/// Request for Historical Social \[Reddit Day\] (Asset).
+///
+/// Note: Sent by [`CoinDesk::get_asset_reddit`](crate::CoinDesk::get_asset_reddit).
+pub struct AssetRedditRequest {
+    /// Asset symbol
+    pub asset: String,
+    /// Final timestamp up to which the data will be extracted
+    pub to_timestamp: Option<i64>,
+    /// Maximum number of datapoints per API endpoint call
+    pub limit: Option<usize>,
+    /// Matching priority for the asset key provided in the asset parameter
+    pub asset_lookup_priority: Option<AssetLookupPriority>,
+    /// Number of days to aggregate into a single data point (e.g., `7` returns weekly data)
+    pub aggregate: Option<u32>,
+    /// Determines if the days without any data are filled with the data of the previous day
+    pub fill: Option<bool>,
+    /// Filtering groups (Overrides the default groups of the API endpoint)
+    pub groups: Option<Vec<Group>>,
+}
+
+impl AssetRedditRequest {
+    /// Creates a request with the default values of the optional parameters.
+    ///
+    /// # Input
+    /// - `asset`: Asset symbol
+    pub fn new(asset: &str) -> Self {
+        Self {
+            asset: asset.to_owned(), to_timestamp: None, limit: None, asset_lookup_priority: None, aggregate: None, fill: None,
+            groups: None,
+        }
+    }
+}
+
+impl_setters!(AssetRedditRequest {
+    to_timestamp, limit, asset_lookup_priority, aggregate: "Number of days to aggregate into a single data point, e.g., `7` returns weekly data",
+    fill: "Determines if the days without any data are filled with the data of the previous day", groups
+});
+
+impl Endpoint for AssetRedditRequest {
+    type API = APIEndpoint;
+    type Response = CoinDeskResponse<Vec<AssetReddit>>;
+
+    fn endpoint(&self) -> APIEndpoint {
+        APIEndpoint::AssetReddit
+    }
+
+    fn params(&self) -> Vec<Param<'_>> {
+        let mut params: Vec<Param> = vec![
+            Param::Asset { v: &self.asset, }, Param::ToTimestamp { v: self.to_timestamp, }, Param::Limit { v: self.limit, },
+            Param::Aggregate { v: self.aggregate, }, Param::Fill { v: self.fill, },
+        ];
+        if let Some(v) = &self.asset_lookup_priority {
+            params.push(Param::AssetLookupPriority { v: v.clone(), });
+        }
+        if let Some(v) = &self.groups {
+            params.push(Param::Groups { v: Some(v.clone()), });
+        }
+        params
+    }
+}
+
+
+#[derive(Clone, Debug)]
+/// Request for Historical Social \[Telegram Day\] (Asset).
+///
+/// Note: Sent by [`CoinDesk::get_asset_telegram`](crate::CoinDesk::get_asset_telegram).
+pub struct AssetTelegramRequest {
+    /// Asset symbol
+    pub asset: String,
+    /// Final timestamp up to which the data will be extracted
+    pub to_timestamp: Option<i64>,
+    /// Maximum number of datapoints per API endpoint call
+    pub limit: Option<usize>,
+    /// Matching priority for the asset key provided in the asset parameter
+    pub asset_lookup_priority: Option<AssetLookupPriority>,
+    /// Number of days to aggregate into a single data point (e.g., `7` returns weekly data)
+    pub aggregate: Option<u32>,
+    /// Determines if the days without any data are filled with the data of the previous day
+    pub fill: Option<bool>,
+    /// Filtering groups (Overrides the default groups of the API endpoint)
+    pub groups: Option<Vec<Group>>,
+}
+
+impl AssetTelegramRequest {
+    /// Creates a request with the default values of the optional parameters.
+    ///
+    /// # Input
+    /// - `asset`: Asset symbol
+    pub fn new(asset: &str) -> Self {
+        Self {
+            asset: asset.to_owned(), to_timestamp: None, limit: None, asset_lookup_priority: None, aggregate: None, fill: None,
+            groups: None,
+        }
+    }
+}
+
+impl_setters!(AssetTelegramRequest {
+    to_timestamp, limit, asset_lookup_priority, aggregate: "Number of days to aggregate into a single data point, e.g., `7` returns weekly data",
+    fill: "Determines if the days without any data are filled with the data of the previous day", groups
+});
+
+impl Endpoint for AssetTelegramRequest {
+    type API = APIEndpoint;
+    type Response = CoinDeskResponse<Vec<AssetTelegram>>;
+
+    fn endpoint(&self) -> APIEndpoint {
+        APIEndpoint::AssetTelegram
+    }
+
+    fn params(&self) -> Vec<Param<'_>> {
+        let mut params: Vec<Param> = vec![
+            Param::Asset { v: &self.asset, }, Param::ToTimestamp { v: self.to_timestamp, }, Param::Limit { v: self.limit, },
+            Param::Aggregate { v: self.aggregate, }, Param::Fill { v: self.fill, },
+        ];
+        if let Some(v) = &self.asset_lookup_priority {
+            params.push(Param::AssetLookupPriority { v: v.clone(), });
+        }
+        if let Some(v) = &self.groups {
+            params.push(Param::Groups { v: Some(v.clone()), });
+        }
+        params
+    }
+}
+
+
+#[derive(Clone, Debug)]
+/// Request for Historical Social \[X (Twitter) Day\] (Asset).
+///
+/// Note: Sent by [`CoinDesk::get_asset_twitter`](crate::CoinDesk::get_asset_twitter).
+pub struct AssetTwitterRequest {
+    /// Asset symbol
+    pub asset: String,
+    /// Final timestamp up to which the data will be extracted
+    pub to_timestamp: Option<i64>,
+    /// Maximum number of datapoints per API endpoint call
+    pub limit: Option<usize>,
+    /// Matching priority for the asset key provided in the asset parameter
+    pub asset_lookup_priority: Option<AssetLookupPriority>,
+    /// Number of days to aggregate into a single data point (e.g., `7` returns weekly data)
+    pub aggregate: Option<u32>,
+    /// Determines if the days without any data are filled with the data of the previous day
+    pub fill: Option<bool>,
+    /// Filtering groups (Overrides the default groups of the API endpoint)
+    pub groups: Option<Vec<Group>>,
+}
+
+impl AssetTwitterRequest {
+    /// Creates a request with the default values of the optional parameters.
+    ///
+    /// # Input
+    /// - `asset`: Asset symbol
+    pub fn new(asset: &str) -> Self {
+        Self {
+            asset: asset.to_owned(), to_timestamp: None, limit: None, asset_lookup_priority: None, aggregate: None, fill: None,
+            groups: None,
+        }
+    }
+}
+
+impl_setters!(AssetTwitterRequest {
+    to_timestamp, limit, asset_lookup_priority, aggregate: "Number of days to aggregate into a single data point, e.g., `7` returns weekly data",
+    fill: "Determines if the days without any data are filled with the data of the previous day", groups
+});
+
+impl Endpoint for AssetTwitterRequest {
+    type API = APIEndpoint;
+    type Response = CoinDeskResponse<Vec<AssetTwitter>>;
+
+    fn endpoint(&self) -> APIEndpoint {
+        APIEndpoint::AssetTwitter
+    }
+
+    fn params(&self) -> Vec<Param<'_>> {
+        let mut params: Vec<Param> = vec![
+            Param::Asset { v: &self.asset, }, Param::ToTimestamp { v: self.to_timestamp, }, Param::Limit { v: self.limit, },
+            Param::Aggregate { v: self.aggregate, }, Param::Fill { v: self.fill, },
+        ];
+        if let Some(v) = &self.asset_lookup_priority {
+            params.push(Param::AssetLookupPriority { v: v.clone(), });
+        }
+        if let Some(v) = &self.groups {
+            params.push(Param::Groups { v: Some(v.clone()), });
+        }
+        params
+    }
+}
+
+
+#[derive(Clone, Debug)]
+/// Request for Latest Articles (News).
+///
+/// Note: Sent by [`CoinDesk::get_news_latest_articles`](crate::CoinDesk::get_news_latest_articles).
+pub struct NewsLatestArticlesRequest {
+    /// Source ID of the news stream
+    pub source_id: NewsSourceID,
+    /// Language of the news
+    pub language: NewsLang,
+    /// List of news categories
+    pub categories: Option<Vec<String>>,
+    /// List of news categories to exclude
+    pub exclude_categories: Option<Vec<String>>,
+    /// Final timestamp up to which the data will be extracted
+    pub to_timestamp: Option<i64>,
+    /// Maximum number of datapoints per API endpoint call
+    pub limit: Option<usize>,
+}
+
+impl NewsLatestArticlesRequest {
+    /// Creates a request with the default values of the optional parameters.
+    ///
+    /// # Input
+    /// - `source_id`: Source ID of the news stream
+    pub fn new(source_id: NewsSourceID) -> Self {
+        Self { source_id, language: Default::default(), categories: None, exclude_categories: None, to_timestamp: None, limit: None, }
+    }
+
+    /// Sets the `categories` parameter (List of news categories).
+    pub fn categories(mut self, categories: impl IntoIterator<Item = impl ToString>) -> Self {
+        self.categories = Some(categories.into_iter().map(|v| v.to_string() ).collect());
+        self
+    }
+
+    /// Sets the `exclude_categories` parameter (List of news categories to exclude).
+    pub fn exclude_categories(mut self, exclude_categories: impl IntoIterator<Item = impl ToString>) -> Self {
+        self.exclude_categories = Some(exclude_categories.into_iter().map(|v| v.to_string() ).collect());
+        self
+    }
+}
+
+impl_setters!(NewsLatestArticlesRequest { language: NewsLang, to_timestamp, limit });
+
+impl Endpoint for NewsLatestArticlesRequest {
+    type API = APIEndpoint;
+    type Response = CoinDeskResponse<Vec<NewsLatestArticle>>;
+
+    fn endpoint(&self) -> APIEndpoint {
+        APIEndpoint::NewsLatestArticles
+    }
+
+    fn params(&self) -> Vec<Param<'_>> {
+        vec![
+            Param::NewsLanguage { v: self.language, }, Param::NewsSourceID { v: self.source_id, }, Param::NewsCategories { v: self.categories.clone(), },
+            Param::NewsExcludeCategories { v: self.exclude_categories.clone(), }, Param::ToTimestamp { v: self.to_timestamp, }, Param::Limit { v: self.limit, },
+        ]
+    }
+}
+
+
+#[derive(Clone, Debug, Default)]
+/// Request for Sources (News).
+///
+/// Note: Sent by [`CoinDesk::get_news_sources`](crate::CoinDesk::get_news_sources).
+pub struct NewsSourcesRequest {
+    /// Language of the news
+    pub language: NewsLang,
+    /// Type of news stream
+    pub source_type: NewsSourceType,
+    /// Status of the news stream (e.g., `ACTIVE`, `INACTIVE`)
+    pub status: NewsStatus,
+}
+
+impl NewsSourcesRequest {
+    /// Creates a request with the default values of the parameters.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the `source_type` parameter (Type of news stream).
+    pub fn source_type(mut self, source_type: NewsSourceType) -> Self {
+        self.source_type = source_type;
+        self
+    }
+
+    /// Sets the `status` parameter (Status of the news stream, e.g., `ACTIVE` or `INACTIVE`).
+    pub fn status(mut self, status: NewsStatus) -> Self {
+        self.status = status;
+        self
+    }
+}
+
+impl_setters!(NewsSourcesRequest { language: NewsLang });
+
+impl Endpoint for NewsSourcesRequest {
+    type API = APIEndpoint;
+    type Response = CoinDeskResponse<Vec<NewsSource>>;
+
+    fn endpoint(&self) -> APIEndpoint {
+        APIEndpoint::NewsSources
+    }
+
+    fn params(&self) -> Vec<Param<'_>> {
+        vec![Param::NewsLanguage { v: self.language, }, Param::NewsSourceType { v: self.source_type, }, Param::NewsStatus { v: self.status, }]
+    }
+}
+
+
+#[derive(Clone, Debug, Default)]
+/// Request for Categories (News).
+///
+/// Note: Sent by [`CoinDesk::get_news_categories`](crate::CoinDesk::get_news_categories).
+pub struct NewsCategoriesRequest {
+    /// Status of the news category (e.g., `ACTIVE`, `INACTIVE`)
+    pub status: NewsStatus,
+}
+
+impl NewsCategoriesRequest {
+    /// Creates a request with the default values of the parameters.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the `status` parameter (Status of the news category, e.g., `ACTIVE` or `INACTIVE`).
+    pub fn status(mut self, status: NewsStatus) -> Self {
+        self.status = status;
+        self
+    }
+}
+
+impl Endpoint for NewsCategoriesRequest {
+    type API = APIEndpoint;
+    type Response = CoinDeskResponse<Vec<NewsCategory>>;
+
+    fn endpoint(&self) -> APIEndpoint {
+        APIEndpoint::NewsCategories
+    }
+
+    fn params(&self) -> Vec<Param<'_>> {
+        vec![Param::NewsStatus { v: self.status, }]
+    }
+}
+
+
+#[derive(Clone, Debug, Default)]
+/// Request for MktCap Historical OHLCV \[All Assets Day\] (Overview).
+///
+/// Note: Sent by [`CoinDesk::get_overview_mktcap_ohlcv`](crate::CoinDesk::get_overview_mktcap_ohlcv).
+pub struct OverviewMktCapOHLCVRequest {
+    /// Final timestamp up to which the data will be extracted
+    pub to_timestamp: Option<i64>,
+    /// Maximum number of datapoints per API endpoint call
+    pub limit: Option<usize>,
+    /// Number of days to aggregate into a single data point (e.g., `7` returns weekly data)
+    pub aggregate: Option<u32>,
+    /// Determines if the days without any trades are filled with the data of the previous day
+    pub fill: Option<bool>,
+    /// Filtering groups (Overrides the default groups of the API endpoint)
+    pub groups: Option<Vec<Group>>,
+}
+
+impl OverviewMktCapOHLCVRequest {
+    /// Creates a request with the default values of the parameters.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl_setters!(OverviewMktCapOHLCVRequest {
+    to_timestamp, limit, aggregate: "Number of days to aggregate into a single data point, e.g., `7` returns weekly data",
+    fill: "Determines if the days without any trades are filled with the data of the previous day", groups
+});
+
+impl Endpoint for OverviewMktCapOHLCVRequest {
+    type API = APIEndpoint;
+    type Response = CoinDeskResponse<Vec<OverviewMktCapOHLCV>>;
+
+    fn endpoint(&self) -> APIEndpoint {
+        APIEndpoint::OverviewMktCapOHLCV
+    }
+
+    fn params(&self) -> Vec<Param<'_>> {
+        let mut params: Vec<Param> = vec![
+            Param::ToTimestamp { v: self.to_timestamp, }, Param::Limit { v: self.limit, }, Param::Aggregate { v: self.aggregate, },
+            Param::Fill { v: self.fill, },
+        ];
+        if let Some(v) = &self.groups {
+            params.push(Param::Groups { v: Some(v.clone()), });
+        }
+        params
+    }
+}
@@ -0,0 +1,464 @@
+use std::str::FromStr;
+use crate::candle::{Candle, OHLCV};
+use crate::schemas::Numeric;
+use crate::schemas::data_api::indices_and_reference_rates::IndicesOHLCV;
+use crate::schemas::data_api::spot::SpotOHLCV;
+use crate::schemas::data_api::futures::FuturesOHLCV;
+use crate::schemas::data_api::options::OptionsOHLCV;
+use crate::schemas::data_api::derivatives_indices::DerIndicesOHLCV;
+use crate::schemas::data_api::on_chain_dex::OCDEXOHLCV;
+use crate::schemas::data_api::overview::OverviewMktCapOHLCV;
+
+
+/// Timestamp of the first Monday after the Unix epoch (1970-01-05 00:00 UTC), used to align weekly buckets.
+const FIRST_MONDAY: i64 = 4 * 86_400;
+
+
+/// Candle that can be merged with the subsequent candles of the same instrument into a candle of a coarser interval.
+pub trait Resample: Candle + Clone {
+    /// Updates the timestamp of the beginning of the interval.
+    ///
+    /// # Input
+    /// - `new_timestamp`: New timestamp (in seconds) of the beginning of the interval
+    fn update_timestamp(&mut self, new_timestamp: i64) -> ();
+
+    /// Merges the subsequent candle into the candle.
+    ///
+    /// Note: The open price and the first trade are kept, the close price and the last trade are taken from `next`, the high and low prices
+    /// (and their trades) are the extremes of both candles, and the volumes and trade counts are summed.
+    ///
+    /// # Input
+    /// - `next`: Candle that directly follows the candle (or a later candle of the same bucket)
+    fn merge(&mut self, next: &Self) -> ();
+}
+
+/// Sums the fields of `next` into the fields of `current`.
+macro_rules! sum {
+    ($current:ident, $next:ident, $($field:ident),*) => {
+        $( $current.$field += $next.$field; )*
+    };
+}
+
+/// Keeps the higher value.
+fn max(current: &mut Numeric, next: Numeric) -> () {
+    if *current < next {
+        *current = next;
+    }
+}
+
+/// Keeps the lower value.
+fn min(current: &mut Numeric, next: Numeric) -> () {
+    if next < *current {
+        *current = next;
+    }
+}
+
+/// Keeps the current value, unless it is missing.
+fn first<T: Copy>(current: &mut Option<T>, next: Option<T>) -> () {
+    if current.is_none() {
+        *current = next;
+    }
+}
+
+/// Takes the next value, unless it is missing.
+fn last<T: Copy>(current: &mut Option<T>, next: Option<T>) -> () {
+    if next.is_some() {
+        *current = next;
+    }
+}
+
+/// Sums the values that are stored as strings (The current value is kept if either of the values is not a number).
+fn sum_str(current: &mut String, next: &str) -> () {
+    if let (Ok(a), Ok(b)) = (Numeric::from_str(current), Numeric::from_str(next)) {
+        *current = (a + b).to_string();
+    }
+}
+
+/// Merges the open, high, low and close prices.
+macro_rules! merge_ohlc {
+    ($current:ident, $next:ident) => {
+        max(&mut $current.high, $next.high);
+        min(&mut $current.low, $next.low);
+        $current.close = $next.close;
+    };
+}
+
+impl Resample for SpotOHLCV {
+    fn update_timestamp(&mut self, new_timestamp: i64) -> () {
+        self.timestamp = new_timestamp;
+    }
+
+    fn merge(&mut self, next: &Self) -> () {
+        merge_ohlc!(self, next);
+        first(&mut self.first_trade_timestamp, next.first_trade_timestamp);
+        first(&mut self.first_trade_price, next.first_trade_price);
+        last(&mut self.last_trade_timestamp, next.last_trade_timestamp);
+        last(&mut self.last_trade_price, next.last_trade_price);
+        if let Some(price) = next.high_trade_price {
+            if self.high_trade_price.is_none_or(|v| v < price ) {
+                (self.high_trade_price, self.high_trade_timestamp) = (next.high_trade_price, next.high_trade_timestamp);
+            }
+        }
+        if let Some(price) = next.low_trade_price {
+            if self.low_trade_price.is_none_or(|v| price < v ) {
+                (self.low_trade_price, self.low_trade_timestamp) = (next.low_trade_price, next.low_trade_timestamp);
+            }
+        }
+        sum!(self, next, total_trades, total_trades_buy, total_trades_sell, total_trades_unknown);
+        sum!(self, next, volume, quote_volume, volume_buy, quote_volume_buy, volume_sell, quote_volume_sell, volume_unknown, quote_volume_unknown);
+    }
+}
+
+impl Resample for FuturesOHLCV {
+    fn update_timestamp(&mut self, new_timestamp: i64) -> () {
+        self.timestamp = new_timestamp;
+    }
+
+    fn merge(&mut self, next: &Self) -> () {
+        merge_ohlc!(self, next);
+        // Trade fields of the candles without any trades are zeros
+        if self.total_trades == 0 {
+            (self.first_trade_timestamp, self.first_trade_price) = (next.first_trade_timestamp, next.first_trade_price);
+            (self.high_trade_timestamp, self.high_trade_price) = (next.high_trade_timestamp, next.high_trade_price);
+            (self.low_trade_timestamp, self.low_trade_price) = (next.low_trade_timestamp, next.low_trade_price);
+            (self.last_trade_timestamp, self.last_trade_price) = (next.last_trade_timestamp, next.last_trade_price);
+        } else if 0 < next.total_trades {
+            (self.last_trade_timestamp, self.last_trade_price) = (next.last_trade_timestamp, next.last_trade_price);
+            if self.high_trade_price < next.high_trade_price {
+                (self.high_trade_price, self.high_trade_timestamp) = (next.high_trade_price, next.high_trade_timestamp);
+            }
+            if next.low_trade_price < self.low_trade_price {
+                (self.low_trade_price, self.low_trade_timestamp) = (next.low_trade_price, next.low_trade_timestamp);
+            }
+        }
+        sum!(self, next, total_trades, total_trades_buy, total_trades_sell, total_trades_unknown, number_of_contracts);
+        sum!(self, next, volume, quote_volume, volume_buy, quote_volume_buy, volume_sell, quote_volume_sell, volume_unknown, quote_volume_unknown);
+    }
+}
+
+impl Resample for OptionsOHLCV {
+    fn update_timestamp(&mut self, new_timestamp: i64) -> () {
+        self.timestamp = new_timestamp;
+    }
+
+    fn merge(&mut self, next: &Self) -> () {
+        merge_ohlc!(self, next);
+        sum!(self, next, number_of_contracts, total_trades, total_trades_buy, total_trades_sell, total_trades_unknown);
+        sum!(self, next, volume, quote_volume, volume_buy, quote_volume_buy, volume_sell, quote_volume_sell, volume_unknown, quote_volume_unknown);
+        sum!(
+            self, next, notional_volume, notional_quote_volume, notional_volume_buy, notional_quote_volume_buy, notional_volume_sell,
+            notional_quote_volume_sell, notional_volume_unknown, notional_quote_volume_unknown
+        );
+    }
+}
+
+/// Merges the candles of the index values (`IndicesOHLCV` and `DerIndicesOHLCV`).
+macro_rules! merge_index_messages {
+    ($current:ident, $next:ident) => {
+        merge_ohlc!($current, $next);
+        ($current.last_message_timestamp, $current.last_message_value) = ($next.last_message_timestamp, $next.last_message_value);
+        if $current.high_message_value < $next.high_message_value {
+            ($current.high_message_value, $current.high_message_timestamp) = ($next.high_message_value, $next.high_message_timestamp);
+        }
+        if $next.low_message_value < $current.low_message_value {
+            ($current.low_message_value, $current.low_message_timestamp) = ($next.low_message_value, $next.low_message_timestamp);
+        }
+        sum!($current, $next, total_index_updates);
+    };
+}
+
+impl Resample for IndicesOHLCV {
+    fn update_timestamp(&mut self, new_timestamp: i64) -> () {
+        self.timestamp = new_timestamp;
+    }
+
+    fn merge(&mut self, next: &Self) -> () {
+        merge_index_messages!(self, next);
+        sum!(self, next, volume, volume_top_tier, volume_direct, volume_top_tier_direct, quote, quote_top_tier, quote_direct, quote_top_tier_direct);
+    }
+}
+
+impl Resample for DerIndicesOHLCV {
+    fn update_timestamp(&mut self, new_timestamp: i64) -> () {
+        self.timestamp = new_timestamp;
+    }
+
+    fn merge(&mut self, next: &Self) -> () {
+        merge_index_messages!(self, next);
+    }
+}
+
+impl Resample for OCDEXOHLCV {
+    fn update_timestamp(&mut self, new_timestamp: i64) -> () {
+        self.timestamp = new_timestamp;
+    }
+
+    fn merge(&mut self, next: &Self) -> () {
+        merge_ohlc!(self, next);
+        // Swap fields of the candles without any swaps are zeros
+        if self.total_swaps == 0 {
+            (self.first_swap_timestamp, self.first_swap_block, self.first_swap_price) = (next.first_swap_timestamp, next.first_swap_block, next.first_swap_price);
+            (self.high_swap_timestamp, self.high_swap_block, self.high_swap_price) = (next.high_swap_timestamp, next.high_swap_block, next.high_swap_price);
+            (self.low_swap_timestamp, self.low_swap_block, self.low_swap_price) = (next.low_swap_timestamp, next.low_swap_block, next.low_swap_price);
+            (self.last_swap_timestamp, self.last_swap_block, self.last_swap_price) = (next.last_swap_timestamp, next.last_swap_block, next.last_swap_price);
+        } else if 0 < next.total_swaps {
+            (self.last_swap_timestamp, self.last_swap_block, self.last_swap_price) = (next.last_swap_timestamp, next.last_swap_block, next.last_swap_price);
+            if self.high_swap_price < next.high_swap_price {
+                (self.high_swap_price, self.high_swap_timestamp, self.high_swap_block) = (next.high_swap_price, next.high_swap_timestamp, next.high_swap_block);
+            }
+            if next.low_swap_price < self.low_swap_price {
+                (self.low_swap_price, self.low_swap_timestamp, self.low_swap_block) = (next.low_swap_price, next.low_swap_timestamp, next.low_swap_block);
+            }
+        }
+        sum!(self, next, total_swaps, total_swaps_buy, total_swaps_sell, total_swaps_unknown);
+        sum!(self, next, volume, quote_volume, volume_buy, quote_volume_buy, volume_sell, quote_volume_sell);
+        sum_str(&mut self.volume_unknown, &next.volume_unknown);
+        sum_str(&mut self.quote_volume_unknown, &next.quote_volume_unknown);
+    }
+}
+
+impl Resample for OverviewMktCapOHLCV {
+    fn update_timestamp(&mut self, new_timestamp: i64) -> () {
+        self.timestamp = new_timestamp;
+    }
+
+    fn merge(&mut self, next: &Self) -> () {
+        merge_ohlc!(self, next);
+        sum!(self, next, top_tier_volume);
+    }
+}
+
+impl Resample for OHLCV {
+    fn update_timestamp(&mut self, new_timestamp: i64) -> () {
+        self.timestamp = new_timestamp;
+    }
+
+    fn merge(&mut self, next: &Self) -> () {
+        merge_ohlc!(self, next);
+        for (current, next) in [(&mut self.volume, next.volume), (&mut self.quote_volume, next.quote_volume)] {
+            *current = match (*current, next) {
+                (Some(a), Some(b)) => Some(a + b),
+                (a, b) => a.or(b),
+            };
+        }
+    }
+}
+
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// Aggregates candles into buckets of a coarser interval (e.g., 5-minute, 4-hour or weekly candles).
+///
+/// Buckets are aligned to `origin`: a candle with the timestamp `t` is placed into the bucket that starts at
+/// `origin + floor((t - origin) / width) * width`.
+///
+/// # Examples
+///
+/// ```rust
+/// use ccdata_api::{Resampler, Numeric, SpotOHLCV};
+///
+/// let minutes: Vec<SpotOHLCV> = (0..10).map(|i| SpotOHLCV { timestamp: 60 * i, volume: Numeric::from(1), ..Default::default() } ).collect();
+///
+/// // 5-minute candles
+/// let candles: Vec<SpotOHLCV> = Resampler::minutes(5).resample(&minutes);
+/// assert_eq!(candles.len(), 2);
+/// assert_eq!(candles[1].timestamp, 300);
+/// assert_eq!(candles[1].volume, Numeric::from(5));
+/// ```
+pub struct Resampler {
+    width: i64,
+    origin: i64,
+}
+
+impl Resampler {
+    /// Creates a resampler with buckets of the given width, aligned to the Unix epoch.
+    ///
+    /// # Input
+    /// - `width`: Width of the buckets in seconds (Must be positive)
+    pub fn new(width: i64) -> Self {
+        assert!(0 < width, "Width of the buckets must be positive.");
+        Self { width, origin: 0, }
+    }
+
+    /// Creates a resampler with buckets of `n` minutes.
+    pub fn minutes(n: i64) -> Self {
+        Self::new(n * 60)
+    }
+
+    /// Creates a resampler with buckets of `n` hours.
+    pub fn hours(n: i64) -> Self {
+        Self::new(n * 3_600)
+    }
+
+    /// Creates a resampler with buckets of `n` days (starting at 00:00 UTC).
+    pub fn days(n: i64) -> Self {
+        Self::new(n * 86_400)
+    }
+
+    /// Creates a resampler with buckets of `n` weeks (starting on Monday at 00:00 UTC).
+    pub fn weeks(n: i64) -> Self {
+        Self::new(n * 7 * 86_400).with_origin(FIRST_MONDAY)
+    }
+
+    /// Sets the timestamp that the buckets are aligned to.
+    ///
+    /// # Input
+    /// - `origin`: Timestamp (in seconds) of the beginning of any bucket
+    pub fn with_origin(mut self, origin: i64) -> Self {
+        self.origin = origin;
+        self
+    }
+
+    /// Returns the width of the buckets in seconds.
+    pub fn width(&self) -> i64 {
+        self.width
+    }
+
+    /// Returns the timestamp that the buckets are aligned to.
+    pub fn origin(&self) -> i64 {
+        self.origin
+    }
+
+    /// Returns the timestamp of the beginning of the bucket that the timestamp belongs to.
+    ///
+    /// # Input
+    /// - `timestamp`: Timestamp in seconds
+    pub fn bucket(&self, timestamp: i64) -> i64 {
+        self.origin + (timestamp - self.origin).div_euclid(self.width) * self.width
+    }
+
+    /// Aggregates the candles into buckets, returning one candle per non-empty bucket ordered by timestamp.
+    ///
+    /// Note: The candles must belong to the same instrument. They are ordered by timestamp before they are aggregated.
+    /// Fields that describe the request (e.g., `unit`) are taken from the first candle of the bucket.
+    ///
+    /// # Input
+    /// - `candles`: Candles of the same instrument (Any type that implements `Resample`)
+    pub fn resample<C: Resample>(&self, candles: &[C]) -> Vec<C> {
+        let mut candles: Vec<&C> = candles.iter().collect();
+        candles.sort_by_key(|v| v.timestamp() );
+        let mut resampled: Vec<C> = Vec::new();
+        for candle in candles {
+            let bucket: i64 = self.bucket(candle.timestamp());
+            match resampled.last_mut() {
+                Some(current) if current.timestamp() == bucket => current.merge(candle),
+                _ => {
+                    let mut current: C = candle.clone();
+                    current.update_timestamp(bucket);
+                    resampled.push(current);
+                },
+            }
+        }
+        resampled
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+
+    #[test]
+    fn unit_test_resample_spot() -> () {
+        use crate::resample::Resampler;
+        use crate::schemas::Numeric;
+        use crate::schemas::data_api::spot::SpotOHLCV;
+        let n = |v: i64| -> Numeric { Numeric::from(v as i32) };
+        let candle = |timestamp: i64, ohlc: [i64; 4], trade: Option<(i64, i64)>| -> SpotOHLCV {
+            SpotOHLCV {
+                timestamp, open: n(ohlc[0]), high: n(ohlc[1]), low: n(ohlc[2]), close: n(ohlc[3]), total_trades: 2, total_trades_buy: 1, volume: n(10),
+                volume_buy: n(4), volume_sell: n(6), quote_volume: n(100),
+                first_trade_timestamp: trade.map(|v| v.0 ), first_trade_price: trade.map(|v| n(v.1) ),
+                last_trade_timestamp: trade.map(|v| v.0 + 30 ), last_trade_price: trade.map(|v| n(v.1) ),
+                high_trade_timestamp: trade.map(|v| v.0 ), high_trade_price: trade.map(|_| n(ohlc[1]) ),
+                low_trade_timestamp: trade.map(|v| v.0 ), low_trade_price: trade.map(|_| n(ohlc[2]) ),
+                ..Default::default()
+            }
+        };
+        // Candles are out of order and the first candle has no trades
+        let minutes: Vec<SpotOHLCV> = vec![
+            candle(120, [12, 15, 11, 14], Some((125, 12))),
+            candle(0, [10, 10, 10, 10], None),
+            candle(60, [10, 13, 8, 12], Some((61, 10))),
+            candle(180, [14, 14, 13, 13], Some((190, 14))),
+        ];
+        let candles: Vec<SpotOHLCV> = Resampler::minutes(3).resample(&minutes);
+        assert_eq!(candles.len(), 2);
+        let candle: &SpotOHLCV = &candles[0];
+        assert_eq!(candle.timestamp, 0);
+        assert_eq!((candle.open, candle.high, candle.low, candle.close), (n(10), n(15), n(8), n(14)));
+        assert_eq!((candle.first_trade_timestamp, candle.first_trade_price), (Some(61), Some(n(10))));
+        assert_eq!((candle.last_trade_timestamp, candle.last_trade_price), (Some(155), Some(n(12))));
+        assert_eq!((candle.high_trade_timestamp, candle.high_trade_price), (Some(125), Some(n(15))));
+        assert_eq!((candle.low_trade_timestamp, candle.low_trade_price), (Some(61), Some(n(8))));
+        assert_eq!((candle.total_trades, candle.total_trades_buy), (6, 3));
+        assert_eq!((candle.volume, candle.volume_buy, candle.volume_sell, candle.quote_volume), (n(30), n(12), n(18), n(300)));
+        assert_eq!(candles[1].timestamp, 180);
+        assert_eq!(candles[1].close, n(13));
+    }
+
+    #[test]
+    fn unit_test_resample_futures_and_dex() -> () {
+        use crate::resample::Resampler;
+        use crate::schemas::Numeric;
+        use crate::schemas::data_api::futures::FuturesOHLCV;
+        use crate::schemas::data_api::on_chain_dex::OCDEXOHLCV;
+        let n = |v: i64| -> Numeric { Numeric::from(v as i32) };
+        // Candles without any trades have zeros in their trade fields
+        let futures = |timestamp: i64, price: i64, trades: i64| -> FuturesOHLCV {
+            let (t, p) = if 0 < trades { (timestamp + 10, n(price)) } else { (0, n(0)) };
+            FuturesOHLCV {
+                timestamp, open: n(price), high: n(price), low: n(price), close: n(price), total_trades: trades,
+                first_trade_timestamp: t, first_trade_price: p, last_trade_timestamp: t, last_trade_price: p,
+                high_trade_timestamp: t, high_trade_price: p, low_trade_timestamp: t, low_trade_price: p, ..Default::default()
+            }
+        };
+        let minutes: Vec<FuturesOHLCV> = vec![futures(0, 10, 0), futures(60, 12, 2), futures(120, 9, 1), futures(180, 9, 0)];
+        let candle: &FuturesOHLCV = &Resampler::minutes(4).resample(&minutes)[0];
+        assert_eq!((candle.first_trade_timestamp, candle.first_trade_price), (70, n(12)));
+        assert_eq!((candle.last_trade_timestamp, candle.last_trade_price), (130, n(9)));
+        assert_eq!((candle.high_trade_timestamp, candle.high_trade_price), (70, n(12)));
+        assert_eq!((candle.low_trade_timestamp, candle.low_trade_price), (130, n(9)));
+        assert_eq!(candle.total_trades, 3);
+        // Bucket without any trades
+        let candle: &FuturesOHLCV = &Resampler::minutes(4).resample(&[futures(0, 10, 0), futures(60, 10, 0)])[0];
+        assert_eq!((candle.first_trade_timestamp, candle.last_trade_timestamp, candle.total_trades), (0, 0, 0));
+        let dex = |timestamp: i64, price: i64, swaps: i64| -> OCDEXOHLCV {
+            let (t, b, p) = if 0 < swaps { (timestamp + 10, timestamp, n(price)) } else { (0, 0, n(0)) };
+            OCDEXOHLCV {
+                timestamp, open: n(price), high: n(price), low: n(price), close: n(price), total_swaps: swaps,
+                first_swap_timestamp: t, first_swap_block: b, first_swap_price: p, last_swap_timestamp: t, last_swap_block: b, last_swap_price: p,
+                high_swap_timestamp: t, high_swap_block: b, high_swap_price: p, low_swap_timestamp: t, low_swap_block: b, low_swap_price: p,
+                ..Default::default()
+            }
+        };
+        let minutes: Vec<OCDEXOHLCV> = vec![dex(0, 10, 0), dex(60, 12, 2), dex(120, 9, 1), dex(180, 9, 0)];
+        let candle: &OCDEXOHLCV = &Resampler::minutes(4).resample(&minutes)[0];
+        assert_eq!((candle.first_swap_timestamp, candle.first_swap_block, candle.first_swap_price), (70, 60, n(12)));
+        assert_eq!((candle.last_swap_timestamp, candle.last_swap_block, candle.last_swap_price), (130, 120, n(9)));
+        assert_eq!((candle.high_swap_timestamp, candle.high_swap_price), (70, n(12)));
+        assert_eq!((candle.low_swap_timestamp, candle.low_swap_block, candle.low_swap_price), (130, 120, n(9)));
+        assert_eq!(candle.total_swaps, 3);
+    }
+
+    #[test]
+    fn unit_test_resampler_buckets() -> () {
+        use crate::candle::OHLCV;
+        use crate::resample::Resampler;
+        use crate::schemas::Numeric;
+        // 1970-01-05 00:00 UTC is Monday
+        let weekly: Resampler = Resampler::weeks(1);
+        assert_eq!(weekly.bucket(4 * 86_400), 4 * 86_400);
+        assert_eq!(weekly.bucket(11 * 86_400 - 1), 4 * 86_400);
+        assert_eq!(weekly.bucket(86_400), -3 * 86_400);
+        assert_eq!(Resampler::hours(4).bucket(1728860400), 1728849600);
+        // Volumes of the normalized candles
+        let hours: Vec<OHLCV> = vec![
+            OHLCV { timestamp: 0, volume: Some(Numeric::from(1)), ..Default::default() },
+            OHLCV { timestamp: 3_600, volume: None, quote_volume: Some(Numeric::from(2)), ..Default::default() },
+            OHLCV { timestamp: 7_200, volume: Some(Numeric::from(3)), ..Default::default() },
+        ];
+        let candles: Vec<OHLCV> = Resampler::days(1).resample(&hours);
+        assert_eq!(candles.len(), 1);
+        assert_eq!((candles[0].volume, candles[0].quote_volume), (Some(Numeric::from(4)), Some(Numeric::from(2))));
+        assert!(Resampler::minutes(5).resample::<OHLCV>(&[]).is_empty());
+    }
+}
@@ -0,0 +1,243 @@
+use std::{fmt::Debug, sync::Arc, time::{Duration, SystemTime, UNIX_EPOCH}};
+use std::hash::{BuildHasher, Hasher, RandomState};
+use reqwest::{StatusCode, header::{HeaderMap, RETRY_AFTER}};
+
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+/// Reason for retrying a request.
+pub enum RetryReason {
+    /// Response had a retryable HTTP status code (e.g., `429`, `502`)
+    Status(u16),
+    /// Request timed out
+    Timeout,
+    /// Connection could not be established or was reset
+    Connection,
+}
+
+
+#[derive(Clone, Debug)]
+/// Information about a retry that is about to be performed, passed to the `on_retry` callback.
+pub struct RetryEvent {
+    /// Number of the attempt that failed (starting from `1`)
+    pub attempt: u32,
+    /// Time the backend will wait before the next attempt
+    pub delay: Duration,
+    /// Reason for retrying the request
+    pub reason: RetryReason,
+}
+
+
+/// Callback that is invoked before every retry.
+pub type OnRetry = Arc<dyn Fn(&RetryEvent) + Send + Sync>;
+
+
+#[derive(Clone)]
+/// Retry policy for transient failures (rate limiting, server errors, timeouts and connection errors).
+///
+/// The delay between the attempts grows exponentially (`base_delay * 2^(attempt - 1)`, capped at `max_delay`) and is randomly reduced
+/// by up to `jitter` fraction of the delay. If the response contains the `Retry-After` header, its value is used as the delay instead.
+///
+/// Note: All CoinDesk API endpoints are `GET` requests, so retrying a request never has side effects.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::{sync::Arc, time::Duration};
+/// use ccdata_api::{CoinDesk, RetryPolicy, RetryEvent};
+///
+/// let mut backend: CoinDesk = CoinDesk::new();
+///
+/// let policy: RetryPolicy = RetryPolicy { max_attempts: 5, base_delay: Duration::from_millis(250), ..Default::default() }
+///     .with_on_retry(Arc::new(|event: &RetryEvent| println!("Retrying after {:?}: {:?}", event.delay, event.reason) ));
+/// backend.update_retry_policy(Some(policy));
+/// ```
+pub struct RetryPolicy {
+    /// Maximum number of attempts (including the first attempt)
+    pub max_attempts: u32,
+    /// Delay before the first retry
+    pub base_delay: Duration,
+    /// Maximum delay between attempts
+    pub max_delay: Duration,
+    /// Fraction of the delay (between `0.0` and `1.0`) that may be randomly subtracted from it
+    pub jitter: f64,
+    /// Retry responses with `429 Too Many Requests` status code
+    pub retry_rate_limited: bool,
+    /// Retry responses with `5xx` status codes
+    pub retry_server_errors: bool,
+    /// Retry requests that timed out
+    pub retry_timeouts: bool,
+    /// Retry requests that failed to connect or whose connection was reset
+    pub retry_connection_errors: bool,
+    /// Use the `Retry-After` header of the response as the delay.
+    ///
+    /// Note: If the `Retry-After` delay exceeds `max_delay`, the request is not retried.
+    pub respect_retry_after: bool,
+    /// Callback that is invoked before every retry
+    pub on_retry: Option<OnRetry>,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(30),
+            jitter: 0.5,
+            retry_rate_limited: true,
+            retry_server_errors: true,
+            retry_timeouts: true,
+            retry_connection_errors: true,
+            respect_retry_after: true,
+            on_retry: None,
+        }
+    }
+}
+
+impl Debug for RetryPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RetryPolicy")
+            .field("max_attempts", &self.max_attempts)
+            .field("base_delay", &self.base_delay)
+            .field("max_delay", &self.max_delay)
+            .field("jitter", &self.jitter)
+            .field("retry_rate_limited", &self.retry_rate_limited)
+            .field("retry_server_errors", &self.retry_server_errors)
+            .field("retry_timeouts", &self.retry_timeouts)
+            .field("retry_connection_errors", &self.retry_connection_errors)
+            .field("respect_retry_after", &self.respect_retry_after)
+            .field("on_retry", &self.on_retry.as_ref().map(|_| "Fn(&RetryEvent)" ))
+            .finish()
+    }
+}
+
+impl RetryPolicy {
+    /// Sets the callback that is invoked before every retry.
+    ///
+    /// # Input
+    /// - `on_retry`: Callback that receives the information about the failed attempt and the upcoming delay
+    pub fn with_on_retry(mut self, on_retry: OnRetry) -> Self {
+        self.on_retry = Some(on_retry);
+        self
+    }
+
+    /// Returns the retry reason if the response with the given status code should be retried.
+    pub fn status_reason(&self, status: StatusCode) -> Option<RetryReason> {
+        let retry: bool = (self.retry_rate_limited && status == StatusCode::TOO_MANY_REQUESTS) || (self.retry_server_errors && status.is_server_error());
+        retry.then_some(RetryReason::Status(status.as_u16()))
+    }
+
+    /// Returns the retry reason if the request that failed with the given error should be retried.
+    pub fn error_reason(&self, error: &reqwest::Error) -> Option<RetryReason> {
+        if error.is_timeout() {
+            self.retry_timeouts.then_some(RetryReason::Timeout)
+        } else if error.is_connect() || error.is_body() {
+            self.retry_connection_errors.then_some(RetryReason::Connection)
+        } else {
+            None
+        }
+    }
+
+    /// Computes the delay before the next attempt.
+    ///
+    /// # Input
+    /// - `attempt`: Number of the attempt that failed (starting from `1`)
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let exponent: u32 = attempt.saturating_sub(1).min(31);
+        let delay: Duration = self.base_delay.saturating_mul(1 << exponent).min(self.max_delay);
+        let jitter: f64 = self.jitter.clamp(0.0, 1.0) * random_fraction();
+        delay.mul_f64(1.0 - jitter)
+    }
+
+    /// Computes the delay before the next attempt, taking the `Retry-After` header into account.
+    ///
+    /// Note: Returns `None` if the `Retry-After` delay exceeds `max_delay`.
+    pub(crate) fn delay(&self, attempt: u32, headers: Option<&HeaderMap>) -> Option<Duration> {
+        match headers.filter(|_| self.respect_retry_after ).and_then(retry_after) {
+            Some(v) if v > self.max_delay => None,
+            Some(v) => Some(v),
+            None => Some(self.backoff(attempt)),
+        }
+    }
+
+    /// Invokes the `on_retry` callback.
+    pub(crate) fn notify(&self, event: &RetryEvent) -> () {
+        if let Some(on_retry) = &self.on_retry { on_retry(event) }
+    }
+}
+
+
+/// Returns a random number in the range `[0, 1)`.
+fn random_fraction() -> f64 {
+    let mut hasher = RandomState::new().build_hasher();
+    hasher.write_u128(SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_nanos());
+    (hasher.finish() >> 11) as f64 / (1u64 << 53) as f64
+}
+
+
+/// Parses the `Retry-After` header, which contains either the number of seconds to wait or an HTTP date.
+pub fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    let value: &str = headers.get(RETRY_AFTER)?.to_str().ok()?.trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+    let date: SystemTime = parse_http_date(value)?;
+    Some(date.duration_since(SystemTime::now()).unwrap_or_default())
+}
+
+
+/// Parses the HTTP date in the IMF-fixdate format (e.g., `Sun, 06 Nov 1994 08:49:37 GMT`).
+fn parse_http_date(value: &str) -> Option<SystemTime> {
+    const MONTHS: [&str; 12] = ["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec"];
+    let parts: Vec<&str> = value.split_whitespace().collect();
+    if parts.len() != 6 || parts[5] != "GMT" {
+        return None;
+    }
+    let day: i64 = parts[1].parse().ok()?;
+    let month: i64 = MONTHS.iter().position(|v| *v == parts[2] )? as i64 + 1;
+    let year: i64 = parts[3].parse().ok()?;
+    let time: Vec<i64> = parts[4].split(':').map(|v| v.parse().ok() ).collect::<Option<Vec<i64>>>()?;
+    if time.len() != 3 {
+        return None;
+    }
+    // Days since Unix epoch (civil calendar to days conversion)
+    let y: i64 = if month <= 2 { year - 1 } else { year };
+    let era: i64 = y.div_euclid(400);
+    let yoe: i64 = y - era * 400;
+    let doy: i64 = (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + day - 1;
+    let doe: i64 = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    let days: i64 = era * 146_097 + doe - 719_468;
+    let seconds: i64 = days * 86_400 + time[0] * 3_600 + time[1] * 60 + time[2];
+    Some(UNIX_EPOCH + Duration::from_secs(u64::try_from(seconds).ok()?))
+}
+
+
+#[cfg(test)]
+mod tests {
+
+    #[test]
+    fn unit_test_backoff() -> () {
+        use std::time::Duration;
+        use crate::retry::RetryPolicy;
+        let policy: RetryPolicy = RetryPolicy { base_delay: Duration::from_millis(100), max_delay: Duration::from_secs(1), jitter: 0.0, ..Default::default() };
+        assert_eq!(policy.backoff(1), Duration::from_millis(100));
+        assert_eq!(policy.backoff(3), Duration::from_millis(400));
+        assert_eq!(policy.backoff(10), Duration::from_secs(1));
+        let policy: RetryPolicy = RetryPolicy { jitter: 0.5, ..policy };
+        let delay: Duration = policy.backoff(2);
+        assert!(Duration::from_millis(100) <= delay && delay <= Duration::from_millis(200));
+    }
+
+    #[test]
+    fn unit_test_retry_after() -> () {
+        use std::time::{Duration, UNIX_EPOCH};
+        use reqwest::header::{HeaderMap, HeaderValue, RETRY_AFTER};
+        use crate::retry::{retry_after, parse_http_date};
+        let mut headers: HeaderMap = HeaderMap::new();
+        headers.insert(RETRY_AFTER, HeaderValue::from_static("7"));
+        assert_eq!(retry_after(&headers), Some(Duration::from_secs(7)));
+        headers.insert(RETRY_AFTER, HeaderValue::from_static("Sun, 06 Nov 1994 08:49:37 GMT"));
+        assert_eq!(retry_after(&headers), Some(Duration::ZERO));
+        assert_eq!(parse_http_date("Sun, 06 Nov 1994 08:49:37 GMT"), Some(UNIX_EPOCH + Duration::from_secs(784111777)));
+        assert_eq!(parse_http_date("yesterday"), None);
+    }
+}
@@ -2,7 +2,24 @@ pub mod min_api;
 pub mod data_api;
 
 
-use serde::{Serialize, Deserialize, Deserializer};
+use std::fmt::Display;
+use serde::{Serialize, Deserialize, Deserializer, de::DeserializeOwned};
+use serde_json::Value;
+use crate::error::Error;
+
+
+#[cfg(not(feature = "decimal"))]
+/// Numeric type of the price, volume, size and supply fields of the schemas.
+///
+/// Note: `f64` by default, or `rust_decimal::Decimal` if the `decimal` feature is enabled.
+pub type Numeric = f64;
+
+#[cfg(feature = "decimal")]
+/// Numeric type of the price, volume, size and supply fields of the schemas.
+///
+/// Note: `rust_decimal::Decimal` since the `decimal` feature is enabled, which deserializes the fields directly from the JSON number text
+/// without losing precision.
+pub type Numeric = rust_decimal::Decimal;
 
 
 #[derive(Clone, Debug, PartialEq, Serialize)]
@@ -35,6 +52,17 @@ impl<'de> Deserialize<'de> for StringOrInt {
             fn visit_u64<E: serde::de::Error>(self, v: u64) -> Result<Self::Value, E> {
                 Ok(StringOrInt::UInt64(v))
             }
+
+            #[cfg(feature = "decimal")]
+            // Numbers are passed as maps when the `arbitrary_precision` feature of `serde_json` is enabled
+            fn visit_map<A: serde::de::MapAccess<'de>>(self, map: A) -> Result<Self::Value, A::Error> {
+                let v: serde_json::Number = Deserialize::deserialize(serde::de::value::MapAccessDeserializer::new(map))?;
+                match (v.as_u64(), v.as_i64()) {
+                    (Some(v), _) => Ok(StringOrInt::UInt64(v)),
+                    (None, Some(v)) => Ok(StringOrInt::Int64(v)),
+                    _ => Err(serde::de::Error::invalid_type(serde::de::Unexpected::Other("number"), &self)),
+                }
+            }
         }
         
         deserializer.deserialize_any(Visitor)
@@ -42,6 +70,37 @@ impl<'de> Deserialize<'de> for StringOrInt {
 }
 
 
+/// Deserializes an optional field that CoinDesk API may return as an empty object (`{}`) instead of `null`.
+///
+/// Note: Both `null` and `{}` are deserialized into `None`. Use it together with `#[serde(default)]` so that missing fields are also deserialized into `None`.
+///
+/// # Examples
+///
+/// ```rust
+/// use serde::Deserialize;
+/// use ccdata_api::schemas::empty_object_as_none;
+///
+/// #[derive(Deserialize)]
+/// struct Response {
+///     #[serde(default, deserialize_with = "empty_object_as_none")]
+///     data: Option<Vec<i32>>,
+/// }
+///
+/// let response: Response = serde_json::from_str("{\"data\":{}}").unwrap();
+/// assert_eq!(response.data, None);
+///
+/// let response: Response = serde_json::from_str("{\"data\":[1, 2]}").unwrap();
+/// assert_eq!(response.data, Some(vec![1, 2]));
+/// ```
+pub fn empty_object_as_none<'de, D: Deserializer<'de>, T: DeserializeOwned>(deserializer: D) -> Result<Option<T>, D::Error> {
+    match Value::deserialize(deserializer)? {
+        Value::Null => Ok(None),
+        Value::Object(v) if v.is_empty() => Ok(None),
+        v => T::deserialize(v).map(Some).map_err(serde::de::Error::custom),
+    }
+}
+
+
 // Min-API Wrappers
 
 
@@ -66,11 +125,14 @@ pub struct CCMaxCalls {
 
 #[derive(Clone, Copy, Debug, Default, Serialize, Deserialize)]
 pub struct CCRateLimit {
+    #[serde(default, deserialize_with = "empty_object_as_none")]
     pub calls_made: Option<CCCallsMade>,
+    #[serde(default, deserialize_with = "empty_object_as_none")]
     pub max_calls: Option<CCMaxCalls>,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(bound(deserialize = "T: DeserializeOwned"))]
 pub struct CCMinWrapper<T> {
     #[serde(rename = "Aggregated")]
     pub aggregated: Option<bool>,
@@ -78,11 +140,12 @@ pub struct CCMinWrapper<T> {
     pub time_from: Option<i64>,
     #[serde(rename = "TimeTo")]
     pub time_to: Option<i64>,
-    #[serde(rename = "Data")]
+    #[serde(rename = "Data", default, deserialize_with = "empty_object_as_none")]
     pub data: Option<T>,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(bound(deserialize = "T: DeserializeOwned"))]
 pub struct CCMinResponse<T> {
     #[serde(rename = "Response")]
     pub response: String,
@@ -92,12 +155,31 @@ pub struct CCMinResponse<T> {
     pub has_warning: bool,
     #[serde(rename = "Type")]
     pub type_: i32,
-    #[serde(rename = "Data")]
+    #[serde(rename = "Data", default, deserialize_with = "empty_object_as_none")]
     pub data: Option<T>,
-    #[serde(rename = "RateLimit")]
+    #[serde(rename = "RateLimit", default, deserialize_with = "empty_object_as_none")]
     pub rate_limit: Option<CCRateLimit>,
 }
 
+impl<T> CCMinResponse<T> {
+    /// Converts the response into the data, or into an error if the response reports an error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ccdata_api::{CCMinResponse, error::Error};
+    ///
+    /// let response: CCMinResponse<i32> = serde_json::from_str("{\"Response\":\"Error\",\"Message\":\"Invalid symbol\",\"HasWarning\":false,\"Type\":2,\"Data\":null}").unwrap();
+    /// assert!(matches!(response.into_result(), Err(Error::Api(e)) if e.message == "Invalid symbol"));
+    /// ```
+    pub fn into_result(self) -> Result<Option<T>, Error> {
+        if self.response == "Error" {
+            return Err(Error::Api(Box::new(CCError { type_: self.type_, message: self.message, other_info: None, })));
+        }
+        Ok(self.data)
+    }
+}
+
 
 // Data-API Wrappers
 
@@ -107,6 +189,7 @@ pub struct CCErrorOtherInfo {
     /// The parameter that is responsible for the error.
     pub param: Option<String>,
     /// The values responsible for the error.
+    #[serde(default, deserialize_with = "empty_object_as_none")]
     pub values: Option<Vec<StringOrInt>>,
     // Instrument specific information
     /// Status of the instrument.
@@ -128,20 +211,51 @@ pub struct CCError {
     pub type_: i32,
     /// A message describing the error.
     pub message: String,
+    #[serde(default, deserialize_with = "empty_object_as_none")]
     pub other_info: Option<CCErrorOtherInfo>,
 }
 
+impl Display for CCError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} (Type {})", self.message, self.type_)
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(bound(deserialize = "T: DeserializeOwned"))]
 pub struct CoinDeskResponse<T> {
-    #[serde(rename = "Data")]
+    #[serde(rename = "Data", default, deserialize_with = "empty_object_as_none")]
     pub data: Option<T>,
-    #[serde(rename = "Err")]
+    #[serde(rename = "Err", default, deserialize_with = "empty_object_as_none")]
     /// This object provides detailed information about an error encountered while processing the request. It includes an error code,
     /// a message explaining the error, and additional context about the parameters or values that caused the issue.
     /// This helps clients identify and resolve issues with their requests.
     pub error: Option<CCError>,
 }
 
+impl<T> CoinDeskResponse<T> {
+    /// Converts the response into the data, or into an error if the `Err` object of the response is populated.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ccdata_api::{CoinDeskResponse, CCError, error::Error};
+    ///
+    /// let response: CoinDeskResponse<Vec<i32>> = CoinDeskResponse { data: Some(vec![1, 2]), error: None };
+    /// assert_eq!(response.into_result().unwrap(), Some(vec![1, 2]));
+    ///
+    /// let error: CCError = CCError { type_: 2, message: String::from("Not found"), other_info: None };
+    /// let response: CoinDeskResponse<Vec<i32>> = CoinDeskResponse { data: None, error: Some(error) };
+    /// assert!(matches!(response.into_result(), Err(Error::Api(e)) if e.type_ == 2));
+    /// ```
+    pub fn into_result(self) -> Result<Option<T>, Error> {
+        match self.error {
+            Some(e) => Err(Error::Api(Box::new(e))),
+            None => Ok(self.data),
+        }
+    }
+}
+
 
 #[cfg(test)]
 mod tests {
@@ -151,7 +265,47 @@ mod tests {
         use serde_json;
         use crate::schemas;
         let d: String = String::from("{\"Data\":{}, \"Err\":{\"type\": 23, \"message\": \"hello\", \"other_info\":null}}");
-        let response: schemas::CoinDeskResponse<String> = serde_json::from_str(&d.to_string().replace("{}", "null")).unwrap();
+        let response: schemas::CoinDeskResponse<String> = serde_json::from_str(&d).unwrap();
         assert_eq!(response.data, None);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn unit_test_absent_group_fields() -> () {
+        use serde_json;
+        use crate::schemas::CoinDeskResponse;
+        use crate::schemas::data_api::spot::SpotOHLCV;
+        use crate::schemas::data_api::asset::AssetMetadata;
+        // Only the fields of the `ID` and `OHLC` groups
+        let d: &str = "{\"Data\":[{\"UNIT\":\"DAY\",\"TIMESTAMP\":1728691200,\"TYPE\":\"267\",\"MARKET\":\"kraken\",\"INSTRUMENT\":\"XBTUSD\",\
+                        \"OPEN\":62500.1,\"HIGH\":63400.0,\"LOW\":62400.2,\"CLOSE\":63200.5}],\"Err\":{}}";
+        let response: CoinDeskResponse<Vec<SpotOHLCV>> = serde_json::from_str(d).unwrap();
+        let ohlcv: Vec<SpotOHLCV> = response.data.unwrap();
+        assert_eq!(ohlcv[0].close.to_string(), "63200.5");
+        assert_eq!(ohlcv[0].mapped_instrument, String::new());
+        // Fields of the `ID` group are required
+        let d: &str = "{\"Data\":[{\"OPEN\":62500.1,\"HIGH\":63400.0,\"LOW\":62400.2,\"CLOSE\":63200.5}],\"Err\":{}}";
+        assert!(serde_json::from_str::<CoinDeskResponse<Vec<SpotOHLCV>>>(d).is_err());
+        // Only the fields of the `ID` group
+        let d: &str = "{\"Data\":{\"BTC\":{\"ID\":1,\"TYPE\":\"162\",\"SYMBOL\":\"BTC\"}},\"Err\":{}}";
+        let response: CoinDeskResponse<std::collections::HashMap<String, AssetMetadata>> = serde_json::from_str(d).unwrap();
+        assert_eq!(response.data.unwrap().get("BTC").unwrap().id, 1);
+    }
+
+    #[cfg(feature = "decimal")]
+    #[test]
+    fn unit_test_decimal_fields() -> () {
+        use serde_json;
+        use rust_decimal::Decimal;
+        use crate::schemas::{CoinDeskResponse, StringOrInt};
+        use crate::schemas::data_api::spot::SpotOHLCV;
+        let d: &str = "{\"Data\":[{\"UNIT\":\"DAY\",\"TIMESTAMP\":1728691200,\"TYPE\":\"267\",\"MARKET\":\"kraken\",\"INSTRUMENT\":\"XBTUSD\",\
+                        \"CLOSE\":63200.123456789012345678,\"VOLUME\":0.1}],\
+                        \"Err\":{\"type\":2,\"message\":\"hello\",\"other_info\":{\"values\":[1,-2,\"BTC\"]}}}";
+        let response: CoinDeskResponse<Vec<SpotOHLCV>> = serde_json::from_str(d).unwrap();
+        let ohlcv: Vec<SpotOHLCV> = response.data.unwrap();
+        assert_eq!(ohlcv[0].close.to_string(), "63200.123456789012345678");
+        assert_eq!(ohlcv[0].volume + ohlcv[0].volume + ohlcv[0].volume, Decimal::new(3, 1));
+        let values: Vec<StringOrInt> = response.error.unwrap().other_info.unwrap().values.unwrap();
+        assert_eq!(values, vec![StringOrInt::UInt64(1), StringOrInt::Int64(-2), StringOrInt::String(String::from("BTC"))]);
+    }
+}
@@ -1,5 +1,6 @@
 use serde::{Serialize, Deserialize};
 use crate::schemas::data_api::{PreviousAssetSymbol, AssetAlternativeId, AssetIndustry, SpecialAddress};
+use crate::schemas::Numeric;
 
 
 // Asset: Full Asset Metadata
@@ -38,17 +39,17 @@ pub struct AssetMetadata {
     #[serde(rename = "TYPE")]
     /// Type of the message.
     pub type_: String,
-    #[serde(rename = "ID_LEGACY")]
+    #[serde(rename = "ID_LEGACY", default)]
     /// The legacy previous asset management system ID.
     pub id_legacy: Option<i32>,
-    #[serde(rename = "ID_PARENT_ASSET")]
+    #[serde(rename = "ID_PARENT_ASSET", default)]
     /// This refers to the base, parent, or main asset to which a token is linked or pegged, signifying that the token acts as a representation of the parent asset.
     /// When a token loses its connection to a parent asset due to events such as hacks or the issuing entity's decision to not honor the peg—similar to how
     /// TerraUSD detached from its USD peg—the PARENT_ASSET_SYMBOL is removed because the token no longer serves as a true representation of the parent asset.
     /// In order to remove the parent we need clear communication from the company who is in charge of keeping the peg.
     /// We add add a plublic notice and the include the communication in the Other Document URLs.
     pub id_parent_asset: Option<i32>,
-    #[serde(rename = "ID_ASSET_ISSUER")]
+    #[serde(rename = "ID_ASSET_ISSUER", default)]
     /// This field identifies the original creator of the token.
     /// It provides essential information about the entity, individual or contract rules responsible for issuing the token initially and/or maintaining the supply.
     /// In the case of of bridged assets, this is the bridge operator and the parent will have its own issuer.
@@ -58,134 +59,134 @@ pub struct AssetMetadata {
     #[serde(rename = "SYMBOL")]
     /// Internal mapped symbol for a specific asset.
     pub symbol: String,
-    #[serde(rename = "URI")]
+    #[serde(rename = "URI", default)]
     /// The uri path that this asset will be found on / url-slug.
     pub uri: String,
-    #[serde(rename = "ASSET_TYPE")]
+    #[serde(rename = "ASSET_TYPE", default)]
     /// The asset class/type.
     pub asset_type: String,
-    #[serde(rename = "ASSET_ISSUER_NAME")]
+    #[serde(rename = "ASSET_ISSUER_NAME", default)]
     /// This field identifies the original creator of the asset. It provides essential information about the entity
     ///  individual or contract rules responsible for issuing the asset initially and/or maintaining the supply.
     /// In the case of of bridged assets, this is the bridge operator and the parent will have its own issuer.
     /// You can go up the parent chain and figure out what counterparty risk you are exposed to when trading a specific asset.
     /// This clarification ensures that users can directly trace the origin of the asset, understanding its issuance history and the primary issuer's credentials.
     pub asset_issuer_name: Option<String>,
-    #[serde(rename = "PARENT_ASSET_SYMBOL")]
+    #[serde(rename = "PARENT_ASSET_SYMBOL", default)]
     /// This refers to the base, parent, or main asset to which a token is linked or pegged, signifying that the token acts as a representation of the parent asset.
     /// When a token loses its connection to a parent asset due to events such as hacks or the issuing entity's decision to not honor the peg—similar to how
     /// TerraUSD detached from its USD peg—the PARENT_ASSET_SYMBOL is removed because the token no longer serves as a true representation of the parent asset.
     /// In order to remove the parent we need clear communication from the company who is in charge of keeping the peg.
     /// We add add a plublic notice and the include the communication in the Other Document URLs.
     pub parent_asset_symbol: Option<String>,
-    #[serde(rename = "CREATED_ON")]
+    #[serde(rename = "CREATED_ON", default)]
     /// Asset internal creation unix ts in our system.
     pub created_on: i64,
-    #[serde(rename = "UPDATED_ON")]
+    #[serde(rename = "UPDATED_ON", default)]
     /// Asset internal last updated unix ts in our system.
     pub updated_on: i64,
-    #[serde(rename = "PUBLIC_NOTICE")]
+    #[serde(rename = "PUBLIC_NOTICE", default)]
     /// A public notice for this asset.
     pub public_notice: Option<String>,
-    #[serde(rename = "NAME")]
+    #[serde(rename = "NAME", default)]
     /// The full name of the asset, e.g. Bitcoin.
     pub name: String,
-    #[serde(rename = "LOGO_URL")]
+    #[serde(rename = "LOGO_URL", default)]
     /// The image that appears when you see this asset.
     pub logo_url: String,
-    #[serde(rename = "LAUNCH_DATE")]
+    #[serde(rename = "LAUNCH_DATE", default)]
     /// The launch date of the asset is indicated as (yyyy-mm-dd).
     /// However, if the asset was initially established as a token before being integrated into a blockchain,
     /// the launch date is reset to the creation of the first block when the blockchain is launched for the token.
     pub launch_date: i64,
-    #[serde(rename = "PREVIOUS_ASSET_SYMBOLS")]
+    #[serde(rename = "PREVIOUS_ASSET_SYMBOLS", default, deserialize_with = "crate::schemas::empty_object_as_none")]
     /// A list of symbols that were previously associated with this asset.
     pub previous_asset_symbols: Option<Vec<PreviousAssetSymbol>>,
-    #[serde(rename = "ASSET_ALTERNATIVE_IDS")]
+    #[serde(rename = "ASSET_ALTERNATIVE_IDS", default, deserialize_with = "crate::schemas::empty_object_as_none")]
     /// Alternative data platforms that also support this asset with their specific asset id.
     pub asset_alternative_ids: Option<Vec<AssetAlternativeId>>,
-    #[serde(rename = "ASSET_DESCRIPTION_SNIPPET")]
+    #[serde(rename = "ASSET_DESCRIPTION_SNIPPET", default)]
     /// The shortest form description text only for this asset. This is a lot more limited than the summary. Generally this is a one or maximum two sentences.
     pub asset_description_snippet: Option<String>,
-    #[serde(rename = "ASSET_DECIMAL_POINTS")]
+    #[serde(rename = "ASSET_DECIMAL_POINTS", default)]
     /// The total decimal places this asset can be divided into. E.g. 8 for BTC (1 Satoshi), 18 for ETH (1 Wei).
     /// Generally blockchains store all units as integers and this is the number you need to divide the lowest unit of accounting by to get the common unit
     /// of measure used for the asset.
     pub asset_decimal_points: Option<i32>,
-    #[serde(rename = "SUPPLY_MAX")]
+    #[serde(rename = "SUPPLY_MAX", default)]
     /// The maximum number of asset parts (coins/tokens) that will ever be issued (supply_circulating + supply_burnt + supply_locked + all supply that
     /// has not been issued yet but is planned to be issued in the future). For assets (coins/tokens) that have infinite supply, we use -1.
-    pub supply_max: f64,
-    #[serde(rename = "SUPPLY_ISSUED")]
+    pub supply_max: Numeric,
+    #[serde(rename = "SUPPLY_ISSUED", default)]
     /// The number of asset parts (coins/tokens) that have been issued so far. (supply_circulating + supply_locked + supply_burnt).
-    pub supply_issued: Option<f64>,
-    #[serde(rename = "SUPPLY_TOTAL")]
+    pub supply_issued: Option<Numeric>,
+    #[serde(rename = "SUPPLY_TOTAL", default)]
     /// The number of asset parts (coins/tokens) that have been issued so far excluding burnt tokens. (supply_circulating + supply_locked).
-    pub supply_total: Option<f64>,
-    #[serde(rename = "SUPPLY_CIRCULATING")]
+    pub supply_total: Option<Numeric>,
+    #[serde(rename = "SUPPLY_CIRCULATING", default)]
     /// Also referred to as free float or public float. The number of asset parts (coins/tokens) that are available to be traded and
     /// it excludes burnt supply and locked supply.
-    pub supply_circulating: Option<f64>,
-    #[serde(rename = "SUPPLY_FUTURE")]
+    pub supply_circulating: Option<Numeric>,
+    #[serde(rename = "SUPPLY_FUTURE", default)]
     /// The number of asset parts (coins/tokens) that are planned to be issued in the future. (supply_max - supply_issued).
-    pub supply_future: f64,
-    #[serde(rename = "SUPPLY_LOCKED")]
+    pub supply_future: Numeric,
+    #[serde(rename = "SUPPLY_LOCKED", default)]
     /// The number of asset parts (coins/tokens) that are currently not transferable until certain conditions are met.
     /// Locked supply is generally held by team members, DAOs, foundations, bridges, stakers, liquidity pools, etc.
-    pub supply_locked: Option<f64>,
-    #[serde(rename = "SUPPY_BURNT")]
+    pub supply_locked: Option<Numeric>,
+    #[serde(rename = "SUPPY_BURNT", default)]
     /// The number of asset parts (coins/tokens) that have been sent to addresses/locations that are no longer accessible.
     /// They are permanently removed from the circulating supply on purpose, this does not include lost tokens sent to wallets that do not exist or
     /// sent to wallets that users no longer have access to, the address of burnt tokens is determined by the project team.
-    pub supply_burnt: Option<f64>,
-    #[serde(rename = "SUPPLY_STAKED")]
+    pub supply_burnt: Option<Numeric>,
+    #[serde(rename = "SUPPLY_STAKED", default)]
     /// The current number of asset parts (coins/tokens) that are locked as part of PoS and PoS partial chains.
-    pub supply_staked: Option<f64>,
-    #[serde(rename = "LAST_BLOCK_MINT")]
+    pub supply_staked: Option<Numeric>,
+    #[serde(rename = "LAST_BLOCK_MINT", default)]
     /// New asset parts (coins/tokens) created in the most recently issued block.
-    pub last_block_mint: Option<f64>,
-    #[serde(rename = "LAST_BLOCK_BURN")]
+    pub last_block_mint: Option<Numeric>,
+    #[serde(rename = "LAST_BLOCK_BURN", default)]
     /// The total amount of asset parts (coins/tokens) that were taken out of circulation in the most recently issued block.
-    pub last_block_burn: Option<f64>,
-    #[serde(rename = "BURN_ADDRESSES")]
+    pub last_block_burn: Option<Numeric>,
+    #[serde(rename = "BURN_ADDRESSES", default, deserialize_with = "crate::schemas::empty_object_as_none")]
     /// The list of addresses that are considered burn addresses for this asset.
     pub burn_addresses: Option<Vec<SpecialAddress>>,
-    #[serde(rename = "LOCKED_ADDRESSES")]
+    #[serde(rename = "LOCKED_ADDRESSES", default, deserialize_with = "crate::schemas::empty_object_as_none")]
     /// The list of addresses that are considered locked addresses for this asset.
     pub locked_addresses: Option<Vec<SpecialAddress>>,
-    #[serde(rename = "ASSET_STATUS")]
+    #[serde(rename = "ASSET_STATUS", default)]
     /// The current operational status of the asset and its underlying project
     pub asset_status: Option<String>,
-    #[serde(rename = "ASSET_SYMBOL_GLYPH")]
+    #[serde(rename = "ASSET_SYMBOL_GLYPH", default)]
     /// The Asset Symbol Glyph represents the visual or typographic mark associated with an asset, such as "$" for USD or "₿" for Bitcoin.
     /// It is distinct from the asset symbol (e.g., USD, BTC) and provides a recognizable representation used in financial contexts,
     /// UIs, and documentation.
     pub asset_symbol_glyph: Option<String>,
-    #[serde(rename = "IS_EXCLUDED_FROM_PRICE_TOPLIST")]
+    #[serde(rename = "IS_EXCLUDED_FROM_PRICE_TOPLIST", default)]
     /// Indicates whether the asset should be excluded from the overall and asset type price toplist rankings.
     /// But this will still be included in the industry ones.
     pub is_excluded_from_price_toplist: Option<bool>,
-    #[serde(rename = "IS_EXCLUDED_FROM_VOLUME_TOPLIST")]
+    #[serde(rename = "IS_EXCLUDED_FROM_VOLUME_TOPLIST", default)]
     /// Indicates whether the asset should be excluded from the overall and asset type volume toplist rankings
     /// But this will still be included in the industry ones.
     pub is_excluded_from_volume_toplist: Option<bool>,
-    #[serde(rename = "IS_EXCLUDED_FROM_MKT_CAP_TOPLIST")]
+    #[serde(rename = "IS_EXCLUDED_FROM_MKT_CAP_TOPLIST", default)]
     /// Indicates whether the asset should be excluded from the overall and asset type market capitalization toplist rankings.
     /// But this will still be included in the industry ones.
     pub is_excluded_from_mkt_cap_totlist: Option<bool>,
-    #[serde(rename = "ASSET_INDUSTRIES")]
+    #[serde(rename = "ASSET_INDUSTRIES", default, deserialize_with = "crate::schemas::empty_object_as_none")]
     /// The asset industries that asset operates in.
     pub asset_industries: Option<Vec<AssetIndustry>>,
-    #[serde(rename = "CONSENSUS_MECHANISMS")]
+    #[serde(rename = "CONSENSUS_MECHANISMS", default, deserialize_with = "crate::schemas::empty_object_as_none")]
     /// The types of consensus mechanisms this network / blockchain / token uses. This is a list of the fault-tolerant mechanism families that
     /// are used to achieve the necessary agreement on a single data value or a single state of the network among distributed processes or multi-agent systems.
     pub consensus_mechanisms: Option<Vec<ConsensusMechanism>>,
-    #[serde(rename = "CONSENSUS_ALGORITHM_TYPES")]
+    #[serde(rename = "CONSENSUS_ALGORITHM_TYPES", default, deserialize_with = "crate::schemas::empty_object_as_none")]
     /// The types of consensus algorithms this blockchain uses. This is dependent on the consensus mechanisms used by the network / blockchain / token.
     /// For blockchains that are proof of work this would in most cases have the mining algorithm. For hybrid assets,
     /// this would be a list of mining and proof of stake and other algorithm used for reaching consensus.
     pub consensus_algorithm_types: Option<Vec<ConsensusAlgorithmType>>,
-    #[serde(rename = "HASHING_ALGORITHM_TYPES")]
+    #[serde(rename = "HASHING_ALGORITHM_TYPES", default, deserialize_with = "crate::schemas::empty_object_as_none")]
     /// The types of algorithms this blockchain uses for hashing blocks, transactions etc.
     pub hashing_algorithm_types: Option<Vec<HashingAlgorithmType>>,
 }
@@ -286,49 +287,49 @@ pub struct AssetCodeRepoMetrics {
     #[serde(rename = "ASSET_SYMBOL")]
     /// Internal mapped symbol for a specific asset.
     pub asset_symbol: String,
-    #[serde(rename = "TOTAL_CONTRIBUTORS")]
+    #[serde(rename = "TOTAL_CONTRIBUTORS", default)]
     /// The total number of contributors across all code repositories associated with this asset.
     /// A contributor is a users who has contributed to a project by making changes or improvements to the project's codebase,
     /// having their changes accepted and merged, and is acknowledged for their contributions in the project's development history.
     pub total_contributors: i32,
-    #[serde(rename = "TOTAL_FORKS")]
+    #[serde(rename = "TOTAL_FORKS", default)]
     /// The total number of forks across all code repositories associated with this asset.
     /// A fork in GitHub is a feature that allows users to create a personal copy of another repository, enabling them to modify,
     /// experiment with, or contribute to the project without affecting the original work.
     pub total_forks: i32,
-    #[serde(rename = "TOTAL_STARS")]
+    #[serde(rename = "TOTAL_STARS", default)]
     /// The total number of stars received across all code repositories associated with this asset.
     /// A star is when a GitHub user bookmarks or wants to show appreciation for a particular repository,
     /// much like a "like" or "favorite" function on other social media platforms.
     pub total_stars: i32,
-    #[serde(rename = "TOTAL_SUBSCRIBERS")]
+    #[serde(rename = "TOTAL_SUBSCRIBERS", default)]
     /// The total number of subscribers across all code repositories associated with this asset.
     /// A subscriber is a user who has chosen to receive notifications for updates or changes made to a specific repository,
     /// allowing them to closely follow the development and discussions of a project.
     pub total_subscribers: i32,
-    #[serde(rename = "TOTAL_OPEN_ISSUES")]
+    #[serde(rename = "TOTAL_OPEN_ISSUES", default)]
     /// The total number of open issues across all code repositories associated with this asset.
     /// An open issue is a reported problem, suggestion, or task related to a repository that has not been resolved or closed yet,
     /// providing a platform for users to track and discuss the ongoing development and improvements of a project.
     pub total_open_issues: Option<i32>,
-    #[serde(rename = "TOTAL_CLOSED_ISSUES")]
+    #[serde(rename = "TOTAL_CLOSED_ISSUES", default)]
     /// The total number of closed issues across all code repositories associated with this asset.
     /// A closed issue is a reported problem, suggestion, or task related to a repository that have been resolved or deemed no longer relevant,
     /// providing a record of past challenges and solutions within the project development.
     pub total_closed_issues: i32,
-    #[serde(rename = "TOTAL_OPEN_PULL_REQUESTS")]
+    #[serde(rename = "TOTAL_OPEN_PULL_REQUESTS", default)]
     /// The total number of open pull requests across all code repositories associated with this asset.
     /// An open pull request on GitHub is a proposed change to a repository's codebase that is open for review and discussion,
     // offering contributors an opportunity to improve the project and maintainers to accept, reject, or request changes before
     /// integrating the proposed modifications.
     pub total_open_pull_requests: i32,
-    #[serde(rename = "TOTAL_CLOSED_PULL_REQUESTS")]
+    #[serde(rename = "TOTAL_CLOSED_PULL_REQUESTS", default)]
     /// The total number of closed pull requests across all code repositories associated with this asset.
     /// A closed pull request is a proposed change to a repository's codebase that has been either accepted and merged into the codebase,
     /// rejected by the maintainers, or withdrawn by the submitter, providing a record of changes that were suggested and their outcomes in
     /// the project's development history.
     pub total_closed_pull_requests: i32,
-    #[serde(rename = "CODE_REPOSITORIES")]
+    #[serde(rename = "CODE_REPOSITORIES", default)]
     /// An array with all the data for each code repository used to calculate the total stats.
     pub code_repositories: Vec<AssetCodeRepository>,
 }
@@ -374,16 +375,16 @@ pub struct AssetDiscord {
     #[serde(rename = "ASSET_SYMBOL")]
     /// Symbol for a specific asset.
     pub asset_symbol: String,
-    #[serde(rename = "TOTAL_MEMBERS")]
+    #[serde(rename = "TOTAL_MEMBERS", default)]
     /// The total number of users/members in this Discord server.
     pub total_members: Option<i32>,
-    #[serde(rename = "TOTAL_CURRENT_ACTIVE_USERS")]
+    #[serde(rename = "TOTAL_CURRENT_ACTIVE_USERS", default)]
     /// The number of online users in this Discord server.
     pub total_current_active_users: Option<i32>,
-    #[serde(rename = "TOTAL_PREMIUM_SUBSCRIBERS")]
+    #[serde(rename = "TOTAL_PREMIUM_SUBSCRIBERS", default)]
     /// The number of premium subscribers in this Discord server.
     pub total_premium_subscribers: Option<i32>,
-    #[serde(rename = "DISCORD_SERVERS")]
+    #[serde(rename = "DISCORD_SERVERS", default)]
     /// An array with all the data for each Discord server used to calculate the total stats.
     pub discord_servers: Vec<Option<AssetDiscordServer>>,
 }
@@ -438,25 +439,25 @@ pub struct AssetReddit {
     #[serde(rename = "ASSET_SYMBOL")]
     /// Internal mapped symbol for a specific asset.
     pub asset_symbol: String,
-    #[serde(rename = "TOTAL_SUBSCRIBERS")]
+    #[serde(rename = "TOTAL_SUBSCRIBERS", default)]
     /// The number of subscribers to the subreddit.
     pub total_subscribers: Option<i32>,
-    #[serde(rename = "TOTAL_ACTIVE_USERS")]
+    #[serde(rename = "TOTAL_ACTIVE_USERS", default)]
     /// The number of currently active users in the subreddit.
     pub total_active_users: Option<i32>,
-    #[serde(rename = "TOTAL_AVERAGE_POSTS_PER_DAY")]
+    #[serde(rename = "TOTAL_AVERAGE_POSTS_PER_DAY", default)]
     /// The average number of posts per day in the subreddit.
     pub total_average_posts_per_day: Option<f64>,
-    #[serde(rename = "TOTAL_AVERAGE_POSTS_PER_HOUR")]
+    #[serde(rename = "TOTAL_AVERAGE_POSTS_PER_HOUR", default)]
     /// The average number of posts per hour in the subreddit.
     pub total_average_posts_per_hour: Option<f64>,
-    #[serde(rename = "TOTAL_AVERAGE_COMMENTS_PER_DAY")]
+    #[serde(rename = "TOTAL_AVERAGE_COMMENTS_PER_DAY", default)]
     /// The average number of comments per day in the subreddit.
     pub total_average_comments_per_day: Option<f64>,
-    #[serde(rename = "TOTAL_AVERAGE_COMMENTS_PER_HOUR")]
+    #[serde(rename = "TOTAL_AVERAGE_COMMENTS_PER_HOUR", default)]
     /// The average number of comments per hour in the subreddit.
     pub total_average_comments_per_hour: Option<f64>,
-    #[serde(rename = "SUBREDDITS")]
+    #[serde(rename = "SUBREDDITS", default, deserialize_with = "crate::schemas::empty_object_as_none")]
     /// An array with all the data for each Subreddit used to calculate the total stats.
     pub subreddits: Option<Vec<AssetSubreddit>>,
 }
@@ -499,10 +500,10 @@ pub struct AssetTelegram {
     #[serde(rename = "ASSET_SYMBOL")]
     /// Internal mapped symbol for a specific asset.
     pub asset_symbol: String,
-    #[serde(rename = "TOTAL_MEMBERS")]
+    #[serde(rename = "TOTAL_MEMBERS", default)]
     /// The total number of users/members in this Telegram group.
     pub total_members: Option<i32>,
-    #[serde(rename = "TELEGRAM_GROUPS")]
+    #[serde(rename = "TELEGRAM_GROUPS", default)]
     /// An array with all the data for each Telegram group used to calculate the total stats.
     pub telegram_groups: Vec<AssetTelegramGroup>,
 }
@@ -564,22 +565,22 @@ pub struct AssetTwitter {
     #[serde(rename = "ASSET_SYMBOL")]
     /// Internal mapped symbol for a specific asset.
     pub asset_symbol: String,
-    #[serde(rename = "TOTAL_FOLLOWING")]
+    #[serde(rename = "TOTAL_FOLLOWING", default)]
     /// The total number of accounts followed by this X account.
     pub total_following: Option<i32>,
-    #[serde(rename = "TOTAL_FOLLOWERS")]
+    #[serde(rename = "TOTAL_FOLLOWERS", default)]
     /// The total number of followers of this X account.
     pub total_followers: Option<i32>,
-    #[serde(rename = "TOTAL_FAVOURITES")]
+    #[serde(rename = "TOTAL_FAVOURITES", default)]
     /// The total number of tweets favorited by this X account.
     pub total_favourites: Option<i32>,
-    #[serde(rename = "TOTAL_LISTS")]
+    #[serde(rename = "TOTAL_LISTS", default)]
     /// The total number of lists this X account is a member of.
     pub total_lists: Option<i32>,
-    #[serde(rename = "TOTAL_STATUSES")]
+    #[serde(rename = "TOTAL_STATUSES", default)]
     /// The total number of tweets and retweets made by this X account.
     pub total_statuses: Option<i32>,
-    #[serde(rename = "TWITTER_ACCOUNTS")]
+    #[serde(rename = "TWITTER_ACCOUNTS", default, deserialize_with = "crate::schemas::empty_object_as_none")]
     /// An array with all the data for each X account used to calculate the total stats.
     pub twitter_accounts: Option<Vec<AssetTwitterAccount>>,
 }
\ No newline at end of file
@@ -1,6 +1,7 @@
 use std::fmt::Display;
 use serde::{Serialize, Deserialize};
 use crate::schemas::data_api::InstrumentStatus;
+use crate::schemas::Numeric;
 
 
 #[derive(Clone, Copy, Debug, Default, Serialize, Deserialize)]
@@ -79,56 +80,56 @@ pub struct DerIndicesOHLCV {
     #[serde(rename = "INSTRUMENT")]
     /// The specific financial asset pair that an index is tracking in unmapped format. In most cases this is a combiation of the base and quote assets of the pair.
     pub instrument: String,
-    #[serde(rename = "OPEN")]
+    #[serde(rename = "OPEN", default)]
     /// The unmapped instrument ID.
-    pub open: f64,
-    #[serde(rename = "HIGH")]
+    pub open: Numeric,
+    #[serde(rename = "HIGH", default)]
     /// The open value for the historical period, this is based on the closest index update before the period start.
-    pub high: f64,
-    #[serde(rename = "LOW")]
+    pub high: Numeric,
+    #[serde(rename = "LOW", default)]
     /// The max between the open and the highest index update value in this time period (same as open when there no messages in the time period).
-    pub low: f64,
-    #[serde(rename = "CLOSE")]
+    pub low: Numeric,
+    #[serde(rename = "CLOSE", default)]
     /// The min between the open and the lowest index update value in this time period (same as open when there no messages in the time period).
-    pub close: f64,
-    #[serde(rename = "FIRST_MESSAGE_TIMESTAMP")]
+    pub close: Numeric,
+    #[serde(rename = "FIRST_MESSAGE_TIMESTAMP", default)]
     /// The timestamp in seconds of the first index update in this time period (only available when we have at least one index update in the time period).
     pub first_message_timestamp: i64,
-    #[serde(rename = "LAST_MESSAGE_TIMESTAMP")]
+    #[serde(rename = "LAST_MESSAGE_TIMESTAMP", default)]
     /// The timestamp in seconds of the last index update in this time period (only available when we have at least one index update in the time period).
     pub last_message_timestamp: i64,
-    #[serde(rename = "FIRST_MESSAGE_VALUE")]
+    #[serde(rename = "FIRST_MESSAGE_VALUE", default)]
     /// The open based on the first index update in the time period (only available when we have at least one index update in the time period).
-    pub first_message_value: f64,
-    #[serde(rename = "HIGH_MESSAGE_VALUE")]
+    pub first_message_value: Numeric,
+    #[serde(rename = "HIGH_MESSAGE_VALUE", default)]
     /// The highest value of the messages in the time period (only available when we have at least one index update in the time period).
-    pub high_message_value: f64,
-    #[serde(rename = "HIGH_MESSAGE_TIMESTAMP")]
+    pub high_message_value: Numeric,
+    #[serde(rename = "HIGH_MESSAGE_TIMESTAMP", default)]
     /// The timestamp in seconds of the highest index update in this time period (only available when we have at least one index update in the time period).
     pub high_message_timestamp: i64,
-    #[serde(rename = "LOW_MESSAGE_VALUE")]
+    #[serde(rename = "LOW_MESSAGE_VALUE", default)]
     /// The lowest value of the messages in the time period (only available when we have at least one index update in the time period).
-    pub low_message_value: f64,
-    #[serde(rename = "LOW_MESSAGE_TIMESTAMP")]
+    pub low_message_value: Numeric,
+    #[serde(rename = "LOW_MESSAGE_TIMESTAMP", default)]
     /// The timestamp in seconds of the lowest index update in this time period (only available when we have at least one index update in the time period).
     pub low_message_timestamp: i64,
-    #[serde(rename = "LAST_MESSAGE_VALUE")]
+    #[serde(rename = "LAST_MESSAGE_VALUE", default)]
     /// The last index update value in the time period (only available when we have at least one index update in the time period).
-    pub last_message_value: f64,
-    #[serde(rename = "TOTAL_INDEX_UPDATES")]
+    pub last_message_value: Numeric,
+    #[serde(rename = "TOTAL_INDEX_UPDATES", default)]
     /// The total number of message updates seen in this time period (0 when there no messages in the time period).
     pub total_index_updates: i32,
-    #[serde(rename = "MAPPED_INSTRUMENT")]
+    #[serde(rename = "MAPPED_INSTRUMENT", default)]
     /// The instrument ID, as derived from our mapping rules. Only available on instruments that have been mapped.
     pub mapped_instrument: Option<String>,
-    #[serde(rename = "CURRENCY")]
+    #[serde(rename = "CURRENCY", default)]
     /// The mapped index currency. Only available on instruments that have mapping.
     pub currency: Option<String>,
-    #[serde(rename = "CURRENCY_ID")]
+    #[serde(rename = "CURRENCY_ID", default)]
     /// Represents the internal CoinDesk ID for the mapped index currency, e.g. 1. This ID is unique and immutable, ensuring consistent identification.
     // Applicable only to instruments with a mapping.
     pub currency_id: Option<i32>,
-    #[serde(rename = "TRANSFORM_FUNCTION")]
+    #[serde(rename = "TRANSFORM_FUNCTION", default)]
     /// The transform function. This is the function we apply when we do mapping to change values into easier human readable ones and to make
     /// sure the mapped direction BASE - QUOTE is constant accross all instruments.
     pub transform_function: Option<String>,
@@ -1,5 +1,6 @@
 use std::fmt::Display;
 use serde::{Serialize, Deserialize};
+use crate::schemas::Numeric;
 
 
 #[derive(Clone, Copy, Debug, Default, Serialize, Deserialize)]
@@ -48,88 +49,88 @@ pub struct IndicesOHLCV {
     #[serde(rename = "INSTRUMENT")]
     /// The specific financial asset pair that an index is tracking in unmapped format. In most cases this is a combiation of the base and quote assets of the pair.
     pub instrument: String,
-    #[serde(rename = "OPEN")]
+    #[serde(rename = "OPEN", default)]
     /// The initial value (price) of an index at market opening on a trading period. It's a significant data point used to understand the initial market sentiment,
     /// calculate various technical indicators, and for visual representation in charts to identify trends or patterns over time.
-    pub open: f64,
-    #[serde(rename = "HIGH")]
+    pub open: Numeric,
+    #[serde(rename = "HIGH", default)]
     /// The maximum value an index (price) reaches during a specific trading period. It's a significant data point used to understand the index's potential
     /// and volatility, calculate the range of daily movement, and for visual representation in charts to identify trends or patterns over time.
-    pub high: f64,
-    #[serde(rename = "LOW")]
+    pub high: Numeric,
+    #[serde(rename = "LOW", default)]
     /// The minimum value (price) an index reaches during a specific trading period. It's a vital data point used to understand the index's volatility and risk,
     /// calculate the range of daily movement, and for visual representation in charts to identify trends or patterns over time.
-    pub low: f64,
-    #[serde(rename = "CLOSE")]
+    pub low: Numeric,
+    #[serde(rename = "CLOSE", default)]
     /// The last value (price) of an index at the end of a trading period. It's a critical data point used for performance comparison,
     /// calculations like daily return or volatility, and for visual representation in charts to identify trends or patterns over time.
-    pub close: f64,
-    #[serde(rename = "FIRST_MESSAGE_TIMESTAMP")]
+    pub close: Numeric,
+    #[serde(rename = "FIRST_MESSAGE_TIMESTAMP", default)]
     /// The timestamp in seconds of the initial index update in the time period (only available when we have at least one index update in the time period).
     pub first_message_timestamp: i64,
-    #[serde(rename = "LAST_MESSAGE_TIMESTAMP")]
+    #[serde(rename = "LAST_MESSAGE_TIMESTAMP", default)]
     /// The timestamp in seconds of the last index update in the time period (only available when we have at least one index update in the time period).
     pub last_message_timestamp: i64,
-    #[serde(rename = "FIRST_MESSAGE_VALUE")]
+    #[serde(rename = "FIRST_MESSAGE_VALUE", default)]
     /// The open value (price) based on the inital index update in the time period (only available when we have at least one index update in the time period).
-    pub first_message_value: f64,
-    #[serde(rename = "HIGH_MESSAGE_VALUE")]
+    pub first_message_value: Numeric,
+    #[serde(rename = "HIGH_MESSAGE_VALUE", default)]
     /// The maximum value an index (price) based on all the index updates in the time period (only available when we have at least one index update
     /// in the time period).
-    pub high_message_value: f64,
-    #[serde(rename = "HIGH_MESSAGE_TIMESTAMP")]
+    pub high_message_value: Numeric,
+    #[serde(rename = "HIGH_MESSAGE_TIMESTAMP", default)]
     /// The timestamp in seconds of the maximum value an index (price) based on all the index updates in the time period (only available when we have at
     /// least one index update in the time period).
     pub high_message_timestamp: i64,
-    #[serde(rename = "LOW_MESSAGE_VALUE")]
+    #[serde(rename = "LOW_MESSAGE_VALUE", default)]
     /// The minimum value an index (price) based on all the index updates in the time period (only available when we have at least one index update
     /// in the time period).
-    pub low_message_value: f64,
-    #[serde(rename = "LOW_MESSAGE_TIMESTAMP")]
+    pub low_message_value: Numeric,
+    #[serde(rename = "LOW_MESSAGE_TIMESTAMP", default)]
     /// The timestamp in seconds of the the minimum value an index (price) based on all the index updates in the time period (only available when we have at
     /// least one index update in the time period).
     pub low_message_timestamp: i64,
-    #[serde(rename = "LAST_MESSAGE_VALUE")]
+    #[serde(rename = "LAST_MESSAGE_VALUE", default)]
     /// The last value (price) of an index based on the last index update in the time period (only available when we have at least one index update
     /// in the time period).
-    pub last_message_value: f64,
-    #[serde(rename = "TOTAL_INDEX_UPDATES")]
+    pub last_message_value: Numeric,
+    #[serde(rename = "TOTAL_INDEX_UPDATES", default)]
     /// The total number of message updates seen in this time period (0 when there no messages in the time period).
     pub total_index_updates: i32,
-    #[serde(rename = "VOLUME")]
+    #[serde(rename = "VOLUME", default)]
     /// The total number of base asset parts traded for the index instrument in the time period.
     /// It's a critical metric that provides insights into market liquidity and activity level. High volumes indicate high investor interest and liquidity,
     /// while low volumes suggest the opposite.
-    pub volume: f64,
-    #[serde(rename = "VOLUME_TOP_TIER")]
+    pub volume: Numeric,
+    #[serde(rename = "VOLUME_TOP_TIER", default)]
     /// The total number of top tier base asset parts traded for the index instrument in the time period.
     /// It's a critical metric that provides insights into market liquidity and activity level. High volumes indicate high investor interest and liquidity,
     /// while low volumes suggest the opposite.
-    pub volume_top_tier: f64,
-    #[serde(rename = "VOLUME_DIRECT")]
+    pub volume_top_tier: Numeric,
+    #[serde(rename = "VOLUME_DIRECT", default)]
     /// The total number of direct base asset parts traded for the index instrument in the time period.
     /// It's a critical metric that provides insights into market liquidity and activity level. High volumes indicate high investor interest and liquidity,
     /// while low volumes suggest the opposite.
-    pub volume_direct: f64,
-    #[serde(rename = "VOLUME_TOP_TIER_DIRECT")]
+    pub volume_direct: Numeric,
+    #[serde(rename = "VOLUME_TOP_TIER_DIRECT", default)]
     /// The total number of top tier direct base asset parts traded for the index instrument in the time period.
     /// It's a critical metric that provides insights into market liquidity and activity level. High volumes indicate high investor interest and liquidity,
     /// while low volumes suggest the opposite.
-    pub volume_top_tier_direct: f64,
-    #[serde(rename = "QUOTE_VOLUME")]
+    pub volume_top_tier_direct: Numeric,
+    #[serde(rename = "QUOTE_VOLUME", default)]
     /// The total number of quote (counter) asset parts traded for the index instrument in the time period.
     /// This offers insight into market activity and liquidity and is used widely in numerical analysis and data visualization.
-    pub quote: f64,
-    #[serde(rename = "QUOTE_VOLUME_TOP_TIER")]
+    pub quote: Numeric,
+    #[serde(rename = "QUOTE_VOLUME_TOP_TIER", default)]
     /// The total number of top tier quote (counter) asset parts traded for the index instrument in the time period.
     /// This offers insight into market activity and liquidity and is used widely in numerical analysis and data visualization.
-    pub quote_top_tier: f64,
-    #[serde(rename = "QUOTE_VOLUME_DIRECT")]
+    pub quote_top_tier: Numeric,
+    #[serde(rename = "QUOTE_VOLUME_DIRECT", default)]
     /// The total number of direct quote (counter) asset parts traded for the index instrument in the time period.
     /// This offers insight into market activity and liquidity and is used widely in numerical analysis and data visualization.
-    pub quote_direct: f64,
-    #[serde(rename = "QUOTE_VOLUME_TOP_TIER_DIRECT")]
+    pub quote_direct: Numeric,
+    #[serde(rename = "QUOTE_VOLUME_TOP_TIER_DIRECT", default)]
     /// The total number of top tier direct quote (counter) asset parts traded for the index instrument in the time period.
     /// This offers insight into market activity and liquidity and is used widely in numerical analysis and data visualization.
-    pub quote_top_tier_direct: f64,
+    pub quote_top_tier_direct: Numeric,
 }
\ No newline at end of file
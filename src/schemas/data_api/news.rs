@@ -403,13 +403,13 @@ pub struct NewsSource {
 /// The filters for the news category.
 #[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct CategoryFilter {
-    #[serde(rename = "INCLUDED_WORDS")]
+    #[serde(rename = "INCLUDED_WORDS", default, deserialize_with = "crate::schemas::empty_object_as_none")]
     /// Words related or included in news category.
     pub included_words: Option<Vec<String>>,
-    #[serde(rename = "INCLUDED_PHRASES")]
+    #[serde(rename = "INCLUDED_PHRASES", default, deserialize_with = "crate::schemas::empty_object_as_none")]
     /// Phrases that should be included in news category.
     pub included_phrases: Option<Vec<String>>,
-    #[serde(rename = "EXCLUDED_PHRASES")]
+    #[serde(rename = "EXCLUDED_PHRASES", default, deserialize_with = "crate::schemas::empty_object_as_none")]
     /// Phrases to should be excluded from news category.
     pub excluded_phrases: Option<Vec<String>>,
 }
@@ -427,7 +427,7 @@ pub struct NewsCategory {
     #[serde(rename = "NAME")]
     /// The name of the news category.
     pub name: String,
-    #[serde(rename = "FILTER")]
+    #[serde(rename = "FILTER", default, deserialize_with = "crate::schemas::empty_object_as_none")]
     /// The filters for the news category.
     pub filter: Option<CategoryFilter>,
     #[serde(rename = "STATUS")]
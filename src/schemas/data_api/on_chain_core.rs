@@ -1,5 +1,6 @@
 use serde::{Serialize, Deserialize};
 use crate::schemas::data_api::{PreviousAssetSymbol, AssetAlternativeId, AssetIndustry, SpecialAddress};
+use crate::schemas::Numeric;
 
 
 #[derive(Clone, Debug, Default, Serialize, Deserialize)]
@@ -46,7 +47,7 @@ pub struct OCCoreETHTrace {
     #[serde(rename = "TRACE_TYPE")]
     /// The type of trace being recorded. It could be either "call", "reward", "suicide", "create", etc. depending on the type of action being traced.
     pub trace_type: Option<String>,
-    #[serde(rename = "ADDRESS")]
+    #[serde(rename = "ADDRESS", default, deserialize_with = "crate::schemas::empty_object_as_none")]
     /// This represents the address associated with the trace. This could refer to the contract address, external user address, or any other relevant
     /// address in the context of the blockchain transaction.
     pub address: Option<Vec<i32>>,
@@ -218,7 +219,7 @@ pub struct OCCoreETHTransactionAccessList {
     #[serde(rename = "ADDRESS")]
     /// Internal mapped Block Transaction Access address state.
     pub address: Option<String>,
-    #[serde(rename = "Storage_KEYS")]
+    #[serde(rename = "Storage_KEYS", default, deserialize_with = "crate::schemas::empty_object_as_none")]
     /// An array of storage keys associated with the address mentioned above. Storage keys are unique identifiers used to access data within
     /// a smart contract's storage on the blockchain. Smart contracts often store data in a key-value format, where the storage keys are used
     /// to retrieve specific values.
@@ -238,7 +239,7 @@ pub struct OCCoreETHTransactionBlob {
     pub kzg_commitment: Option<String>,
     #[serde(rename = "KZG_PROOF")]
     pub kzg_proof: Option<String>,
-    #[serde(rename = "KZG_COMMITMENT_INCLUSION_PROOF")]
+    #[serde(rename = "KZG_COMMITMENT_INCLUSION_PROOF", default, deserialize_with = "crate::schemas::empty_object_as_none")]
     pub kzg_commitment_inclusion_proof: Option<Vec<String>>,
     #[serde(rename = "SIZE")]
     pub size: Option<i32>,
@@ -266,7 +267,7 @@ pub struct OCCoreETHTransactionLog {
     /// This data can vary depending on the specific smart contract and event that generated the log.
     /// It might include relevant information about the state change or action represented by the log.
     pub data: Option<String>,
-    #[serde(rename = "TOPICS")]
+    #[serde(rename = "TOPICS", default, deserialize_with = "crate::schemas::empty_object_as_none")]
     /// An array of one or more indexed event topics. These topics are hash values of event signatures or indexed arguments.
     /// They provide a way to filter and search for specific types of events within the logs efficiently.
     pub topics: Option<Vec<String>>,
@@ -361,10 +362,10 @@ pub struct OCCoreETHTransaction {
     #[serde(rename = "RECEIPT_EFFECTIVE_GAS_PRICE")]
     /// The total base charge plus tip paid for each unit of gas.
     pub receipt_effective_gas_price: Option<String>,
-    #[serde(rename = "ACCESS_LIST")]
+    #[serde(rename = "ACCESS_LIST", default, deserialize_with = "crate::schemas::empty_object_as_none")]
     /// This causes a transaction to warm (i.e. pre-cache) another addresses state and the specified storage keys, Available on EIP-2930 transactions.
     pub access_list: Option<Vec<OCCoreETHTransactionAccessList>>,
-    #[serde(rename = "BLOBS")]
+    #[serde(rename = "BLOBS", default, deserialize_with = "crate::schemas::empty_object_as_none")]
     /// The current transaction blobs, null when there are no blobs attached to the transaction.
     pub blobs: Option<Vec<OCCoreETHTransactionBlob>>,
     #[serde(rename = "ECDSA_V")]
@@ -380,10 +381,10 @@ pub struct OCCoreETHTransaction {
     #[serde(rename = "Y_PARITY")]
     /// This is a recent addition from July 2023 and could be used instead of the ECDSA_V. ECDSA_V would be used for backward compatibility.
     pub y_parity: Option<String>,
-    #[serde(rename = "TRACES")]
+    #[serde(rename = "TRACES", default, deserialize_with = "crate::schemas::empty_object_as_none")]
     /// The detailed records of the steps and interactions that occured during the execution of this transaction.
     pub traces: Option<Vec<OCCoreETHTrace>>,
-    #[serde(rename = "LOGS")]
+    #[serde(rename = "LOGS", default, deserialize_with = "crate::schemas::empty_object_as_none")]
     /// The chronological records of all activities such as transaction executions, contract interactions,
     /// errors, and changes that occur within a blockchain network.
     pub logs: Option<Vec<OCCoreETHTransactionLog>>,
@@ -462,19 +463,19 @@ pub struct OCCoreETHBlock {
     #[serde(rename = "RECEIVED_TIMESTAMP")]
     /// The current block received timestamp.
     pub received_timestamp: i64,
-    #[serde(rename = "METADATA")]
+    #[serde(rename = "METADATA", default)]
     /// The current block full metadata.
     pub metadata: OCCoreETHMetadata,
-    #[serde(rename = "TRANSACTIONS")]
+    #[serde(rename = "TRANSACTIONS", default, deserialize_with = "crate::schemas::empty_object_as_none")]
     /// The current block transactions.
     pub transactions: Option<Vec<OCCoreETHTransaction>>,
-    #[serde(rename = "ORPHAN_TRACES")]
+    #[serde(rename = "ORPHAN_TRACES", default, deserialize_with = "crate::schemas::empty_object_as_none")]
     /// Block traces not linked to a transaction.
     pub orphan_traces: Option<Vec<OCCoreETHTrace>>,
-    #[serde(rename = "UNCLES")]
+    #[serde(rename = "UNCLES", default, deserialize_with = "crate::schemas::empty_object_as_none")]
     /// The current block uncles.
     pub uncles: Option<Vec<OCCoreETHUncle>>,
-    #[serde(rename = "WITHDRAWALS")]
+    #[serde(rename = "WITHDRAWALS", default, deserialize_with = "crate::schemas::empty_object_as_none")]
     /// The current block withdrawal.
     pub withdrawals: Option<Vec<OCCoreETHWithdrawal>>,
 }
@@ -533,7 +534,7 @@ pub struct SupportedAsset {
     /// The launch date of the asset is indicated as (yyyy-mm-dd). However, if the asset was initially established as a token before being integrated into
     /// a blockchain, the launch date is reset to the creation of the first block when the blockchain is launched for the token.
     pub launch_date: Option<i64>,
-    #[serde(rename = "FILTERED_SUPPORTED_PLATFORMS")]
+    #[serde(rename = "FILTERED_SUPPORTED_PLATFORMS", default, deserialize_with = "crate::schemas::empty_object_as_none")]
     pub filtered_supported_platforms: Option<Vec<OCCoreSupportedPlatforms>>,
 }
 
@@ -624,67 +625,67 @@ pub struct OCCoreToplistRank {
     pub launch_date: i64,
     #[serde(rename = "PRICE_USD")]
     /// Rank based on PRICE_USD sorted DESC from most expensive asset to cheapest one.
-    pub price_usd: Option<f64>,
+    pub price_usd: Option<Numeric>,
     #[serde(rename = "CIRCULATING_MKT_CAP_USD")]
     /// Rank based on CIRCULATING_MKT_CAP_USD sorted DESC from highest circulating market cap to lowest.
-    pub circulating_mkt_cap_usd: Option<f64>,
+    pub circulating_mkt_cap_usd: Option<Numeric>,
     #[serde(rename = "TOTAL_MKT_CAP_USD")]
     /// Rank based on TOTAL_MKT_CAP_USD sorted DESC from highest total market cap to lowest.
-    pub total_mkt_cap_usd: Option<f64>,
+    pub total_mkt_cap_usd: Option<Numeric>,
     #[serde(rename = "SPOT_MOVING_24_HOUR_QUOTE_VOLUME_TOP_TIER_DIRECT_USD")]
     /// Rank based on SPOT_MOVING_24_HOUR_QUOTE_VOLUME_TOP_TIER_DIRECT_USD sorted DESC from highest quote
     /// volume accross top tier markets that trade directly to USD to lowest.
-    pub spot_moving_24_hour_quote_volume_top_tier_direct_usd: Option<f64>,
+    pub spot_moving_24_hour_quote_volume_top_tier_direct_usd: Option<Numeric>,
     #[serde(rename = "SPOT_MOVING_24_HOUR_QUOTE_VOLUME_DIRECT_USD")]
     /// Rank based on SPOT_MOVING_24_HOUR_QUOTE_VOLUME_DIRECT_USD sorted DESC from highest volume accross all markets that trade directly to USD to lowest.
-    pub spot_moving_24_hour_quote_volume_direct_usd: Option<f64>,
+    pub spot_moving_24_hour_quote_volume_direct_usd: Option<Numeric>,
     #[serde(rename = "SPOT_MOVING_24_HOUR_QUOTE_VOLUME_TOP_TIER_USD")]
     /// Rank based on SPOT_MOVING_24_HOUR_QUOTE_VOLUME_TOP_TIER_USD sorted DESC from highest quote volume accross top tier markets to lowest.
-    pub spot_moving_24_hour_quote_volume_top_tier_usd: Option<f64>,
+    pub spot_moving_24_hour_quote_volume_top_tier_usd: Option<Numeric>,
     #[serde(rename = "SPOT_MOVING_24_HOUR_QUOTE_VOLUME_USD")]
     /// Rank based on SPOT_MOVING_24_HOUR_QUOTE_VOLUME_USD sorted DESC from highest quote volume accross all markets to lowest.
-    pub spot_moving_24_hour_quote_volume_usd: Option<f64>,
+    pub spot_moving_24_hour_quote_volume_usd: Option<Numeric>,
     #[serde(rename = "SPOT_MOVING_24_HOUR_CHANGE_USD")]
     /// Rank based on SPOT_MOVING_24_HOUR_CHANGE_USD sorted DESC from highest change accross all markets to lowest.
-    pub spot_moving_24_hour_change_usd: Option<f64>,
+    pub spot_moving_24_hour_change_usd: Option<Numeric>,
     #[serde(rename = "SPOT_MOVING_24_HOUR_CHANGE_PERCENTAGE_USD")]
     /// Rank based on SPOT_MOVING_24_HOUR_CHANGE_PERCENTAGE_USD sorted DESC from highest percentage change accross all markets to lowest.
     pub spot_moving_24_hour_change_percentage_usd: Option<f64>,
     #[serde(rename = "SPOT_MOVING_7_DAY_QUOTE_VOLUME_TOP_TIER_DIRECT_USD")]
     /// Rank based on SPOT_MOVING_7_DAY_QUOTE_VOLUME_TOP_TIER_DIRECT_USD sorted DESC from highest quote volume accross top tier markets that
     /// trade directly to USD to lowest.
-    pub spot_moving_7_day_quote_volume_top_tier_direct_usd: Option<f64>,
+    pub spot_moving_7_day_quote_volume_top_tier_direct_usd: Option<Numeric>,
     #[serde(rename = "SPOT_MOVING_7_DAY_QUOTE_VOLUME_DIRECT_USD")]
     /// Rank based on SPOT_MOVING_7_DAY_QUOTE_VOLUME_DIRECT_USD sorted DESC from highest volume accross all markets that trade directly to USD to lowest.
-    pub spot_moving_7_day_quote_volume_direct_usd: Option<f64>,
+    pub spot_moving_7_day_quote_volume_direct_usd: Option<Numeric>,
     #[serde(rename = "SPOT_MOVING_7_DAY_QUOTE_VOLUME_TOP_TIER_USD")]
     /// Rank based on SPOT_MOVING_7_DAY_QUOTE_VOLUME_TOP_TIER_USD sorted DESC from highest quote volume accross top tier markets to lowest.
-    pub spot_moving_7_day_quote_volume_top_tier_usd: Option<f64>,
+    pub spot_moving_7_day_quote_volume_top_tier_usd: Option<Numeric>,
     #[serde(rename = "SPOT_MOVING_7_DAY_QUOTE_VOLUME_USD")]
     /// Rank based on SPOT_MOVING_7_DAY_QUOTE_VOLUME_USD sorted DESC from highest quote volume accross all markets to lowest.
-    pub spot_moving_7_day_quote_volume_usd: Option<f64>,
+    pub spot_moving_7_day_quote_volume_usd: Option<Numeric>,
     #[serde(rename = "SPOT_MOVING_7_DAY_CHANGE_USD")]
     /// Rank based on SPOT_MOVING_7_DAY_CHANGE_USD sorted DESC from highest change accross all markets to lowest.
-    pub spot_moving_7_day_change_usd: Option<f64>,
+    pub spot_moving_7_day_change_usd: Option<Numeric>,
     #[serde(rename = "SPOT_MOVING_7_DAY_CHANGE_PERCENTAGE_USD")]
     /// Rank based on SPOT_MOVING_7_DAY_CHANGE_PERCENTAGE_USD sorted DESC from highest percentage change accross all markets to lowest.
     pub spot_moving_7_day_change_percentage_usd: Option<f64>,
     #[serde(rename = "SPOT_MOVING_30_DAY_QUOTE_VOLUME_TOP_TIER_DIRECT_USD")]
     /// Rank based on SPOT_MOVING_30_DAY_QUOTE_VOLUME_TOP_TIER_DIRECT_USD sorted DESC from highest quote volume accross top tier markets
     /// that trade directly to USD to lowest.
-    pub spot_moving_30_day_quote_volume_top_tier_direct_usd: Option<f64>,
+    pub spot_moving_30_day_quote_volume_top_tier_direct_usd: Option<Numeric>,
     #[serde(rename = "SPOT_MOVING_30_DAY_QUOTE_VOLUME_DIRECT_USD")]
     /// Rank based on SPOT_MOVING_30_DAY_QUOTE_VOLUME_DIRECT_USD sorted DESC from highest volume accross all markets that trade directly to USD to lowest.
-    pub spot_moving_30_day_quote_volume_direct_usd: Option<f64>,
+    pub spot_moving_30_day_quote_volume_direct_usd: Option<Numeric>,
     #[serde(rename = "SPOT_MOVING_30_DAY_QUOTE_VOLUME_TOP_TIER_USD")]
     /// Rank based on SPOT_MOVING_30_DAY_QUOTE_VOLUME_TOP_TIER_USD sorted DESC from highest quote volume accross top tier markets to lowest.
-    pub spot_moving_30_day_quote_volume_top_tier_usd: Option<f64>,
+    pub spot_moving_30_day_quote_volume_top_tier_usd: Option<Numeric>,
     #[serde(rename = "SPOT_MOVING_30_DAY_QUOTE_VOLUME_USD")]
     /// Rank based on SPOT_MOVING_30_DAY_QUOTE_VOLUME_USD sorted DESC from highest quote volume accross all markets to lowest.
-    pub spot_moving_30_day_quote_volume_usd: Option<f64>,
+    pub spot_moving_30_day_quote_volume_usd: Option<Numeric>,
     #[serde(rename = "SPOT_MOVING_30_DAY_CHANGE_USD")]
     /// Rank based on SPOT_MOVING_30_DAY_CHANGE_USD sorted DESC from highest change accross all markets to lowest.
-    pub spot_moving_30_day_change_usd: Option<f64>,
+    pub spot_moving_30_day_change_usd: Option<Numeric>,
     #[serde(rename = "SPOT_MOVING_30_DAY_CHANGE_PERCENTAGE_USD")]
     /// Rank based on SPOT_MOVING_30_DAY_CHANGE_PERCENTAGE_USD sorted DESC from highest percentage change accross all markets to lowest.
     pub spot_moving_30_day_change_percentage_usd: Option<f64>,
@@ -724,17 +725,17 @@ pub struct OCCoreAssetByAddress {
     #[serde(rename = "TYPE")]
     /// Type of the message.
     pub type_: String,
-    #[serde(rename = "ID_LEGACY")]
+    #[serde(rename = "ID_LEGACY", default)]
     /// The legacy previous asset management system ID.
     pub id_legacy: i32,
-    #[serde(rename = "ID_PARENT_ASSET")]
+    #[serde(rename = "ID_PARENT_ASSET", default)]
     /// This refers to the base, parent, or main asset to which a token is linked or pegged, signifying that the token acts as a representation of the parent asset.
     /// When a token loses its connection to a parent asset due to events such as hacks or the issuing entity's decision to not honor the peg—similar to how
     /// TerraUSD detached from its USD peg—the PARENT_ASSET_SYMBOL is removed because the token no longer serves as a true representation of the parent asset.
     /// In order to remove the parent we need clear communication from the company who is in charge of keeping the peg.
     /// We add add a plublic notice and the include the communication in the Other Document URLs.
     pub id_parent_asset: i32,
-    #[serde(rename = "IS_ASSET_ISSUER")]
+    #[serde(rename = "IS_ASSET_ISSUER", default)]
     /// This field identifies the original creator of the token. It provides essential information about the entity, individual or contract rules responsible for
     /// issuing the token initially and/or maintaining the supply. In the case of of bridged assets, this is the bridge operator and the parent will have
     /// its own issuer. You can go up the parent chain and figure out what counterparty risk you are exposed to when trading a specific asset.
@@ -743,316 +744,316 @@ pub struct OCCoreAssetByAddress {
     #[serde(rename = "SYMBOL")]
     /// Internal mapped symbol for a specific asset.
     pub symbol: String,
-    #[serde(rename = "URI")]
+    #[serde(rename = "URI", default)]
     /// The uri path that this asset will be found on / url-slug.
     pub uri: String,
-    #[serde(rename = "ASSET_TYPE")]
+    #[serde(rename = "ASSET_TYPE", default)]
     /// The asset class/type.
     pub asset_type: String,
-    #[serde(rename = "ASSET_ISSUER_NAME")]
+    #[serde(rename = "ASSET_ISSUER_NAME", default)]
     /// This field identifies the original creator of the asset. It provides essential information about the entity, individual or contract rules responsible for
     /// issuing the asset initially and/or maintaining the supply. In the case of of bridged assets, this is the bridge operator and the parent will have its
     /// own issuer. You can go up the parent chain and figure out what counterparty risk you are exposed to when trading a specific asset.
     /// This clarification ensures that users can directly trace the origin of the asset, understanding its issuance history and the primary issuer's credentials.
     pub asset_issuer_name: Option<String>,
-    #[serde(rename = "PARENT_ASSET_SYMBOL")]
+    #[serde(rename = "PARENT_ASSET_SYMBOL", default)]
     /// his refers to the base, parent, or main asset to which a token is linked or pegged, signifying that the token acts as a representation of the parent asset. 
     /// When a token loses its connection to a parent asset due to events such as hacks or the issuing entity's decision to not honor the peg—similar to how
     /// TerraUSD detached from its USD peg—the PARENT_ASSET_SYMBOL is removed because the token no longer serves as a true representation of the parent asset.
     /// In order to remove the parent we need clear communication from the company who is in charge of keeping the peg.
     /// We add add a plublic notice and the include the communication in the Other Document URLs.
     pub parent_asset_symbol: Option<String>,
-    #[serde(rename = "ROOT_ASSET_ID")]
+    #[serde(rename = "ROOT_ASSET_ID", default)]
     /// Identifies the root asset in a chain of derived or related assets, essential for tracing asset lineage. Null if the asset does not have a parent.
     /// For example, the fiat USD (id: 5) would be the root asset for the tokens CUSDC (id: 1623) or USDCE (id: 1728).
     pub root_asset_id: i32,
-    #[serde(rename = "ROOT_ASSET_SYMBOL")]
+    #[serde(rename = "ROOT_ASSET_SYMBOL", default)]
     /// The symbol of the root asset, facilitating recognition and correlation within asset hierarchies. Null if the asset does not have a parent.
     /// For example, the fiat USD (id: 5) would be the root asset for the tokens CUSDC (id: 1623) or USDCE (id: 1728).
     pub root_asset_symbol: String,
-    #[serde(rename = "ROOT_ASSET_TYPE")]
+    #[serde(rename = "ROOT_ASSET_TYPE", default)]
     /// Specifies the fundamental category of the root asset, crucial for understanding the base or origin of an asset's classification.
     /// Null if the asset does not have a parent. For example, the fiat USD would be the root asset for tokens CUSDC or USDCE, and in this case,
     /// the ROOT_ASSET_TYPE would be fiat.
     pub root_asset_type: String,
-    #[serde(rename = "CREATED_ON")]
+    #[serde(rename = "CREATED_ON", default)]
     /// Asset internal creation unix ts in our system.
     pub created_on: i64,
-    #[serde(rename = "UPDATED_ON")]
+    #[serde(rename = "UPDATED_ON", default)]
     /// Asset internal last updated unix ts in our system.
     pub updated_on: i64,
-    #[serde(rename = "PUBLIC_NOTICE")]
+    #[serde(rename = "PUBLIC_NOTICE", default)]
     /// A public notice for this asset.
     pub public_notice: Option<String>,
-    #[serde(rename = "NAME")]
+    #[serde(rename = "NAME", default)]
     /// The full name of the asset, e.g. Bitcoin.
     pub name: String,
-    #[serde(rename = "LOGO_URL")]
+    #[serde(rename = "LOGO_URL", default)]
     /// The image that appears when you see this asset.
     pub logo_url: String,
-    #[serde(rename = "LAUNCH_DATE")]
+    #[serde(rename = "LAUNCH_DATE", default)]
     /// The launch date of the asset is indicated as (yyyy-mm-dd). However, if the asset was initially established as a token before being integrated into
     /// a blockchain, the launch date is reset to the creation of the first block when the blockchain is launched for the token.
     pub launch_date: i64,
-    #[serde(rename = "PERIOUS_ASSET_SYMBOLS")]
+    #[serde(rename = "PERIOUS_ASSET_SYMBOLS", default, deserialize_with = "crate::schemas::empty_object_as_none")]
     /// A list of symbols that were previously associated with this asset.
     pub previous_asset_symbols: Option<Vec<PreviousAssetSymbol>>,
-    #[serde(rename = "ASSET_ALTERNATIVE_IDS")]
+    #[serde(rename = "ASSET_ALTERNATIVE_IDS", default, deserialize_with = "crate::schemas::empty_object_as_none")]
     /// Alternative data platforms that also support this asset with their specific asset id.
     pub asset_laternative_ids: Option<Vec<AssetAlternativeId>>,
-    #[serde(rename = "ASSET_DESCRIPTION_SNIPPET")]
+    #[serde(rename = "ASSET_DESCRIPTION_SNIPPET", default)]
     /// The shortest form description text only for this asset. This is a lot more limited than the summary. Generally this is a one or maximum two sentences.
     pub asset_description_snippet: Option<String>,
-    #[serde(rename = "SUPPORTED_PLATFORMS")]
+    #[serde(rename = "SUPPORTED_PLATFORMS", default, deserialize_with = "crate::schemas::empty_object_as_none")]
     /// When an asset (token, fiat, cryptocurrency, commodity) can be used on multiple blockchains, we refer to those blockchains as supported platforms.
     /// There are two types of assets that can be used on multiple blockchains: native tokens and bridged tokens.
     /// Native tokens are assets that are natively supported by a particular blockchain, while bridged tokens are assets that are "bridged" or "pegged"
     /// to another asset on a different blockchain.
     pub supported_platforms: Option<Vec<OCCoreSupportedPlatforms>>,
-    #[serde(rename = "ASSET_SECURITY_METRICS")]
+    #[serde(rename = "ASSET_SECURITY_METRICS", default, deserialize_with = "crate::schemas::empty_object_as_none")]
     pub asset_security_metrics: Option<Vec<OCCoreSecurityMetric>>,
-    #[serde(rename = "SUPPLY_MAX")]
+    #[serde(rename = "SUPPLY_MAX", default)]
     /// The maximum number of asset parts (coins/tokens) that will ever be issued (supply_circulating + supply_burnt + supply_locked + all supply that
     /// has not been issued yet but is planned to be issued in the future). For assets (coins/tokens) that have infinite supply, we use -1.
-    pub supply_max: f64,
-    #[serde(rename = "SUPPLY_ISSUED")]
+    pub supply_max: Numeric,
+    #[serde(rename = "SUPPLY_ISSUED", default)]
     /// The number of asset parts (coins/tokens) that have been issued so far. (supply_circulating + supply_locked + supply_burnt).
-    pub supply_issued: Option<f64>,
-    #[serde(rename = "SUPPLY_TOTAL")]
+    pub supply_issued: Option<Numeric>,
+    #[serde(rename = "SUPPLY_TOTAL", default)]
     /// The number of asset parts (coins/tokens) that have been issued so far excluding burnt tokens. (supply_circulating + supply_locked).
-    pub supply_total: Option<f64>,
-    #[serde(rename = "SUPPLY_CIRCULATING")]
+    pub supply_total: Option<Numeric>,
+    #[serde(rename = "SUPPLY_CIRCULATING", default)]
     /// Also referred to as free float or public float. The number of asset parts (coins/tokens) that are available to be traded and it
     /// excludes burnt supply and locked supply.
-    pub supply_circulating: Option<f64>,
-    #[serde(rename = "SUPPLY_FUTURE")]
+    pub supply_circulating: Option<Numeric>,
+    #[serde(rename = "SUPPLY_FUTURE", default)]
     /// The number of asset parts (coins/tokens) that are planned to be issued in the future. (supply_max - supply_issued).
-    pub supply_future: f64,
-    #[serde(rename = "SUPPLY_LOCKED")]
+    pub supply_future: Numeric,
+    #[serde(rename = "SUPPLY_LOCKED", default)]
     /// The number of asset parts (coins/tokens) that are currently not transferable until certain conditions are met.
     /// Locked supply is generally held by team members, DAOs, foundations, bridges, stakers, liquidity pools, etc.
-    pub supply_locked: Option<f64>,
-    #[serde(rename = "SUPPY_BURNT")]
+    pub supply_locked: Option<Numeric>,
+    #[serde(rename = "SUPPY_BURNT", default)]
     /// The number of asset parts (coins/tokens) that have been sent to addresses/locations that are no longer accessible.
     /// They are permanently removed from the circulating supply on purpose, this does not include lost tokens sent to wallets that do not exist or
     // sent to wallets that users no longer have access to, the address of burnt tokens is determined by the project team.
-    pub supply_burnt: Option<f64>,
-    #[serde(rename = "SUPPLY_STAKED")]
+    pub supply_burnt: Option<Numeric>,
+    #[serde(rename = "SUPPLY_STAKED", default)]
     /// The current number of asset parts (coins/tokens) that are locked as part of PoS and PoS partial chains.
-    pub supply_staked: Option<f64>,
-    #[serde(rename = "BURN_ADDRESSES")]
+    pub supply_staked: Option<Numeric>,
+    #[serde(rename = "BURN_ADDRESSES", default, deserialize_with = "crate::schemas::empty_object_as_none")]
     /// The list of addresses that are considered burn addresses for this asset.
     pub burn_addresses: Option<Vec<SpecialAddress>>,
-    #[serde(rename = "LOCKED_ADDRESSES")]
+    #[serde(rename = "LOCKED_ADDRESSES", default, deserialize_with = "crate::schemas::empty_object_as_none")]
     /// The list of addresses that are considered locked addresses for this asset.
     pub locked_addresses: Option<Vec<SpecialAddress>>,
-    #[serde(rename = "RESERVES_BREAKDOWN")]
+    #[serde(rename = "RESERVES_BREAKDOWN", default, deserialize_with = "crate::schemas::empty_object_as_none")]
     /// A detailed breakdown of reserves backing a stablecoin asset, covering various categories such as cash, cryptocurrency, bonds, and
     /// other investments for transparency.
     pub reserves_breakdown: Option<Vec<OCCoreReservesBreakdown>>,
-    #[serde(rename = "WEBSITE_URL")]
+    #[serde(rename = "WEBSITE_URL", default)]
     /// The link for the official project website.
     pub website_url: Option<String>,
-    #[serde(rename = "BLOG_URL")]
+    #[serde(rename = "BLOG_URL", default)]
     /// The link for the official blog.
     pub blog_url: Option<String>,
-    #[serde(rename = "WHITE_PAPER_URL")]
+    #[serde(rename = "WHITE_PAPER_URL", default)]
     /// A white paper, also written as "whitepaper", a document released by the project that gives investors technical information about its concept,
     /// its purpose, how it works, etc.
     pub white_paper_url: Option<String>,
-    #[serde(rename = "OTHER_DOCUMENT_URLS")]
+    #[serde(rename = "OTHER_DOCUMENT_URLS", default, deserialize_with = "crate::schemas::empty_object_as_none")]
     /// Other documents that might be relevant to the asset, they should all be .PDFs.
     pub other_document_urls: Option<Vec<OCCoreDocumentURLs>>,
-    #[serde(rename = "ASSET_INDUSTRIES")]
+    #[serde(rename = "ASSET_INDUSTRIES", default, deserialize_with = "crate::schemas::empty_object_as_none")]
     /// The asset industries that asset operates in.
     pub asset_industries: Option<Vec<AssetIndustry>>,
-    #[serde(rename = "PRICE_USD")]
+    #[serde(rename = "PRICE_USD", default)]
     /// Denotes the current value / price of the asset in USD. This value is used to populate toplists and is calculated based on real-time market data.
-    pub price_usd: f64,
-    #[serde(rename = "PRICE_USD_SOURCE")]
+    pub price_usd: Numeric,
+    #[serde(rename = "PRICE_USD_SOURCE", default)]
     /// Denotes the source we used for the USD value / price.
     pub price_usd_source: String,
-    #[serde(rename = "PRICE_USD_LAST_UPDATE_TS")]
+    #[serde(rename = "PRICE_USD_LAST_UPDATE_TS", default)]
     /// The Unix timestamp of the last update to the USD value / price of the asset.
     pub price_usd_last_update_ts: i64,
-    #[serde(rename = "PRICE_CONVERSION_ASSET")]
+    #[serde(rename = "PRICE_CONVERSION_ASSET", default, deserialize_with = "crate::schemas::empty_object_as_none")]
     /// Denotes the convesion asset ID, SYMBOL and ASSET_TYPE.
     pub price_conversion_asset: Option<OCCorePriceConversionAsset>,
-    #[serde(rename = "PRICE_CONVERSION_RATE")]
+    #[serde(rename = "PRICE_CONVERSION_RATE", default)]
     /// Denotes the current value / price of USD in the requested quote asset.
-    pub price_conversion_rate: Option<f64>,
-    #[serde(rename = "PRICE_CONVERSION_VALUE")]
+    pub price_conversion_rate: Option<Numeric>,
+    #[serde(rename = "PRICE_CONVERSION_VALUE", default)]
     /// Denotes the current value / price of the asset in the requested quote currency.
-    pub price_conversion_value: Option<f64>,
-    #[serde(rename = "PRICE_CONVERSION_SOURCE")]
+    pub price_conversion_value: Option<Numeric>,
+    #[serde(rename = "PRICE_CONVERSION_SOURCE", default)]
     /// Denotes the source we used for the conversion asset value / price.
     pub price_conversion_source: Option<String>,
-    #[serde(rename = "PRICE_CONVERSION_LAST_UPDATE_TS")]
+    #[serde(rename = "PRICE_CONVERSION_LAST_UPDATE_TS", default)]
     /// The Unix timestamp of the last update to the conversion asset value / price.
     pub price_conversion_last_update_ts: Option<i64>,
-    #[serde(rename = "MKT_CAP_PENALTY")]
+    #[serde(rename = "MKT_CAP_PENALTY", default)]
     /// The total penalty applied to the mkt cap due to liquidity or quality of data. Comment example: The value is reduced to 0.01% of the original due to
     /// low volume on B+ ranked exchanges or because it is only trading on a limited number of exchanges.
     pub mkt_cap_penalty: Option<f64>,
-    #[serde(rename = "CIRCULATING_MKT_CAP_USD")]
+    #[serde(rename = "CIRCULATING_MKT_CAP_USD", default)]
     /// Calculated as the product of the asset's circulating supply and its current price quoted in USD (we sometimes apply a MKT_CAP_PENALTY depending on
     /// liquidity conditions). This is used to rank assets in toplists based on their circulating market capitalization.
-    pub circulating_mkt_cap_usd: f64,
-    #[serde(rename = "TOTAL_MKT_CAP_USD")]
+    pub circulating_mkt_cap_usd: Numeric,
+    #[serde(rename = "TOTAL_MKT_CAP_USD", default)]
     /// Calculated as the product of the asset's total supply and its current price quoted in USD (we sometimes apply a MKT_CAP_PENALTY depending on
     /// liquidity conditions). This figure gives an overview of the total value of all issued tokens for a given asset.
-    pub total_mkt_cap_usd: f64,
-    #[serde(rename = "CIRCULATING_MKT_CAP_CONVERSION")]
+    pub total_mkt_cap_usd: Numeric,
+    #[serde(rename = "CIRCULATING_MKT_CAP_CONVERSION", default)]
     /// Calculated as the product of the asset's circulating supply and its current price quoted in conversion asset (we sometimes apply a MKT_CAP_PENALTY
     /// depending on liquidity conditions). We calculate this field by multiplying the CIRCULATING_MKT_CAP_USD and the PRICE_CONVERSION_VALUE.
-    pub circulating_mkt_cap_conversion: Option<f64>,
-    #[serde(rename = "TOTAL_MKT_CAP_CONVERSION")]
+    pub circulating_mkt_cap_conversion: Option<Numeric>,
+    #[serde(rename = "TOTAL_MKT_CAP_CONVERSION", default)]
     /// Calculated as the product of the asset's total supply and its current price quoted in conversion asset (we sometimes apply a MKT_CAP_PENALTY
     /// depending on liquidity conditions). We calculate this field by multiplying the TOTAL_MKT_CAP_USD and the PRICE_CONVERSION_VALUE.
-    pub total_mkt_cap_conversion: Option<f64>,
-    #[serde(rename = "SPOT_MOVING_24_HOUR_QUOTE_VOLUME_TOP_TIER_DIRECT_USD")]
+    pub total_mkt_cap_conversion: Option<Numeric>,
+    #[serde(rename = "SPOT_MOVING_24_HOUR_QUOTE_VOLUME_TOP_TIER_DIRECT_USD", default)]
     /// Aggregated 24 hours volume of the asset traded accross instruments that are quoted in USD on spot markets that we consider top tier, quoted in USD.
     /// Provides insights into the USD liquidity and trading activity of the asset on top tier spot markets.
-    pub spot_moving_24_hour_quote_volume_top_tier_direct_usd: f64,
-    #[serde(rename = "SPOT_MOVING_24_HOUR_QUOTE_VOLUME_DIRECT_USD")]
+    pub spot_moving_24_hour_quote_volume_top_tier_direct_usd: Numeric,
+    #[serde(rename = "SPOT_MOVING_24_HOUR_QUOTE_VOLUME_DIRECT_USD", default)]
     /// Aggregated 24 hours volume of the asset traded accross instruments that are quoted in USD on all the intergarted spot markets, quoted in USD.
     /// Provides insights into the USD liquidity and trading activity of the asset.
-    pub spot_moving_24_hour_quote_volume_direct_usd: f64,
-    #[serde(rename = "SPOT_MOVING_24_HOUR_QUOTE_VOLUME_TOP_TIER_USD")]
+    pub spot_moving_24_hour_quote_volume_direct_usd: Numeric,
+    #[serde(rename = "SPOT_MOVING_24_HOUR_QUOTE_VOLUME_TOP_TIER_USD", default)]
     /// Aggregated 24 hours volume of the asset traded accross all instruments on spot markets that we consider top tier, quoted in USD.
     /// This is the sum of all the trade volumes on all the spot top tier markets, every trade volume is converted to USD using the most accuarte
     /// conversion price at the time of the trade. These markets meet specific quality criteria, offering a more curated view of trading activity.
-    pub spot_moving_24_hour_quote_volume_top_tier_usd: f64,
-    #[serde(rename = "SPOT_MOVING_24_HOUR_QUOTE_VOLUME_USD")]
+    pub spot_moving_24_hour_quote_volume_top_tier_usd: Numeric,
+    #[serde(rename = "SPOT_MOVING_24_HOUR_QUOTE_VOLUME_USD", default)]
     /// Aggregated 24 hours volume of the asset traded accross all instruments on all the integrated spot markets, quoted in USD.
     /// This is the sum of all the trade volumes on all the spot markets, every trade volume is converted to USD using the most accuarte conversion
     /// price at the time of the trade. Useful for understanding demand and trading interest.
-    pub spot_moving_24_hour_quote_volume_usd: f64,
-    #[serde(rename = "SPOT_MOVING_24_HOUR_QUOTE_VOLUME_TOP_TIER_CONVERSION")]
+    pub spot_moving_24_hour_quote_volume_usd: Numeric,
+    #[serde(rename = "SPOT_MOVING_24_HOUR_QUOTE_VOLUME_TOP_TIER_CONVERSION", default)]
     /// Aggregated 24 hours volume of the asset traded accross all instruments on spot markets that we consider top tier, quoted in conversion asset.
     /// We calculate this field by multiplying the SPOT_MOVING_24_HOUR_QUOTE_VOLUME_TOP_TIER_USD and the PRICE_CONVERSION_VALUE.
-    pub spot_moving_24_hour_quote_volume_top_tier_conversion: Option<f64>,
-    #[serde(rename = "SPOT_MOVING_24_HOUR_QUOTE_VOLUME_CONVERSION")]
+    pub spot_moving_24_hour_quote_volume_top_tier_conversion: Option<Numeric>,
+    #[serde(rename = "SPOT_MOVING_24_HOUR_QUOTE_VOLUME_CONVERSION", default)]
     /// Aggregated 24 hours volume of the asset traded accross all instruments on all the integrated spot markets.
     /// We calculate this field by multiplying the SPOT_MOVING_24_HOUR_QUOTE_VOLUME_USD and the PRICE_CONVERSION_VALUE.
-    pub spot_moving_24_hour_quote_volume_conversion: Option<f64>,
-    #[serde(rename = "SPOT_MOVING_7_DAY_QUOTE_VOLUME_TOP_TIER_DIRECT_USD")]
+    pub spot_moving_24_hour_quote_volume_conversion: Option<Numeric>,
+    #[serde(rename = "SPOT_MOVING_7_DAY_QUOTE_VOLUME_TOP_TIER_DIRECT_USD", default)]
     /// Aggregated 7 days volume of the asset traded accross instruments that are quoted in USD on spot markets that we consider top tier, quoted in USD.
     /// Provides insights into the USD liquidity and trading activity of the asset on top tier spot markets.
-    pub spot_moving_7_day_quote_volume_top_tier_direct_usd: f64,
-    #[serde(rename = "SPOT_MOVING_7_DAY_QUOTE_VOLUME_DIRECT_USD")]
+    pub spot_moving_7_day_quote_volume_top_tier_direct_usd: Numeric,
+    #[serde(rename = "SPOT_MOVING_7_DAY_QUOTE_VOLUME_DIRECT_USD", default)]
     /// Aggregated 7 days volume of the asset traded accross instruments that are quoted in USD on all the intergarted spot markets, quoted in USD.
     /// Provides insights into the USD liquidity and trading activity of the asset.
-    pub spot_moving_7_day_quote_volume_direct_usd: f64,
-    #[serde(rename = "SPOT_MOVING_7_DAY_QUOTE_VOLUME_TOP_TIER_USD")]
+    pub spot_moving_7_day_quote_volume_direct_usd: Numeric,
+    #[serde(rename = "SPOT_MOVING_7_DAY_QUOTE_VOLUME_TOP_TIER_USD", default)]
     /// Aggregated 7 days volume of the asset traded accross all instruments on spot markets that we consider top tier, quoted in USD.
     /// This is the sum of all the trade volumes on all the spot top tier markets, every trade volume is converted to USD using the most accuarte
     /// conversion price at the time of the trade. These markets meet specific quality criteria, offering a more curated view of trading activity.
-    pub spot_moving_7_day_quote_volume_top_tier_usd: f64,
-    #[serde(rename = "SPOT_MOVING_7_DAY_QUOTE_VOLUME_USD")]
+    pub spot_moving_7_day_quote_volume_top_tier_usd: Numeric,
+    #[serde(rename = "SPOT_MOVING_7_DAY_QUOTE_VOLUME_USD", default)]
     /// Aggregated 7 days volume of the asset traded accross all instruments on all the integrated spot markets, quoted in USD.
     /// This is the sum of all the trade volumes on all the spot markets, every trade volume is converted to USD using the most accuarte conversion
     /// price at the time of the trade. Useful for understanding demand and trading interest.
-    pub spot_moving_7_day_quote_volume_usd: f64,
-    #[serde(rename = "SPOT_MOVING_7_DAY_QUOTE_VOLUME_TOP_TIER_CONVERSION")]
+    pub spot_moving_7_day_quote_volume_usd: Numeric,
+    #[serde(rename = "SPOT_MOVING_7_DAY_QUOTE_VOLUME_TOP_TIER_CONVERSION", default)]
     /// Aggregated 7 days volume of the asset traded accross all instruments on spot markets that we consider top tier, quoted in conversion asset.
     /// We calculate this field by multiplying the SPOT_MOVING_7_DAY_QUOTE_VOLUME_TOP_TIER_USD and the PRICE_CONVERSION_VALUE.
-    pub spot_moving_7_day_quote_volume_top_tier_conversion: Option<f64>,
-    #[serde(rename = "SPOT_MOVING_7_DAY_QUOTE_VOLUME_CONVERSION")]
+    pub spot_moving_7_day_quote_volume_top_tier_conversion: Option<Numeric>,
+    #[serde(rename = "SPOT_MOVING_7_DAY_QUOTE_VOLUME_CONVERSION", default)]
     /// Aggregated 7 days volume of the asset traded accross all instruments on all the integrated spot markets.
     /// We calculate this field by multiplying the SPOT_MOVING_7_DAY_QUOTE_VOLUME_USD and the PRICE_CONVERSION_VALUE.
-    pub spot_moving_7_day_quote_volume_conversion: Option<f64>,
-    #[serde(rename = "SPOT_MOVING_30_DAY_QUOTE_VOLUME_TOP_TIER_DIRECT_USD")]
+    pub spot_moving_7_day_quote_volume_conversion: Option<Numeric>,
+    #[serde(rename = "SPOT_MOVING_30_DAY_QUOTE_VOLUME_TOP_TIER_DIRECT_USD", default)]
     /// Aggregated 30 days volume of the asset traded accross instruments that are quoted in USD on spot markets that we consider top tier, quoted in USD.
     /// Provides insights into the USD liquidity and trading activity of the asset on top tier spot markets.
-    pub spot_moving_30_day_quote_volume_top_tier_direct_usd: f64,
-    #[serde(rename = "SPOT_MOVING_30_DAY_QUOTE_VOLUME_DIRECT_USD")]
+    pub spot_moving_30_day_quote_volume_top_tier_direct_usd: Numeric,
+    #[serde(rename = "SPOT_MOVING_30_DAY_QUOTE_VOLUME_DIRECT_USD", default)]
     /// Aggregated 30 days volume of the asset traded accross instruments that are quoted in USD on all the intergarted spot markets, quoted in USD.
     /// Provides insights into the USD liquidity and trading activity of the asset.
-    pub spot_moving_30_day_quote_volume_direct_usd: f64,
-    #[serde(rename = "SPOT_MOVING_30_DAY_QUOTE_VOLUME_TOP_TIER_USD")]
+    pub spot_moving_30_day_quote_volume_direct_usd: Numeric,
+    #[serde(rename = "SPOT_MOVING_30_DAY_QUOTE_VOLUME_TOP_TIER_USD", default)]
     /// Aggregated 30 days volume of the asset traded accross all instruments on spot markets that we consider top tier, quoted in USD.
     /// This is the sum of all the trade volumes on all the spot top tier markets, every trade volume is converted to USD using the most accuarte
     /// conversion price at the time of the trade. These markets meet specific quality criteria, offering a more curated view of trading activity.
-    pub spot_moving_30_day_quote_volume_top_tier_usd: f64,
-    #[serde(rename = "SPOT_MOVING_30_DAY_QUOTE_VOLUME_USD")]
+    pub spot_moving_30_day_quote_volume_top_tier_usd: Numeric,
+    #[serde(rename = "SPOT_MOVING_30_DAY_QUOTE_VOLUME_USD", default)]
     /// Aggregated 30 days volume of the asset traded accross all instruments on all the integrated spot markets, quoted in USD.
     /// This is the sum of all the trade volumes on all the spot markets, every trade volume is converted to USD using the most accuarte conversion
     /// price at the time of the trade. Useful for understanding demand and trading interest.
-    pub spot_moving_30_day_quote_volume_usd: f64,
-    #[serde(rename = "SPOT_MOVING_30_DAY_QUOTE_VOLUME_TOP_TIER_CONVERSION")]
+    pub spot_moving_30_day_quote_volume_usd: Numeric,
+    #[serde(rename = "SPOT_MOVING_30_DAY_QUOTE_VOLUME_TOP_TIER_CONVERSION", default)]
     /// Aggregated 30 days volume of the asset traded accross all instruments on spot markets that we consider top tier, quoted in conversion asset.
     /// We calculate this field by multiplying the SPOT_MOVING_30_DAY_QUOTE_VOLUME_TOP_TIER_USD and the PRICE_CONVERSION_VALUE.
-    pub spot_moving_30_day_quote_volume_top_tier_conversion: Option<f64>,
-    #[serde(rename = "SPOT_MOVING_30_DAY_QUOTE_VOLUME_CONVERSION")]
+    pub spot_moving_30_day_quote_volume_top_tier_conversion: Option<Numeric>,
+    #[serde(rename = "SPOT_MOVING_30_DAY_QUOTE_VOLUME_CONVERSION", default)]
     /// Aggregated 30 days volume of the asset traded accross all instruments on all the integrated spot markets.
     /// We calculate this field by multiplying the SPOT_MOVING_30_DAY_QUOTE_VOLUME_USD and the PRICE_CONVERSION_VALUE.
-    pub spot_moving_30_day_quote_volume_conversion: Option<f64>,
-    #[serde(rename = "SPOT_MOVING_24_HOUR_CHANGE_USD")]
+    pub spot_moving_30_day_quote_volume_conversion: Option<Numeric>,
+    #[serde(rename = "SPOT_MOVING_24_HOUR_CHANGE_USD", default)]
     /// The difference between the value of the asset quoted in USD 24 hours ago and the latest USD price.
-    pub spot_moving_24_hour_change_usd: f64,
-    #[serde(rename = "SPOT_MOVING_24_HOUR_CHANGE_PERCENTAGE_USD")]
+    pub spot_moving_24_hour_change_usd: Numeric,
+    #[serde(rename = "SPOT_MOVING_24_HOUR_CHANGE_PERCENTAGE_USD", default)]
     /// The percentage difference between the value of the asset quoted in USD 24 hours ago and the latest USD price.
     pub spot_moving_24_hour_change_percentage_usd: f64,
-    #[serde(rename = "SPOT_MOVING_24_HOUR_CHANGE_CONVERSION")]
+    #[serde(rename = "SPOT_MOVING_24_HOUR_CHANGE_CONVERSION", default)]
     /// The approximate difference between the value of the asset quoted conversion currency 23 hours ago and now.
     /// We calculate this field by multiplying the SPOT_MOVING_24_HOUR_CHANGE_USD and the PRICE_CONVERSION_VALUE.
     /// This figure does not take into account the volatility of the conversion asset quoted in USD.
-    pub spot_moving_24_hour_change_conversion: Option<f64>,
-    #[serde(rename = "SPOT_MOVING_24_HOUR_CHANGE_PERCENTAGE_CONVERSION")]
+    pub spot_moving_24_hour_change_conversion: Option<Numeric>,
+    #[serde(rename = "SPOT_MOVING_24_HOUR_CHANGE_PERCENTAGE_CONVERSION", default)]
     /// The percentage difference between the value of the asset quoted in USD 24 hours ago and the latest USD price.
     /// This is the same as the SPOT_MOVING_24_HOUR_CHANGE_PERCENTAGE_USD just adding it here for easier access.
     pub spot_moving_24_hour_change_percentage_conversion: Option<f64>,
-    #[serde(rename = "SPOT_MOVING_7_DAY_CHANGE_USD")]
+    #[serde(rename = "SPOT_MOVING_7_DAY_CHANGE_USD", default)]
     /// The difference between the value of the asset quoted in USD 7 days ago and the latest USD price.
-    pub spot_moving_7_day_change_usd: f64,
-    #[serde(rename = "SPOT_MOVING_7_DAY_CHANGE_PERCENTAGE_USD")]
+    pub spot_moving_7_day_change_usd: Numeric,
+    #[serde(rename = "SPOT_MOVING_7_DAY_CHANGE_PERCENTAGE_USD", default)]
     /// The percentage difference between the value of the asset quoted in USD 7 days ago and the latest USD price.
     pub spot_moving_7_day_change_percentage_usd: f64,
-    #[serde(rename = "SPOT_MOVING_7_DAY_CHANGE_CONVERSION")]
+    #[serde(rename = "SPOT_MOVING_7_DAY_CHANGE_CONVERSION", default)]
     /// The approximate difference between the value of the asset quoted conversion currency 6 days ago and current day.
     /// We calculate this field by multiplying the SPOT_MOVING_7_DAY_CHANGE_USD and the PRICE_CONVERSION_VALUE.
     /// This figure does not take into account the volatility of the conversion asset quoted in USD.
-    pub spot_moving_7_day_change_conversion: Option<f64>,
-    #[serde(rename = "SPOT_MOVING_7_DAY_CHANGE_PERCENTAGE_CONVERSION")]
+    pub spot_moving_7_day_change_conversion: Option<Numeric>,
+    #[serde(rename = "SPOT_MOVING_7_DAY_CHANGE_PERCENTAGE_CONVERSION", default)]
     /// The percentage difference between the value of the asset quoted in USD 7 days ago and the latest USD price.
     /// This is the same as the SPOT_MOVING_7_DAY_CHANGE_PERCENTAGE_USD just adding it here for easier access.
     pub spot_moving_7_day_change_percentage_conversion: Option<f64>,
-    #[serde(rename = "SPOT_MOVING_30_DAY_CHANGE_USD")]
+    #[serde(rename = "SPOT_MOVING_30_DAY_CHANGE_USD", default)]
     /// The difference between the value of the asset quoted in USD 30 days ago and the latest USD price.
-    pub spot_moving_30_day_change_usd: f64,
-    #[serde(rename = "SPOT_MOVING_30_DAY_CHANGE_PERCENTAGE_USD")]
+    pub spot_moving_30_day_change_usd: Numeric,
+    #[serde(rename = "SPOT_MOVING_30_DAY_CHANGE_PERCENTAGE_USD", default)]
     /// The percentage difference between the value of the asset quoted in USD 30 days ago and the latest USD price.
     pub spot_moving_30_day_change_percentage_usd: f64,
-    #[serde(rename = "SPOT_MOVING_30_DAY_CHANGE_CONVERSION")]
+    #[serde(rename = "SPOT_MOVING_30_DAY_CHANGE_CONVERSION", default)]
     /// The approximate difference between the value of the asset quoted conversion currency 29 days ago and current day.
     /// We calculate this field by multiplying the SPOT_MOVING_30_DAY_CHANGE_USD and the PRICE_CONVERSION_VALUE.
     /// This figure does not take into account the volatility of the conversion asset quoted in USD.
-    pub spot_moving_30_day_change_conversion: Option<f64>,
-    #[serde(rename = "SPOT_MOVING_30_DAY_CHANGE_PERCENTAGE_CONVERSION")]
+    pub spot_moving_30_day_change_conversion: Option<Numeric>,
+    #[serde(rename = "SPOT_MOVING_30_DAY_CHANGE_PERCENTAGE_CONVERSION", default)]
     /// The percentage difference between the value of the asset quoted in USD 30 days ago and the latest USD price.
     /// This is the same as the SPOT_MOVING_30_DAY_CHANGE_PERCENTAGE_USD just adding it here for easier access.
     pub spot_moving_30_day_change_percentage_conversion: Option<f64>,
-    #[serde(rename = "TOPLIST_BASE_RANK")]
+    #[serde(rename = "TOPLIST_BASE_RANK", default, deserialize_with = "crate::schemas::empty_object_as_none")]
     /// Indicates the asset's position in our global asset ranking. There are multiple ranks, each is determined by at least one factor,
     /// such as asset launch date, market cap, trading volume, etc...
     pub toplist_base_rank: Option<OCCoreToplistRank>,
-    #[serde(rename = "ASSET_DESCRIPTION")]
+    #[serde(rename = "ASSET_DESCRIPTION", default)]
     /// The long form description in markdown for this asset.
     pub asset_description: String,
-    #[serde(rename = "ASSET_DESCRIPTION_SUMMARY")]
+    #[serde(rename = "ASSET_DESCRIPTION_SUMMARY", default)]
     /// The short form description text only for this asset.
     pub asset_description_summary: String,
-    #[serde(rename = "PROJECT_LEADERS")]
+    #[serde(rename = "PROJECT_LEADERS", default, deserialize_with = "crate::schemas::empty_object_as_none")]
     /// An array of individuals that contains founders, whitepaper authors, investors, resechers, and other important people related to a project.
     pub project_leaders: Option<Vec<OCCoreProjectLeader>>,
-    #[serde(rename = "ASSOCIATED_CONTACT_DETAILS")]
+    #[serde(rename = "ASSOCIATED_CONTACT_DETAILS", default, deserialize_with = "crate::schemas::empty_object_as_none")]
     /// Associated contact addresses for the team members, employees or affiliated contacts.
     pub associated_contact_details: Option<OCCoreContactDetails>,
-    #[serde(rename = "SEO_TITLE")]
+    #[serde(rename = "SEO_TITLE", default)]
     /// The title that appears when you post this page on social media.
     pub seo_title: String,
-    #[serde(rename = "SEO_DESCRIPTION")]
+    #[serde(rename = "SEO_DESCRIPTION", default)]
     /// The description that appears when you post this page on social media.
     pub seo_description: String, 
 }
@@ -1082,30 +1083,30 @@ pub struct OCCoreSupply {
     #[serde(rename = "SUPPLY_CIRCULATING")]
     /// Also referred to as free float or public float. The number of asset parts (coins/tokens) that are available to be traded and it excludes burnt
     /// supply and locked supply.
-    pub supply_circulating: Option<f64>,
+    pub supply_circulating: Option<Numeric>,
     #[serde(rename = "SUPPLY_TOTAL")]
     /// The number of asset parts (coins/tokens) that have been issued so far excluding burnt tokens. (supply_circulating + supply_locked).
-    pub supply_total: Option<f64>,
+    pub supply_total: Option<Numeric>,
     #[serde(rename = "SUPPLY_BURNT")]
     /// The number of asset parts (coins/tokens) that have been sent to addresses/locations that are no longer accessible.
     /// They are permanently removed from the circulating supply on purpose, this does not include lost tokens sent to wallets that do not exist
     /// or sent to wallets that users no longer have access to, the address of burnt tokens is determined by the project team.
-    pub supply_burnt: Option<f64>,
+    pub supply_burnt: Option<Numeric>,
     #[serde(rename = "SUPPLY_MAX")]
     /// The maximum number of asset parts (coins/tokens) that will ever be issued (supply_circulating + supply_burnt + supply_locked + all supply that
     /// has not been issued yet but is planned to be issued in the future). For assets (coins/tokens) that have infinite supply, we use -1.
-    pub supply_max: Option<f64>,
+    pub supply_max: Option<Numeric>,
     #[serde(rename = "SUPPLY_STAKED")]
     /// The current number of asset parts (coins/tokens) that are locked as part of PoS and PoS partial chains.
-    pub supply_staked: Option<f64>,
+    pub supply_staked: Option<Numeric>,
     #[serde(rename = "SUPPLY_FUTURE")]
     /// The number of asset parts (coins/tokens) that are planned to be issued in the future. (supply_max - supply_issued).
-    pub supply_future: Option<f64>,
+    pub supply_future: Option<Numeric>,
     #[serde(rename = "SUPPLY_ISSUED")]
     /// The number of asset parts (coins/tokens) that have been issued so far. (supply_circulating + supply_locked + supply_burnt).
-    pub supply_issued: Option<f64>,
+    pub supply_issued: Option<Numeric>,
     #[serde(rename = "SUPPLY_LOCKED")]
     /// The number of asset parts (coins/tokens) that are currently not transferable until certain conditions are met. Locked supply is generally held by
     /// team members, DAOs, foundations, bridges, stakers, liquidity pools, etc.
-    pub supply_locked: Option<f64>,
+    pub supply_locked: Option<Numeric>,
 }
\ No newline at end of file
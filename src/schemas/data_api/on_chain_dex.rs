@@ -1,6 +1,7 @@
 use std::fmt::Display;
 use serde::{Serialize, Deserialize};
 use crate::schemas::data_api::InstrumentStatus;
+use crate::schemas::Numeric;
 
 
 #[derive(Clone, Copy, Debug, Default, Serialize, Deserialize)]
@@ -63,112 +64,112 @@ pub struct OCDEXOHLCV {
     #[serde(rename = "INSTRUMENT")]
     /// The unmapped instrument address and the CHAIN_ASSET ID separated by underscore (e.g. 0xe83c76c50033a5396d21ceff9fa192e2550d10ca_2, etc.).
     pub instrument: String,
-    #[serde(rename = "MAPPED_INSTRUMENT")]
+    #[serde(rename = "MAPPED_INSTRUMENT", default)]
     /// The instrument id based on asset symbols, as derived from our mapping rules. This is the same as the INSTRUMENT and will not change.
     pub mapped_instrument: String,
-    #[serde(rename = "BASE")]
+    #[serde(rename = "BASE", default)]
     /// Represents the base asset or coin symbol, commonly known as the ticker (e.g., BTC). This symbol may change in cases of asset rebranding.
     /// Applicable only to instruments with a mapping.
     pub base: String,
-    #[serde(rename = "QUOTE")]
+    #[serde(rename = "QUOTE", default)]
     /// Represents the quote asset or counter coin symbol, commonly known as the ticker (e.g., USD). This symbol may change in cases of asset rebranding.
     /// Applicable only to instruments with a mapping.
     pub quote: String,
-    #[serde(rename = "BASE_ID")]
+    #[serde(rename = "BASE_ID", default)]
     /// Represents the internal CoinDesk ID for the base asset or coin (e.g., 1 for BTC). This ID is unique and immutable, ensuring consistent identification.
     /// Applicable only to instruments with a mapping.
     pub base_id: i32,
-    #[serde(rename = "QUOTE_ID")]
+    #[serde(rename = "QUOTE_ID", default)]
     /// Represents the internal CoinDesk ID for the quote asset or counter coin (e.g., 5 for USD). This ID is unique and immutable, ensuring consistent identification.
     /// Applicable only to instruments with a mapping.
     pub quote_id: i32,
-    #[serde(rename = "TRANSFORM_FUNCTION")]
+    #[serde(rename = "TRANSFORM_FUNCTION", default)]
     /// The transform function (or list of functions concatenated by _AND_). This is the function we apply when we do mapping to change values into easier human
     /// readable ones and to make sure the mapped direction BASE - QUOTE is constant accross all chains and instruments.
     pub transform_function: String,
-    #[serde(rename = "OPEN")]
+    #[serde(rename = "OPEN", default)]
     /// The open price for the historical period, this is based on the closest swap before the period start.
-    pub open: f64,
-    #[serde(rename = "HIGH")]
+    pub open: Numeric,
+    #[serde(rename = "HIGH", default)]
     /// The highest swap price in the time period. If there were no swaps in the time period, the open price will be given.
-    pub high: f64,
-    #[serde(rename = "LOW")]
+    pub high: Numeric,
+    #[serde(rename = "LOW", default)]
     /// The lowest swap price in the time period. If there were no swaps in the time period, the open price will be given.
-    pub low: f64,
-    #[serde(rename = "CLOSE")]
+    pub low: Numeric,
+    #[serde(rename = "CLOSE", default)]
     /// The price of the last swap in this time period. If there were no swaps in the time period, the open price will be given.
-    pub close: f64,
-    #[serde(rename = "FIRST_SWAP_TIMESTAMP")]
+    pub close: Numeric,
+    #[serde(rename = "FIRST_SWAP_TIMESTAMP", default)]
     /// The timestamp, in seconds, of the first swap in this time period. This is only available when there is at least one swap in the time period.
     pub first_swap_timestamp: i64,
-    #[serde(rename = "FIRST_SWAP_BLOCK")]
+    #[serde(rename = "FIRST_SWAP_BLOCK", default)]
     /// The block of the first swap in the time period. This is only available when there is at least one swap in the time period.
     pub first_swap_block: i64,
-    #[serde(rename = "LAST_SWAP_TIMESTAMP")]
+    #[serde(rename = "LAST_SWAP_TIMESTAMP", default)]
     /// The timestamp, in seconds, of the last swap in this time period. This is only available when there is at least one swap in the time period.
     pub last_swap_timestamp: i64,
-    #[serde(rename = "LAST_SWAP_BLOCK")]
+    #[serde(rename = "LAST_SWAP_BLOCK", default)]
     /// The block of the last swap in the time period. This is only available when there is at least one swap in the time period.
     pub last_swap_block: i64,
-    #[serde(rename = "FIRST_SWAP_PRICE")]
+    #[serde(rename = "FIRST_SWAP_PRICE", default)]
     /// The price of the first swap in the time period. This is only available when there is at least one swap in the time period.
-    pub first_swap_price: f64,
-    #[serde(rename = "HIGH_SWAP_PRICE")]
+    pub first_swap_price: Numeric,
+    #[serde(rename = "HIGH_SWAP_PRICE", default)]
     /// The highest value of the swaps in the time period. This is only available when there is at least one swap in the time period.
-    pub high_swap_price: f64,
-    #[serde(rename = "HIGH_SWAP_TIMESTAMP")]
+    pub high_swap_price: Numeric,
+    #[serde(rename = "HIGH_SWAP_TIMESTAMP", default)]
     /// The timestamp, in seconds, of the highest swap in the time period. This is only available when there is at least one swap in the time period.
     pub high_swap_timestamp: i64,
-    #[serde(rename = "HIGH_SWAP_BLOCK")]
+    #[serde(rename = "HIGH_SWAP_BLOCK", default)]
     /// The block of the highest swap in the time period. This is only available when there is at least one swap in the time period.
     pub high_swap_block: i64,
-    #[serde(rename = "LOW_SWAP_PRICE")]
+    #[serde(rename = "LOW_SWAP_PRICE", default)]
     /// The lowest value of the swaps in the time period. This is only available when there is at least one swap in the time period.
-    pub low_swap_price: f64,
-    #[serde(rename = "LOW_SWAP_TIMESTAMP")]
+    pub low_swap_price: Numeric,
+    #[serde(rename = "LOW_SWAP_TIMESTAMP", default)]
     /// The timestamp, in seconds, of the lowest swap in the time period. This is only available when there is at least one swap in the time period.
     pub low_swap_timestamp: i64,
-    #[serde(rename = "LOW_SWAP_BLOCK")]
+    #[serde(rename = "LOW_SWAP_BLOCK", default)]
     /// The block of the lowest swap in the time period. This is only available when there is at least one swap in the time period.
     pub low_swap_block: i64,
-    #[serde(rename = "LAST_SWAP_PRICE")]
+    #[serde(rename = "LAST_SWAP_PRICE", default)]
     /// The last swap price in the time period. This is only available when there is at least one swap in the time period.
-    pub last_swap_price: f64,
-    #[serde(rename = "TOTAL_SWAPS")]
+    pub last_swap_price: Numeric,
+    #[serde(rename = "TOTAL_SWAPS", default)]
     /// The total number of swaps seen in this time period. If there were no swaps in the time period, 0 will be returned.
     pub total_swaps: i64,
-    #[serde(rename = "TOTAL_SWAPS_BUY")]
+    #[serde(rename = "TOTAL_SWAPS_BUY", default)]
     /// The total number of BUY swaps seen in this time period. If there were no swaps in the time period, 0 will be returned.
     pub total_swaps_buy: i64,
-    #[serde(rename = "TOTAL_SWAPS_SELL")]
+    #[serde(rename = "TOTAL_SWAPS_SELL", default)]
     /// The total number of SELL swaps seen in this time period. If there were no swaps in the time period, 0 will be returned."
     pub total_swaps_sell: i64,
-    #[serde(rename = "TOTAL_SWAPS_UNKNOWN")]
+    #[serde(rename = "TOTAL_SWAPS_UNKNOWN", default)]
     /// The total number of UNKNOWN swaps seen in this time period. If there were no swaps in the time period, 0 will be returned.
     pub total_swaps_unknown: i64,
-    #[serde(rename = "VOLUME")]
+    #[serde(rename = "VOLUME", default)]
     /// The sum of all the swap volumes in the from asset (base symbol / coin) for the time period. If there were no swaps in the time period, 0 will be returned.
-    pub volume: f64,
-    #[serde(rename = "QUOTE_VOLUME")]
+    pub volume: Numeric,
+    #[serde(rename = "QUOTE_VOLUME", default)]
     /// The sum of all the swap volumes in the To asset (quote/counter symbol/coin) for the time period. If there were no swaps in the time period,
     /// 0 will be returned.
-    pub quote_volume: f64,
-    #[serde(rename = "VOLUME_BUY")]
+    pub quote_volume: Numeric,
+    #[serde(rename = "VOLUME_BUY", default)]
     /// The sum of all the BUY swap volumes in the from asset (base symbol / coin) for the time period.
-    pub volume_buy: f64,
-    #[serde(rename = "QUOTE_VOLUME_BUY")]
+    pub volume_buy: Numeric,
+    #[serde(rename = "QUOTE_VOLUME_BUY", default)]
     /// The sum of all the BUY swap volumes in the to asset (quote/counter symbol/coin) for the time period.
-    pub quote_volume_buy: f64,
-    #[serde(rename = "VOLUME_SELL")]
+    pub quote_volume_buy: Numeric,
+    #[serde(rename = "VOLUME_SELL", default)]
     /// The sum of all the SELL swap volumes in the from asset (base symbol / coin) for the time period.
-    pub volume_sell: f64,
-    #[serde(rename = "QUOTE_VOLUME_SELL")]
+    pub volume_sell: Numeric,
+    #[serde(rename = "QUOTE_VOLUME_SELL", default)]
     /// The sum of all the SELL swap volumes in the To asset (quote/counter symbol/coin) for the time period.
-    pub quote_volume_sell: f64,
-    #[serde(rename = "VOLUME_UNKNOWN")]
+    pub quote_volume_sell: Numeric,
+    #[serde(rename = "VOLUME_UNKNOWN", default)]
     /// The sum of all the UNKNOWN swap volumes in the from asset (base symbol / coin) for the time period.
     pub volume_unknown: String,
-    #[serde(rename = "QUOTE_VOLUME_UNKNOWN")]
+    #[serde(rename = "QUOTE_VOLUME_UNKNOWN", default)]
     /// The sum of all the UNKNOWN swap volumes in the To asset (quote/counter symbol/coin) for the time period.
     pub quote_volume_unknown: String,
 }
@@ -1,6 +1,7 @@
 use std::fmt::Display;
 use serde::{Serialize, Deserialize};
 use crate::schemas::data_api::InstrumentStatus;
+use crate::schemas::Numeric;
 
 
 #[derive(Clone, Copy, Debug, Default, Serialize, Deserialize)]
@@ -82,125 +83,125 @@ pub struct OptionsOHLCV {
     #[serde(rename = "INSTRUMENT")]
     /// The unmapped instrument ID.
     pub instrument: String,
-    #[serde(rename = "MAPPED_INSTRUMENT")]
+    #[serde(rename = "MAPPED_INSTRUMENT", default)]
     /// The mapped instrument ID, derived from our mapping rules.
     pub mapped_instrument: String,
-    #[serde(rename = "INDEX_UNDERLYING")]
+    #[serde(rename = "INDEX_UNDERLYING", default)]
     /// The mapped index underlying asset.
     pub index_underlying: String,
-    #[serde(rename = "QUOTE_CURRENCY")]
+    #[serde(rename = "QUOTE_CURRENCY", default)]
     /// The mapped to asset quote / counter symbol / coin (e.g. USD). Only available on instruments that have mapping.
     pub quote_currency: String,
-    #[serde(rename = "SETTLEMENT_CURRENCY")]
+    #[serde(rename = "SETTLEMENT_CURRENCY", default)]
     /// The currency that the contract is settled in (e.g. USD). Only available on instruments that have mapping.
     pub settlement_currency: String,
-    #[serde(rename = "CONTRACT_CURRENCY")]
+    #[serde(rename = "CONTRACT_CURRENCY", default)]
     /// The currency that the contract size is denominated in (e.g. USD). Only available on instruments that have mapping.
     pub contract_currency: String,
-    #[serde(rename = "STRIKE_CURRENCY")]
+    #[serde(rename = "STRIKE_CURRENCY", default)]
     /// The strike currency of the contract underlying index.
     pub strike_currency: String,
-    #[serde(rename = "INDEX_UNDERLYING_ID")]
+    #[serde(rename = "INDEX_UNDERLYING_ID", default)]
     /// Represents the internal CoinDesk ID for the index underlying asset (e.g., 1). This ID is unique and immutable,
     /// ensuring consistent identification. Applicable only to instruments with a mapping.
     pub index_underlying_id: i32,
-    #[serde(rename = "QUOTE_CURRENCY_ID")]
+    #[serde(rename = "QUOTE_CURRENCY_ID", default)]
     /// Represents the internal CoinDesk ID for the asset quote / counter symbol / coin (e.g. 5). This ID is unique and immutable,
     /// ensuring consistent identification. Applicable only to instruments with a mapping.
     pub quote_currency_id: i32,
-    #[serde(rename = "SETTLEMENT_CURRENCY_ID")]
+    #[serde(rename = "SETTLEMENT_CURRENCY_ID", default)]
     /// Represents the internal CoinDesk ID for the currency that the contract is settled in (e.g. 5). This ID is unique and immutable,
     /// ensuring consistent identification. Applicable only to instruments with a mapping.
     pub settlement_currency_id: i32,
-    #[serde(rename = "CONTRACT_CURRENCY_ID")]
+    #[serde(rename = "CONTRACT_CURRENCY_ID", default)]
     /// Represents the internal CoinDesk ID for the currency that the contract size is denominated in (e.g. 5). This ID is unique and immutable,
     /// ensuring consistent identification. Applicable only to instruments with a mapping.
     pub contract_currency_id: i32,
-    #[serde(rename = "STRIKE_CURRENCY_ID")]
+    #[serde(rename = "STRIKE_CURRENCY_ID", default)]
     /// Represents the internal CoinDesk ID for the strike currency of the contract underlying index. This ID is unique and immutable,
     /// ensuring consistent identification. Applicable only to instruments with a mapping.
     pub strike_currency_id: i32,
-    #[serde(rename = "TRANSFORM_FUNCTION")]
+    #[serde(rename = "TRANSFORM_FUNCTION", default)]
     /// The transform function. This is the function we apply when we do mapping to change values into easier human readable
     /// ones and to make sure the mapped direction BASE - QUOTE is constant accross all instruments.
     pub transform_function: String,
-    #[serde(rename = "OPEN")]
+    #[serde(rename = "OPEN", default)]
     /// The open price for the historical period, based on the closest trade before the period start.
-    pub open: f64,
-    #[serde(rename = "HIGH")]
+    pub open: Numeric,
+    #[serde(rename = "HIGH", default)]
     /// The highest trade price of the historical period. If there were no trades in the period, the open price will be taken as the highest.
-    pub high: f64,
-    #[serde(rename = "LOW")]
+    pub high: Numeric,
+    #[serde(rename = "LOW", default)]
     /// The lowest trade price of the historical period. If there were no trades in the period, the open price will be taken as the lowest.
-    pub low: f64,
-    #[serde(rename = "CLOSE")]
+    pub low: Numeric,
+    #[serde(rename = "CLOSE", default)]
     /// The price of the last trade of the historical period. If there were no trades in the period, the open price will be taken as the close.
-    pub close: f64,
-    #[serde(rename = "NUMBER_OF_CONTRACTS")]
+    pub close: Numeric,
+    #[serde(rename = "NUMBER_OF_CONTRACTS", default)]
     /// The sum of all the trade number of contracts for the time period. If there were no trades in the time period, 0 will be given.
     pub number_of_contracts: i64,
-    #[serde(rename = "TOTAL_TRADES")]
+    #[serde(rename = "TOTAL_TRADES", default)]
     /// The total number of trades that occurred in the time period. If there were no trades in the time period, 0 will be given.
     pub total_trades: i64,
-    #[serde(rename = "TOTAL_TRADES_BUY")]
+    #[serde(rename = "TOTAL_TRADES_BUY", default)]
     /// The total number of BUY trades that occurred in the in time period.
     pub total_trades_buy: i64,
-    #[serde(rename = "TOTAL_TRADES_SELL")]
+    #[serde(rename = "TOTAL_TRADES_SELL", default)]
     /// The total number of SELL trades that occurred in the time period.
     pub total_trades_sell: i64,
-    #[serde(rename = "TOTAL_TRADES_UNKNOWN")]
+    #[serde(rename = "TOTAL_TRADES_UNKNOWN", default)]
     /// The total number of UNKNOWN trades that occurred in the time period.
     pub total_trades_unknown: i64,
-    #[serde(rename = "VOLUME")]
+    #[serde(rename = "VOLUME", default)]
     /// The sum of all the trade volumes in the from asset (base symbol / coin) for the time period. If there were no trades in the time period, 0 will be given.
-    pub volume: f64,
-    #[serde(rename = "QUOTE_VOLUME")]
+    pub volume: Numeric,
+    #[serde(rename = "QUOTE_VOLUME", default)]
     /// The sum of all the trade volumes in the To asset (quote/counter symbol/coin) for the time period. If there were no trades in the time period, 0 will be given.
-    pub quote_volume: f64,
-    #[serde(rename = "VOLUME_BUY")]
+    pub quote_volume: Numeric,
+    #[serde(rename = "VOLUME_BUY", default)]
     /// The sum of all the BUY trade volumes in the from asset (base symbol / coin) for the time period.
-    pub volume_buy: f64,
-    #[serde(rename = "QUOTE_VOLUME_BUY")]
+    pub volume_buy: Numeric,
+    #[serde(rename = "QUOTE_VOLUME_BUY", default)]
     /// The sum of all the BUY trade volumes in the To asset (quote/counter symbol/coin) for the time period.
-    pub quote_volume_buy: f64,
-    #[serde(rename = "VOLUME_SELL")]
+    pub quote_volume_buy: Numeric,
+    #[serde(rename = "VOLUME_SELL", default)]
     /// The sum of all the SELL trade volumes in the from asset (base symbol / coin) for the time period.
-    pub volume_sell: f64,
-    #[serde(rename = "QUOTE_VOLUME_SELL")]
+    pub volume_sell: Numeric,
+    #[serde(rename = "QUOTE_VOLUME_SELL", default)]
     /// The sum of all the SELL trade volumes in the To asset (quote/counter symbol/coin) for the time period.
-    pub quote_volume_sell: f64,
-    #[serde(rename = "VOLUME_UNKNOWN")]
+    pub quote_volume_sell: Numeric,
+    #[serde(rename = "VOLUME_UNKNOWN", default)]
     /// The sum of all the UNKNOWN trade volumes in the from asset (base symbol / coin) for the time period.
-    pub volume_unknown: f64,
-    #[serde(rename = "QUOTE_VOLUME_UNKNOWN")]
+    pub volume_unknown: Numeric,
+    #[serde(rename = "QUOTE_VOLUME_UNKNOWN", default)]
     /// The sum of all the UNKNOWN trade volumes in the To asset (quote/counter symbol/coin) for the time period.
-    pub quote_volume_unknown: f64,
-    #[serde(rename = "NOTIONAL_VOLUME")]
+    pub quote_volume_unknown: Numeric,
+    #[serde(rename = "NOTIONAL_VOLUME", default)]
     /// The sum of all the notional trade volumes in the from asset (base symbol / coin) for the time period.
     /// If there were no trades in the time period, 0 will be given.
-    pub notional_volume: f64,
-    #[serde(rename = "NOTIONAL_QUOTE_VOLUME")]
+    pub notional_volume: Numeric,
+    #[serde(rename = "NOTIONAL_QUOTE_VOLUME", default)]
     /// The sum of all the notional trade volumes in the To asset (quote/counter symbol/coin) for the time period.
     /// If there were no trades in the time period, 0 will be given.
-    pub notional_quote_volume: f64,
-    #[serde(rename = "NOTIONAL_VOLUME_BUY")]
+    pub notional_quote_volume: Numeric,
+    #[serde(rename = "NOTIONAL_VOLUME_BUY", default)]
     /// The sum of all the BUY notional trade volumes in the from asset (base symbol / coin) for the time period.
-    pub notional_volume_buy: f64,
-    #[serde(rename = "NOTIONAL_QUOTE_VOLUME_BUY")]
+    pub notional_volume_buy: Numeric,
+    #[serde(rename = "NOTIONAL_QUOTE_VOLUME_BUY", default)]
     /// The sum of all the BUY notional trade volumes in the To asset (quote/counter symbol/coin) for the time period.
-    pub notional_quote_volume_buy: f64,
-    #[serde(rename = "NOTIONAL_VOLUME_SELL")]
+    pub notional_quote_volume_buy: Numeric,
+    #[serde(rename = "NOTIONAL_VOLUME_SELL", default)]
     /// The sum of all the SELL notional trade volumes in the from asset (base symbol / coin) for the time period.
-    pub notional_volume_sell: f64,
-    #[serde(rename = "NOTIONAL_QUOTE_VOLUME_SELL")]
+    pub notional_volume_sell: Numeric,
+    #[serde(rename = "NOTIONAL_QUOTE_VOLUME_SELL", default)]
     /// The sum of all the SELL notional trade volumes in the To asset (quote/counter symbol/coin) for the time period.
-    pub notional_quote_volume_sell: f64,
-    #[serde(rename = "NOTIONAL_VOLUME_UNKNOWN")]
+    pub notional_quote_volume_sell: Numeric,
+    #[serde(rename = "NOTIONAL_VOLUME_UNKNOWN", default)]
     /// The sum of all the UNKNOWN notional trade volumes in the from asset (base symbol / coin) for the time period.
-    pub notional_volume_unknown: f64,
-    #[serde(rename = "NOTIONAL_QUOTE_VOLUME_UNKNOWN")]
+    pub notional_volume_unknown: Numeric,
+    #[serde(rename = "NOTIONAL_QUOTE_VOLUME_UNKNOWN", default)]
     /// The sum of all the UNKNOWN notional trade volumes in the To asset (quote/counter symbol/coin) for the time period.
-    pub notional_quote_volume_unknown: f64,
+    pub notional_quote_volume_unknown: Numeric,
 }
 
 
@@ -209,83 +210,83 @@ pub struct OptionsOHLCV {
 
 #[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct OptionsInstrumentMetadata {
-    #[serde(rename = "METADATA_VERSION")]
+    #[serde(rename = "METADATA_VERSION", default)]
     /// The version of metadata, used for version conversions/migrates.
     pub metadata_version: i32,
-    #[serde(rename = "INSTRUMENT_STATUS")]
+    #[serde(rename = "INSTRUMENT_STATUS", default)]
     /// The status of the instrument, we only poll / stream / connect to the ACTIVE ones,
     /// for the RETIRED / IGNORED / EXPIRED / READY_FOR_DECOMMISSIONING means we no longer query/stream data.
     pub instrument_status: String,
-    #[serde(rename = "FIRST_SEEN_ON_POLLING_TS")]
+    #[serde(rename = "FIRST_SEEN_ON_POLLING_TS", default)]
     /// This is the first time instrument was seen on instrumentListSourceType POLLING.
     pub firt_seen_on_polling_ts: i64,
-    #[serde(rename = "LAST_SEEN_ON_POLLING_TS")]
+    #[serde(rename = "LAST_SEEN_ON_POLLING_TS", default)]
     /// This is the last time instrument was seen on instrumentListSourceType POLLING.
     pub last_seen_on_polling_ts: i64,
     #[serde(rename = "INSTRUMENT")]
     /// The instrument ID as it is on the exchange with small modifications - we do not allow the following characters inside isntrument ids: ,/&?
     pub instrument: String,
-    #[serde(rename = "INSTRUMENT_MAPPING")]
+    #[serde(rename = "INSTRUMENT_MAPPING", default)]
     /// The current mapping information for this instrument
     pub instrument_mapping: OptionsInstrumentMapping,
-    #[serde(rename = "INSTRUMENT_EXTERNAL_DATA")]
+    #[serde(rename = "INSTRUMENT_EXTERNAL_DATA", default)]
     /// The full data we get from the polling endpoint for this specific instrument.
     /// This is a JSON stringified object with different properties per exchange.
     pub instrument_external_data: String,
-    #[serde(rename = "INSTRUMENT_AVAILABLE_ON_INSTRUMENTS_ENDPOINT")]
+    #[serde(rename = "INSTRUMENT_AVAILABLE_ON_INSTRUMENTS_ENDPOINT", default)]
     /// This flags the exchange instrument is currently available on instruments endpoint.
     pub instrument_available_on_instruments_endpoint: bool,
-    #[serde(rename = "INDEX_ID")]
+    #[serde(rename = "INDEX_ID", default)]
     /// The id of the index the contract is based on.
     pub index_id: String,
-    #[serde(rename = "INDEX_UNDERLYING")]
+    #[serde(rename = "INDEX_UNDERLYING", default)]
     /// The underlying instrument of the index.
     pub index_underlying: String,
-    #[serde(rename = "QUOTE_CURRENCY")]
+    #[serde(rename = "QUOTE_CURRENCY", default)]
     /// The instrument that the contract is priced in.
     pub quote_currency: String,
-    #[serde(rename = "SETTLEMENT_CURRENCY")]
+    #[serde(rename = "SETTLEMENT_CURRENCY", default)]
     /// The currency used to calculate contract PnL. The settlement currency can be different from the index underlying or quote currencies.
     pub settlement_currency: String,
-    #[serde(rename = "CONTRACT_CURRENCY")]
+    #[serde(rename = "CONTRACT_CURRENCY", default)]
     /// The denomination of the CONTRACT_SIZE.
     pub contract_currency: String,
-    #[serde(rename = "CONTRACT_SIZE")]
+    #[serde(rename = "CONTRACT_SIZE", default)]
     /// The contract size - how much of the contract currency does one contract contain.
-    pub contract_size: f64,
-    #[serde(rename = "TICK_SIZE")]
+    pub contract_size: Numeric,
+    #[serde(rename = "TICK_SIZE", default)]
     /// The minimum amount the price can move, denominated in QUOTE_CURRENCY.
-    pub tick_size: f64,
-    #[serde(rename = "CONTRACT_CREATION_TS")]
+    pub tick_size: Numeric,
+    #[serde(rename = "CONTRACT_CREATION_TS", default)]
     /// The contract creation timestamp we get for the specific derivative instrument.
     pub contract_creation_ts:  i64,
-    #[serde(rename = "CONTRACT_EXPIRATION_TS")]
+    #[serde(rename = "CONTRACT_EXPIRATION_TS", default)]
     /// The contract expiration timestamp we get for the specific derivative instrument. Not needed for PERPETUAL contract types.
     pub contract_expiration_ts: i64,
-    #[serde(rename = "CONTRACT_EXPIRATION_YEAR")]
+    #[serde(rename = "CONTRACT_EXPIRATION_YEAR", default)]
     /// Year in which the contract expires.
     pub contract_expiration_year: i32,
-    #[serde(rename = "CONTRACT_EXPIRATION_MONTH_CODE")]
+    #[serde(rename = "CONTRACT_EXPIRATION_MONTH_CODE", default)]
     /// Month in which the contract expires.
     pub contract_expiration_month_code: String,
-    #[serde(rename = "STRIKE_PRICE")]
+    #[serde(rename = "STRIKE_PRICE", default)]
     /// The strike price.
-    pub strike_price: f64,
-    #[serde(rename = "STRIKE_CURRENCY")]
+    pub strike_price: Numeric,
+    #[serde(rename = "STRIKE_CURRENCY", default)]
     /// The denomination of the STRIKE_PRICE.
     pub strike_currency: String,
-    #[serde(rename = "OPTION_TYPE")]
+    #[serde(rename = "OPTION_TYPE", default)]
     /// The type of option contract.
     pub option_type: String,
-    #[serde(rename = "OPTION_STYLE")]
+    #[serde(rename = "OPTION_STYLE", default)]
     /// The style of option contract.
     pub option_style: String,
-    #[serde(rename = "BASE_CURRENCY")]
+    #[serde(rename = "BASE_CURRENCY", default)]
     /// The base currency of the contract underlying index.
     pub base_currency: String,
-    #[serde(rename = "MIN_TRADE_AMOUNT")]
+    #[serde(rename = "MIN_TRADE_AMOUNT", default)]
     /// The minimum amount of contracts you can trade.
-    pub min_trade_amount: f64,
+    pub min_trade_amount: Numeric,
 }
 
 
@@ -1,4 +1,5 @@
 use serde::{Serialize, Deserialize};
+use crate::schemas::Numeric;
 
 
 // Overview: MktCap Historical OHLCV
@@ -17,19 +18,19 @@ pub struct OverviewMktCapOHLCV {
     #[serde(rename = "TYPE")]
     /// Type of the message, this is 886.
     pub type_: String,
-    #[serde(rename = "OPEN")]
+    #[serde(rename = "OPEN", default)]
     /// The sum of assets circulating mkt caps (circulating supply * price quoted in USD) that meet the inclusion criteria closest to the start of the time period.
-    pub open: f64,
-    #[serde(rename = "HIGH")]
+    pub open: Numeric,
+    #[serde(rename = "HIGH", default)]
     /// The highest sum of assets circulating mkt caps (circulating supply * price quoted in USD) that meet the inclusion criteria during the time period.
-    pub high: f64,
-    #[serde(rename = "LOW")]
+    pub high: Numeric,
+    #[serde(rename = "LOW", default)]
     /// The lowest sum of assets circulating mkt caps (circulating supply * price quoted in USD) that meet the inclusion criteria during the time period.
-    pub low: f64,
-    #[serde(rename = "CLOSE")]
+    pub low: Numeric,
+    #[serde(rename = "CLOSE", default)]
     /// The sum of assets circulating mkt caps (circulating supply * price quoted in USD) that meet the inclusion criteria closest to the end of the time period.
-    pub close: f64,
-    #[serde(rename = "TOP_TIER_VOLUME")]
+    pub close: Numeric,
+    #[serde(rename = "TOP_TIER_VOLUME", default)]
     /// The sum of assets top-tier volumes (top-tier volume * price quoted in USD) that meet the inclusion criteria during the time period.
-    pub top_tier_volume: f64,
+    pub top_tier_volume: Numeric,
 }
\ No newline at end of file
@@ -1,6 +1,7 @@
 use std::{fmt::Display, collections::HashMap};
 use serde::{Serialize, Deserialize};
 use crate::schemas::data_api::InstrumentStatus;
+use crate::schemas::Numeric;
 
 
 #[derive(Clone, Copy, Debug, Default, Serialize, Deserialize)]
@@ -441,103 +442,103 @@ pub struct SpotOHLCV {
     #[serde(rename = "INSTRUMENT")]
     /// The unmapped instrument ID.
     pub instrument: String,
-    #[serde(rename = "MAPPED_INSTRUMENT")]
+    #[serde(rename = "MAPPED_INSTRUMENT", default)]
     /// The instrument ID, as derived from our mapping rules. This takes the form "BASE-QUOTE" (e.g. BTC-USD). Only available on instruments that have been mapped.
     pub mapped_instrument: String,
-    #[serde(rename = "BASE")]
+    #[serde(rename = "BASE", default)]
     /// Represents the base asset or coin symbol, commonly known as the ticker (e.g., BTC). This symbol may change in cases of asset rebranding.
     /// Applicable only to instruments with a mapping.
     pub base: String,
-    #[serde(rename = "QUOTE")]
+    #[serde(rename = "QUOTE", default)]
     /// Represents the quote asset or counter coin symbol, commonly known as the ticker (e.g., USD). This symbol may change in cases of asset rebranding.
     /// Applicable only to instruments with a mapping.
     pub quote: String,
-    #[serde(rename = "BASE_ID")]
+    #[serde(rename = "BASE_ID", default)]
     /// Represents the internal CoinDesk ID for the base asset or coin (e.g., 1 for BTC). This ID is unique and immutable, ensuring consistent identification.
     /// Applicable only to instruments with a mapping.
     pub base_id: i32,
-    #[serde(rename = "QUOTE_ID")]
+    #[serde(rename = "QUOTE_ID", default)]
     /// Represents the internal CoinDesk ID for the quote asset or counter coin (e.g., 5 for USD). This ID is unique and immutable, ensuring consistent identification.
     /// Applicable only to instruments with a mapping.
     pub quote_id: i32,
-    #[serde(rename = "TRANSFORM_FUNCTION")]
+    #[serde(rename = "TRANSFORM_FUNCTION", default)]
     /// The transform function. This is the function we apply when we do mapping to change values into easier human readable ones and to make sure the
     /// mapped direction BASE - QUOTE is constant accross all instruments.
     pub transform_function: String,
-    #[serde(rename = "OPEN")]
+    #[serde(rename = "OPEN", default)]
     /// The open price for the historical period, this is based on the closest trade before the period start.
-    pub open: f64,
-    #[serde(rename = "HIGH")]
+    pub open: Numeric,
+    #[serde(rename = "HIGH", default)]
     /// The highest trade price in the time period. If there were no trades in the time period, the open price will be given.
-    pub high: f64,
-    #[serde(rename = "LOW")]
+    pub high: Numeric,
+    #[serde(rename = "LOW", default)]
     /// The lowest trade price in the time period. If there were no trades in the time period, the open price will be given.
-    pub low: f64,
-    #[serde(rename = "CLOSE")]
+    pub low: Numeric,
+    #[serde(rename = "CLOSE", default)]
     /// The price of the last trade in this time period. If there were no trades in the time period, the open price will be given.
-    pub close: f64,
-    #[serde(rename = "FIRST_TRADE_TIMESTAMP")]
+    pub close: Numeric,
+    #[serde(rename = "FIRST_TRADE_TIMESTAMP", default)]
     /// The timestamp, in seconds, of the first trade in this time perio. This is only available when there is at least one trade in the time period.
     pub first_trade_timestamp: Option<i64>,
-    #[serde(rename = "LAST_TRADE_TIMESTAMP")]
+    #[serde(rename = "LAST_TRADE_TIMESTAMP", default)]
     /// The timestamp, in seconds, of the last trade in this time period. This is only available when there is at least one trade in the time period.
     pub last_trade_timestamp: Option<i64>,
-    #[serde(rename = "FIRST_TRADE_PRICE")]
+    #[serde(rename = "FIRST_TRADE_PRICE", default)]
     /// The price of the first trade in the time period. This is only available when there is at least one trade in the time period.
-    pub first_trade_price: Option<f64>,
-    #[serde(rename = "HIGH_TRADE_PRICE")]
+    pub first_trade_price: Option<Numeric>,
+    #[serde(rename = "HIGH_TRADE_PRICE", default)]
     /// The highest value of the trades in the time period. This is only available when there is at least one trade in the time period.
-    pub high_trade_price: Option<f64>,
-    #[serde(rename = "HIGH_TRADE_TIMESTAMP")]
+    pub high_trade_price: Option<Numeric>,
+    #[serde(rename = "HIGH_TRADE_TIMESTAMP", default)]
     /// The timestamp, in seconds, of the highest trade in the time period. This is only available when there is at least one trade in the time period.
     pub high_trade_timestamp: Option<i64>,
-    #[serde(rename = "LOW_TRADE_PRICE")]
+    #[serde(rename = "LOW_TRADE_PRICE", default)]
     /// The lowest value of the trades in the time period. This is only available when there is at least one trade in the time period.
-    pub low_trade_price: Option<f64>,
-    #[serde(rename = "LOW_TRADE_TIMESTAMP")]
+    pub low_trade_price: Option<Numeric>,
+    #[serde(rename = "LOW_TRADE_TIMESTAMP", default)]
     /// The timestamp, in seconds, of the lowest trade in the time period. This is only available when there is at least one trade in the time period.
     pub low_trade_timestamp: Option<i64>,
-    #[serde(rename = "LAST_TRADE_PRICE")]
+    #[serde(rename = "LAST_TRADE_PRICE", default)]
     /// The last trade price in the time period. This is only available when there is at least one trade in the time period.
-    pub last_trade_price: Option<f64>,
-    #[serde(rename = "TOTAL_TRADES")]
+    pub last_trade_price: Option<Numeric>,
+    #[serde(rename = "TOTAL_TRADES", default)]
     /// The total number of trades seen in this time period. If there were no trades in the time period, 0 will be returned.
     pub total_trades: i64,
-    #[serde(rename = "TOTAL_TRADES_BUY")]
+    #[serde(rename = "TOTAL_TRADES_BUY", default)]
     /// The total number of BUY trades seen in this time period. If there were no trades in the time period, 0 will be returned.
     pub total_trades_buy: i64,
-    #[serde(rename = "TOTAL_TRADES_SELL")]
+    #[serde(rename = "TOTAL_TRADES_SELL", default)]
     // The total number of SELL trades seen in this time period. If there were no trades in the time period, 0 will be returned.
     pub total_trades_sell: i64,
-    #[serde(rename = "TOTAL_TRADES_UNKNOWN")]
+    #[serde(rename = "TOTAL_TRADES_UNKNOWN", default)]
     /// The total number of UNKNOWN trades seen in this time period. If there were no trades in the time period, 0 will be returned.
     pub total_trades_unknown: i64,
-    #[serde(rename = "VOLUME")]
+    #[serde(rename = "VOLUME", default)]
     /// The sum of all the trade volumes in the from asset (base symbol / coin) for the time period. If there were no trades in the time period,
     /// 0 will be returned.
-    pub volume: f64,
-    #[serde(rename = "QUOTE_VOLUME")]
+    pub volume: Numeric,
+    #[serde(rename = "QUOTE_VOLUME", default)]
     /// The sum of all the trade volumes in the To asset (quote/counter symbol/coin) for the time period. If there were no trades in the time period,
     /// 0 will be returned.
-    pub quote_volume: f64,
-    #[serde(rename = "VOLUME_BUY")]
+    pub quote_volume: Numeric,
+    #[serde(rename = "VOLUME_BUY", default)]
     /// The sum of all the BUY trade volumes in the from asset (base symbol / coin) for the time period.
-    pub volume_buy: f64,
-    #[serde(rename = "QUOTE_VOLUME_BUY")]
+    pub volume_buy: Numeric,
+    #[serde(rename = "QUOTE_VOLUME_BUY", default)]
     /// The sum of all the BUY trade volumes in the to asset (quote/counter symbol/coin) for the time period.
-    pub quote_volume_buy: f64,
-    #[serde(rename = "VOLUME_SELL")]
+    pub quote_volume_buy: Numeric,
+    #[serde(rename = "VOLUME_SELL", default)]
     /// The sum of all the SELL trade volumes in the from asset (base symbol / coin) for the time period.
-    pub volume_sell: f64,
-    #[serde(rename = "QUOTE_VOLUME_SELL")]
+    pub volume_sell: Numeric,
+    #[serde(rename = "QUOTE_VOLUME_SELL", default)]
     /// The sum of all the SELL trade volumes in the To asset (quote/counter symbol/coin) for the time period.
-    pub quote_volume_sell: f64,
-    #[serde(rename = "VOLUME_UNKNOWN")]
+    pub quote_volume_sell: Numeric,
+    #[serde(rename = "VOLUME_UNKNOWN", default)]
     /// The sum of all the UNKNOWN trade volumes in the from asset (base symbol / coin) for the time period.
-    pub volume_unknown: f64,
-    #[serde(rename = "QUOTE_VOLUME_UNKNOWN")]
+    pub volume_unknown: Numeric,
+    #[serde(rename = "QUOTE_VOLUME_UNKNOWN", default)]
     /// The sum of all the UNKNOWN trade volumes in the To asset (quote/counter symbol/coin) for the time period.
-    pub quote_volume_unknown: f64,
+    pub quote_volume_unknown: Numeric,
 }
 
 
@@ -547,29 +548,29 @@ pub struct SpotOHLCV {
 /// Spot: Instrument Metadata
 #[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct SpotInstrumentMetdata {
-    #[serde(rename = "METADATA_VERSION")]
+    #[serde(rename = "METADATA_VERSION", default)]
     /// The version of metadata, used for version conversions/migrates.
     pub metadata_version: usize,
-    #[serde(rename = "INSTRUMENT_STATUS")]
+    #[serde(rename = "INSTRUMENT_STATUS", default)]
     /// The status of the instrument, we only poll / stream / connect to the ACTIVE ones, for the RETIRED / IGNORED / EXPIRED /
     /// READY_FOR_DECOMMISSIONING means we no longer query/stream data.
     pub instrument_status: String,
-    #[serde(rename = "FIRST_SEEN_ON_POLLING_TS")]
+    #[serde(rename = "FIRST_SEEN_ON_POLLING_TS", default)]
     /// This is the first time instrument was seen on instrumentListSourceType POLLING.
     pub first_seen_on_pollings_ts: i64,
-    #[serde(rename = "LAST_SEEN_ON_POLLING_TS")]
+    #[serde(rename = "LAST_SEEN_ON_POLLING_TS", default)]
     /// This is the last time instrument was seen on instrumentListSourceType POLLING.
     pub last_seen_pollings_ts: i64,
     #[serde(rename = "INSTRUMENT")]
     /// The instrument ID as it is on the exchange with small modifications - we do not allow the following characters inside isntrument ids: ,/&?
     pub instrument: String,
-    #[serde(rename = "INSTRUMENT_MAPPING")]
+    #[serde(rename = "INSTRUMENT_MAPPING", default)]
     /// The current mapping information for this instrument.
     pub instrument_mapping: SpotInstrumentMapping,
-    #[serde(rename = "INSTRUMENT_EXTERNAL_DATA")]
+    #[serde(rename = "INSTRUMENT_EXTERNAL_DATA", default)]
     /// The full data we get from the polling endpoint for this specific instrument. This is a JSON stringified object with different properties per exchange.
     pub instrument_external_data: String,
-    #[serde(rename = "FIRST_OB_L2_MINUTE_SNAPSHOT_TS")]
+    #[serde(rename = "FIRST_OB_L2_MINUTE_SNAPSHOT_TS", default)]
     /// Timestamp of the initial Level 2 minute snapshot.
     pub first_ob_l2_minute_snapshot_ts: Option<i64>,
 }
@@ -0,0 +1,137 @@
+//! Local HTTP server used by the unit tests to serve canned responses without network access.
+use std::sync::{Arc, Mutex};
+use tokio::{io::{AsyncReadExt, AsyncWriteExt}, net::TcpListener};
+use crate::{BaseURL, CoinDesk};
+
+
+/// Canned HTTP response served by the `MockServer`.
+pub struct MockResponse {
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+}
+
+impl MockResponse {
+    pub fn new(status: u16, body: &str) -> Self {
+        Self { status, headers: Vec::new(), body: body.to_owned(), }
+    }
+
+    pub fn ok(body: &str) -> Self {
+        Self::new(200, body)
+    }
+
+    /// Spot OHLCV+ response with the data points at the timestamps (Only the fields of the `ID` group are included).
+    pub fn ohlcv(timestamps: &[i64]) -> Self {
+        let data: Vec<String> = timestamps.iter()
+            .map(|v| format!("{{\"UNIT\":\"DAY\",\"TIMESTAMP\":{v},\"TYPE\":\"267\",\"MARKET\":\"kraken\",\"INSTRUMENT\":\"XBTUSD\"}}") )
+            .collect();
+        Self::ok(&format!("{{\"Data\":[{}]}}", data.join(",")))
+    }
+
+    pub fn header(mut self, name: &str, value: &str) -> Self {
+        self.headers.push((name.to_owned(), value.to_owned()));
+        self
+    }
+
+    fn to_http(&self) -> String {
+        let headers: String = self.headers.iter().map(|(k, v)| format!("{k}: {v}\r\n") ).collect();
+        format!(
+            "HTTP/1.1 {} MOCK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n{}\r\n{}",
+            self.status, self.body.len(), headers, self.body,
+        )
+    }
+}
+
+
+/// HTTP server that serves the canned responses in order (one per connection) and records the heads of the received requests.
+pub struct MockServer {
+    pub url: String,
+    requests: Arc<Mutex<Vec<String>>>,
+}
+
+impl MockServer {
+    pub async fn start(responses: Vec<MockResponse>) -> Self {
+        let listener: TcpListener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url: String = format!("http://{}", listener.local_addr().unwrap());
+        let requests: Arc<Mutex<Vec<String>>> = Arc::new(Mutex::new(Vec::new()));
+        let received: Arc<Mutex<Vec<String>>> = requests.clone();
+        tokio::spawn(async move {
+            for response in responses {
+                let (mut stream, _) = match listener.accept().await { Ok(v) => v, Err(_) => return, };
+                let mut head: Vec<u8> = Vec::new();
+                let mut buffer: [u8; 1024] = [0; 1024];
+                while !head.windows(4).any(|w| w == b"\r\n\r\n" ) {
+                    match stream.read(&mut buffer).await {
+                        Ok(0) | Err(_) => break,
+                        Ok(n) => head.extend_from_slice(&buffer[..n]),
+                    }
+                }
+                received.lock().unwrap().push(String::from_utf8_lossy(&head).into_owned());
+                let _ = stream.write_all(response.to_http().as_bytes()).await;
+                let _ = stream.shutdown().await;
+            }
+        });
+        Self { url, requests, }
+    }
+
+    /// Returns the heads (request line and headers) of all requests received so far.
+    pub fn requests(&self) -> Vec<String> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+
+
+/// Creates a backend with a dummy API key that sends the Data API requests to the mock server.
+pub fn mock_backend(server: &MockServer) -> CoinDesk {
+    let mut backend: CoinDesk = CoinDesk::new();
+    backend.update_api_key(String::from("xxxxxxx"));
+    backend.update_base_url(BaseURL { data_api: server.url.clone(), ..Default::default() });
+    backend
+}
+
+
+#[cfg(feature = "tracing")]
+#[derive(Clone, Default)]
+/// Layer that records the fields of all spans and events as `name=value` strings.
+pub struct RecordingLayer {
+    records: Arc<Mutex<Vec<(tracing::Level, String)>>>,
+}
+
+#[cfg(feature = "tracing")]
+impl RecordingLayer {
+    /// Returns the recorded fields at the given level or more severe levels.
+    pub fn fields(&self, level: tracing::Level) -> Vec<String> {
+        self.records.lock().unwrap().iter().filter(|(l, _)| *l <= level ).map(|(_, v)| v.clone() ).collect()
+    }
+
+    fn push(&self, level: tracing::Level, record: &dyn Fn(&mut dyn tracing::field::Visit)) -> () {
+        struct Visitor(Vec<String>);
+        impl tracing::field::Visit for Visitor {
+            fn record_debug(&mut self, field: &tracing::field::Field, value: &dyn std::fmt::Debug) {
+                self.0.push(format!("{}={:?}", field.name(), value));
+            }
+            fn record_str(&mut self, field: &tracing::field::Field, value: &str) {
+                self.0.push(format!("{}={}", field.name(), value));
+            }
+        }
+        let mut visitor: Visitor = Visitor(Vec::new());
+        record(&mut visitor);
+        self.records.lock().unwrap().extend(visitor.0.into_iter().map(|v| (level, v) ));
+    }
+}
+
+#[cfg(feature = "tracing")]
+impl<S: tracing::Subscriber> tracing_subscriber::Layer<S> for RecordingLayer {
+    fn on_new_span(&self, attrs: &tracing::span::Attributes<'_>, _: &tracing::span::Id, _: tracing_subscriber::layer::Context<'_, S>) {
+        self.push(*attrs.metadata().level(), &|v| attrs.record(v) );
+    }
+
+    fn on_record(&self, _: &tracing::span::Id, values: &tracing::span::Record<'_>, _: tracing_subscriber::layer::Context<'_, S>) {
+        self.push(tracing::Level::INFO, &|v| values.record(v) );
+    }
+
+    fn on_event(&self, event: &tracing::Event<'_>, _: tracing_subscriber::layer::Context<'_, S>) {
+        self.push(*event.metadata().level(), &|v| event.record(v) );
+    }
+}
@@ -0,0 +1,104 @@
+#[cfg(feature = "chrono")]
+use chrono::{DateTime, TimeZone, Utc};
+#[cfg(feature = "time")]
+use time::OffsetDateTime;
+
+
+/// Value that can be converted into a Unix timestamp in seconds (the unit used by all time parameters of CoinDesk API).
+///
+/// Note: `DateTime` (`chrono` feature) and `OffsetDateTime` (`time` feature) are converted into seconds, so the time parameters cannot
+/// be mixed up with milliseconds.
+///
+/// # Examples
+///
+/// ```rust
+/// use ccdata_api::IntoTimestamp;
+///
+/// assert_eq!(1728860400.into_timestamp(), 1728860400);
+/// ```
+pub trait IntoTimestamp {
+    /// Converts the value into a Unix timestamp in seconds.
+    fn into_timestamp(self) -> i64;
+}
+
+impl IntoTimestamp for i64 {
+    fn into_timestamp(self) -> i64 {
+        self
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl<Tz: TimeZone> IntoTimestamp for DateTime<Tz> {
+    fn into_timestamp(self) -> i64 {
+        self.timestamp()
+    }
+}
+
+#[cfg(feature = "time")]
+impl IntoTimestamp for OffsetDateTime {
+    fn into_timestamp(self) -> i64 {
+        self.unix_timestamp()
+    }
+}
+
+
+#[cfg(feature = "chrono")]
+/// Converts a Unix timestamp in seconds into `DateTime<Utc>`.
+///
+/// Note: Returns `None` if the timestamp is out of the range supported by `chrono`.
+///
+/// # Input
+/// - `timestamp`: Unix timestamp in seconds
+pub fn datetime_utc(timestamp: i64) -> Option<DateTime<Utc>> {
+    DateTime::from_timestamp(timestamp, 0)
+}
+
+#[cfg(feature = "time")]
+/// Converts a Unix timestamp in seconds into `OffsetDateTime` (UTC).
+///
+/// Note: Returns `None` if the timestamp is out of the range supported by `time`.
+///
+/// # Input
+/// - `timestamp`: Unix timestamp in seconds
+pub fn offset_datetime(timestamp: i64) -> Option<OffsetDateTime> {
+    OffsetDateTime::from_unix_timestamp(timestamp).ok()
+}
+
+
+#[cfg(test)]
+mod tests {
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn unit_test_chrono_timestamp() -> () {
+        use chrono::{DateTime, TimeZone, Utc, FixedOffset};
+        use crate::timestamp::{IntoTimestamp, datetime_utc};
+        use crate::pagination::Timestamped;
+        use crate::request::SpotOHLCVRequest;
+        use crate::schemas::data_api::spot::SpotOHLCV;
+        let datetime: DateTime<Utc> = Utc.with_ymd_and_hms(2024, 10, 13, 23, 0, 0).unwrap();
+        assert_eq!(datetime.into_timestamp(), 1728860400);
+        let datetime: DateTime<FixedOffset> = FixedOffset::east_opt(3600).unwrap().with_ymd_and_hms(2024, 10, 14, 0, 0, 0).unwrap();
+        assert_eq!(datetime.into_timestamp(), 1728860400);
+        assert_eq!(datetime_utc(1728860400).unwrap(), Utc.with_ymd_and_hms(2024, 10, 13, 23, 0, 0).unwrap());
+        // Request builders and schemas
+        assert_eq!(SpotOHLCVRequest::new("BTC-USD").to_timestamp(datetime).to_timestamp, Some(1728860400));
+        let ohlcv: SpotOHLCV = SpotOHLCV { timestamp: 1728860400, ..Default::default() };
+        assert_eq!(ohlcv.datetime_utc().unwrap(), datetime);
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn unit_test_time_timestamp() -> () {
+        use time::{Date, Month, OffsetDateTime};
+        use crate::timestamp::{IntoTimestamp, offset_datetime};
+        use crate::pagination::Timestamped;
+        use crate::schemas::data_api::news::NewsLatestArticle;
+        let datetime: OffsetDateTime = Date::from_calendar_date(2024, Month::October, 13).unwrap().with_hms(23, 0, 0).unwrap().assume_utc();
+        assert_eq!(datetime.into_timestamp(), 1728860400);
+        assert_eq!(offset_datetime(1728860400).unwrap(), datetime);
+        assert_eq!(offset_datetime(i64::MAX), None);
+        let article: NewsLatestArticle = NewsLatestArticle { published_on: 1728860400, ..Default::default() };
+        assert_eq!(article.offset_datetime().unwrap(), datetime);
+    }
+}
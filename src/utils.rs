@@ -1,22 +1,19 @@
-use std::fmt::Display;
+use std::{fmt::Display, mem::discriminant, sync::Arc, time::Duration};
 use serde::{Serialize, Deserialize, de::DeserializeOwned};
-use reqwest::Response;
+use reqwest::{Client, Request, RequestBuilder, Response, StatusCode, Url, header::{HeaderMap, HeaderValue, AUTHORIZATION}};
+use url::form_urlencoded;
 use crate::error::Error;
-use crate::{Unit, APIEndpointTrait, APIEndpoint};
+use crate::client::AuthMethod;
+use crate::cache::{Cache, cache_key};
+use crate::cassette::CassetteMode;
+use crate::retry::{RetryPolicy, RetryEvent};
+use crate::schemas::{CCRateLimit, CCError};
+use crate::retry::retry_after;
+use crate::{Unit, APIEndpointTrait, CoinDesk};
 use crate::schemas::data_api::spot::SpotInstrumentStatus;
 use crate::schemas::data_api::news::{NewsLang, NewsSourceID, NewsSourceType, NewsStatus};
 
 
-#[cfg(feature = "debug")]
-/// Checks whether the `CCDATA_API_DEBUG` environment variable is enabled.
-/// Note: If `CCDATA_API_DEBUG` variable exists, the reponses will be printed to command line.
-fn debug() -> Result<bool, Error> {
-    dotenv::dotenv()?;
-    let debug: bool = serde_json::from_str(&std::env::var("CCDATA_API_DEBUG")?)?;
-    Ok(debug)
-}
-
-
 #[derive(Clone, Copy, Debug, Serialize, Deserialize)]
 /// Filtering groups.
 /// 
@@ -24,6 +21,10 @@ fn debug() -> Result<bool, Error> {
 /// When requesting market metadata entries you can filter by specific groups of interest.
 /// To do so just pass the groups of interest into the URL as a comma separated list.
 /// If left empty it will get all data that your account is allowed to access.
+///
+/// Note: The groups can be overridden per request (e.g., `SpotOHLCVRequest::groups`). Fields of the response schemas that belong to
+/// the groups that were not requested are deserialized into their default values, while the identity and timestamp fields
+/// (e.g., `TIMESTAMP`, `MARKET` and `INSTRUMENT` of the `Id` group) are required.
 pub enum Group {
     Activity,
     AssetTypeSpecificMetrics,
@@ -161,6 +162,10 @@ pub enum Param<'a> {
     ToTimestamp { v: Option<i64>, },
     /// Maximum number of datapoints per API endpoint call
     Limit { v: Option<usize>, },
+    /// Number of data points to aggregate into a single data point
+    Aggregate { v: Option<u32>, },
+    /// Determines if the intervals without any trades are filled with the data of the previous interval
+    Fill { v: Option<bool>, },
     // Special parameters
     /// Market name
     Market { v: String, },
@@ -198,82 +203,430 @@ pub enum Param<'a> {
 }
 
 impl<'a> Param<'a> {
-    fn add_param_to_url(&self, url: &mut String) -> () {
-        let url_param: String = match self {
+    /// Returns the name and the values of the query parameter (List parameters have multiple values), or `None` if the parameter is not set.
+    fn query_pair(&self) -> Option<(&'static str, Vec<String>)> {
+        let pair: (&str, Vec<String>) = match self {
             // Instrument parameters
-            Self::Symbol { v } => format!("&fsym={v}"),
-            Self::Instrument { v } => format!("&instrument={v}"),
-            Self::Instruments { v } => format!("&instruments={}", v.join(",")),
-            Self::ChainAsset { v } => format!("&chain_asset={v}"),
-            Self::Asset { v } => format!("&asset={v}"),
-            Self::Assets { v } => format!("&assets={}", v.join(",")),
+            Self::Symbol { v } => ("fsym", vec![v.to_string()]),
+            Self::Instrument { v } => ("instrument", vec![v.to_string()]),
+            Self::Instruments { v } => ("instruments", v.to_vec()),
+            Self::ChainAsset { v } => ("chain_asset", vec![v.to_string()]),
+            Self::Asset { v } => ("asset", vec![v.to_string()]),
+            Self::Assets { v } => ("assets", v.clone()),
             // Overlapping parameters
-            Self::ToTs { v } => v.map_or("".to_owned(), |i| format!("&toTs={i}") ),
-            Self::ToTimestamp { v } => v.map_or("".to_owned(), |i| format!("&to_ts={i}") ),
-            Self::Limit { v } => format!("&limit={}", v.unwrap_or(2_000)),
+            Self::ToTs { v } => ("toTs", vec![v.as_ref()?.to_string()]),
+            Self::ToTimestamp { v } => ("to_ts", vec![v.as_ref()?.to_string()]),
+            Self::Limit { v } => ("limit", vec![v.unwrap_or(2_000).to_string()]),
+            Self::Aggregate { v } => ("aggregate", vec![v.as_ref()?.to_string()]),
+            Self::Fill { v } => ("fill", vec![v.as_ref()?.to_string()]),
             // Special parameters
-            Self::Market { v } => format!("&market={v}"),
-            Self::Markets { v } => format!("&markets={}", v.join(",")),
-            Self::InstrumentStatus { v } => format!("&instrument_status={v}"),
-            Self::OCCoreBlockNumber { v } => format!("&block_number={v}"),
-            Self::OCCoreAddress { v } => format!("&address={v}"),
-            Self::OCCoreQuoteAsset { v } => format!("&quote_asset={v}"),
-            Self::NewsLanguage { v } => format!("&lang={v}"),
-            Self::NewsSourceID { v } => format!("&source_ids={v}"),
-            Self::NewsCategories { v } => format!("&categories={}", v.as_ref().unwrap_or(&vec![]).join(",")),
-            Self::NewsExcludeCategories { v } => format!("&exclude_categories={}", v.as_ref().unwrap_or(&vec![]).join(",")),
-            Self::NewsSourceType { v } => format!("&source_type={v}"),
-            Self::NewsStatus { v } => format!("&status={v}"),
-            Self::Groups { v } => v.as_ref().map_or("".to_owned(), |i|
-                format!("&groups={}", i.iter().map(|v| v.to_string() ).collect::<Vec<String>>().join(","))
-            ),
-            Self::ApplyMapping { v } => format!("&apply_mapping={v}"),
-            Self::AssetLookupPriority { v } => format!("&asset_lookup_priority={v}"),
-            Self::QuoteAsset { v } => format!("&quote_asset={v}"),
+            Self::Market { v } => ("market", vec![v.clone()]),
+            Self::Markets { v } => ("markets", v.clone()),
+            Self::InstrumentStatus { v } => ("instrument_status", vec![v.to_string()]),
+            Self::OCCoreBlockNumber { v } => ("block_number", vec![v.to_string()]),
+            Self::OCCoreAddress { v } => ("address", vec![v.to_string()]),
+            Self::OCCoreQuoteAsset { v } => ("quote_asset", vec![v.to_string()]),
+            Self::NewsLanguage { v } => ("lang", vec![v.to_string()]),
+            Self::NewsSourceID { v } => ("source_ids", vec![v.to_string()]),
+            Self::NewsCategories { v } => ("categories", v.clone().unwrap_or_default()),
+            Self::NewsExcludeCategories { v } => ("exclude_categories", v.clone().unwrap_or_default()),
+            Self::NewsSourceType { v } => ("source_type", vec![v.to_string()]),
+            Self::NewsStatus { v } => ("status", vec![v.to_string()]),
+            Self::Groups { v } => ("groups", v.as_ref()?.iter().map(|v| v.to_string() ).collect()),
+            Self::ApplyMapping { v } => ("apply_mapping", vec![v.to_string()]),
+            Self::AssetLookupPriority { v } => ("asset_lookup_priority", vec![v.to_string()]),
+            Self::QuoteAsset { v } => ("quote_asset", vec![v.to_string()]),
         };
-        url.push_str(&url_param);
+        Some(pair)
+    }
+
+    /// Adds the percent-encoded parameter to the query string.
+    ///
+    /// Note: The values of the list parameters are encoded separately and joined with commas, so the commas inside of the values are encoded.
+    fn add_param_to_query(&self, query: &mut String) -> () {
+        if let Some((name, values)) = self.query_pair() {
+            let values: Vec<String> = values.iter().map(|v| encode_query_value(v) ).collect();
+            query.push_str(&format!("&{}={}", name, values.join(",")));
+        }
     }
 }
 
 
-/// Make a request to the provided URL, validate the status code of the response, and return deserialized data.
-async fn process_request<T: DeserializeOwned>(url: String) -> Result<T, Error> {
-    let response: Response = reqwest::get(url).await?;
-    let response_body: String = response.text().await?;
-    // Print response body to the command line
-    #[cfg(feature = "debug")]
-    if debug()? { println!("{:?}", response_body) }
-    // Replace empty object and empty list placeholders
-    let response_body: String = response_body.replace("{}", "null");
-    // let response_body: String = response_body.replace("[]", "null");
-    let data: T = serde_json::from_str(&response_body)?;
-    Ok(data)
+/// Percent-encodes the value of the query parameter.
+fn encode_query_value(value: &str) -> String {
+    form_urlencoded::byte_serialize(value.as_bytes()).collect()
+}
+
+
+/// Replaces the value of the `api_key` query parameter of the URL with a placeholder.
+pub(crate) fn redact_url(url: &mut Url) -> () {
+    if !url.query_pairs().any(|(k, _)| k == "api_key" ) {
+        return;
+    }
+    let pairs: Vec<(String, String)> = url.query_pairs().map(|(k, v)| {
+        let v: String = if k == "api_key" { String::from("REDACTED") } else { v.into_owned() };
+        (k.into_owned(), v)
+    }).collect();
+    url.query_pairs_mut().clear().extend_pairs(pairs);
+}
+
+
+#[derive(Clone, Debug)]
+/// Raw HTTP response received from CoinDesk API.
+pub struct RawResponse {
+    /// HTTP status code of the response
+    pub status: StatusCode,
+    /// Headers of the response
+    pub headers: HeaderMap,
+    /// Response body
+    pub body: String,
+}
+
+
+#[derive(Deserialize)]
+/// Error object of Data API responses.
+struct ErrorPayload {
+    #[serde(rename = "Err")]
+    error: Option<CCError>,
+}
+
+
+#[derive(Deserialize)]
+/// Rate limit information that is included in Min-API responses.
+struct RateLimitPayload {
+    #[serde(rename = "RateLimit")]
+    rate_limit: Option<CCRateLimit>,
+}
+
+
+/// Sends the request and reads the response body, retrying transient failures according to the retry policy of the backend.
+///
+/// Note: The `after_response` hooks of the middleware are applied to the response of every attempt. Both the received response
+/// and the response returned by the hooks are returned.
+async fn send_request(backend: &CoinDesk, request: Request) -> Result<(RawResponse, RawResponse), Error> {
+    let mut attempt: u32 = 1;
+    loop {
+        if let Some(rate_limiter) = backend.rate_limiter() {
+            rate_limiter.acquire().await;
+        }
+        // All requests are `GET` requests without a body, so the request can always be cloned
+        let result: Result<RawResponse, reqwest::Error> = match request.try_clone() {
+            Some(request) => read_response(backend.client(), request).await,
+            None => return apply_after_response(backend, read_response(backend.client(), request).await?).await,
+        };
+        let result: Result<(RawResponse, RawResponse), reqwest::Error> = match result {
+            Ok(response) => Ok(apply_after_response(backend, response).await?),
+            Err(e) => Err(e),
+        };
+        if let (Some(rate_limiter), Ok((_, response))) = (backend.rate_limiter(), &result) {
+            rate_limiter.update_from_headers(&response.headers);
+        }
+        let policy: &RetryPolicy = match backend.retry_policy() {
+            Some(policy) if attempt < policy.max_attempts => policy,
+            _ => return Ok(result?),
+        };
+        let (reason, delay) = match &result {
+            Ok((_, response)) => match policy.status_reason(response.status) {
+                Some(reason) => (reason, policy.delay(attempt, Some(&response.headers))),
+                None => return Ok(result?),
+            },
+            Err(e) => match policy.error_reason(e) {
+                Some(reason) => (reason, policy.delay(attempt, None)),
+                None => return Ok(result?),
+            },
+        };
+        let delay: Duration = match delay {
+            Some(v) => v,
+            None => return Ok(result?),
+        };
+        policy.notify(&RetryEvent { attempt, delay, reason, });
+        #[cfg(feature = "tracing")]
+        tracing::debug!(attempt, ?delay, ?reason, "Retrying CoinDesk API request");
+        tokio::time::sleep(delay).await;
+        #[cfg(feature = "tracing")]
+        tracing::Span::current().record("retries", attempt);
+        attempt += 1;
+    }
+}
+
+
+/// Applies the `before_request` hooks of the middleware registered on the backend.
+async fn apply_before_request(backend: &CoinDesk, request: &mut Request) -> Result<(), Error> {
+    for middleware in backend.middleware() {
+        middleware.before_request(request).await?;
+    }
+    Ok(())
+}
+
+
+/// Applies the `after_response` hooks of the middleware registered on the backend to a copy of the received response,
+/// and returns both the received response and the response returned by the hooks.
+async fn apply_after_response(backend: &CoinDesk, received: RawResponse) -> Result<(RawResponse, RawResponse), Error> {
+    let mut response: RawResponse = received.clone();
+    for middleware in backend.middleware() {
+        middleware.after_response(&mut response).await?;
+    }
+    Ok((received, response))
+}
+
+
+/// Sends the request and reads the status code, headers and body of the response.
+async fn read_response(client: &Client, request: Request) -> Result<RawResponse, reqwest::Error> {
+    let response: Response = client.execute(request).await?;
+    let status: StatusCode = response.status();
+    let headers: HeaderMap = response.headers().clone();
+    let body: String = response.text().await?;
+    Ok(RawResponse { status, headers, body, })
+}
+
+
+/// Converts the response with an unsuccessful HTTP status code into an error.
+fn status_error(response: RawResponse) -> Error {
+    let error: Option<Box<CCError>> = serde_json::from_str::<ErrorPayload>(&response.body).ok().and_then(|v| v.error ).map(Box::new);
+    match response.status {
+        StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => Error::Unauthorized(error),
+        StatusCode::NOT_FOUND => Error::NotFound(error),
+        StatusCode::TOO_MANY_REQUESTS => Error::RateLimited { retry_after: retry_after(&response.headers), },
+        status => match error {
+            Some(e) => Error::Api(e),
+            None => Error::Http { status: status.as_u16(), body: response.body, },
+        },
+    }
+}
+
+
+/// Validate the status code of the response, and return the response body.
+fn process_response(backend: &CoinDesk, response: RawResponse) -> Result<String, Error> {
+    if !response.status.is_success() {
+        return Err(status_error(response));
+    }
+    let response_body: String = response.body;
+    // Update the rate limiter from the rate limit information of Min-API responses
+    if let Some(rate_limiter) = backend.rate_limiter().filter(|_| response_body.contains("\"RateLimit\"") ) {
+        if let Ok(RateLimitPayload { rate_limit: Some(rate_limit), }) = serde_json::from_str(&response_body) {
+            rate_limiter.update_from_rate_limit(&rate_limit);
+        }
+    }
+    #[cfg(feature = "tracing")]
+    tracing::trace!(body = %response_body, "CoinDesk API response body");
+    Ok(response_body)
 }
 
 
-/// Constructs a URL for API request, sends the request, and returns the deserialzied response.
+/// Adds the query string and the API key to the endpoint URL, and creates the request.
+///
+/// Note: The request is created without the API key if the backend does not have one, so that the cached and recorded responses
+/// can be served without it.
+fn build_request(backend: &CoinDesk, url: &str, query: &str) -> Result<Request, Error> {
+    let mut url: Url = Url::parse(url)?;
+    let request: RequestBuilder = match (backend.api_key().ok(), backend.auth_method()) {
+        (None, _) => {
+            url.set_query(Some(query.trim_start_matches('&')));
+            backend.client().get(url)
+        },
+        (Some(api_key), AuthMethod::Header) => {
+            url.set_query(Some(query.trim_start_matches('&')));
+            let mut auth: HeaderValue = HeaderValue::from_str(&format!("Apikey {}", api_key)).map_err(|_| Error::InvalidAPIKey )?;
+            auth.set_sensitive(true);
+            backend.client().get(url).header(AUTHORIZATION, auth)
+        },
+        (Some(api_key), AuthMethod::QueryString) => {
+            url.set_query(Some(&format!("api_key={}{}", encode_query_value(api_key), query)));
+            backend.client().get(url)
+        },
+    };
+    Ok(request.build()?)
+}
+
+
+/// Constructs a URL for API request, sends the request with a backend that only has the API key, and returns the deserialzied response.
+///
+/// Note: Every call creates a new backend with the default HTTP client, retry policy and authentication method. Use [`call_backend_endpoint`]
+/// (or `CoinDesk::call_api_endpoint`) to reuse the HTTP client and the configuration of an existing backend.
 ///
 /// # Input
 /// - `api_key`: CoinDesk API key
-/// - `endpoint`: Enum that represents the CoinDesk API endpoint for function to send the request to
+/// - `endpoint`: CoinDesk API endpoint for function to send the request to (`APIEndpoint` or a custom type that implements `APIEndpointTrait`)
 /// - `unit`: Unit for data to be binned by (e.g., `Day`, `Hour`)
 /// - `params`: List of parameters expected by the CoinDesk API endpoint
 /// - `additional_params`: Additional parameters to add to the request
 pub async fn call_api_endpoint<'a, R: DeserializeOwned>(
-    api_key: &str, endpoint: APIEndpoint, unit: Unit, mut params: Vec<Param<'a>>, additional_params: Option<String>) -> Result<R, Error>
+    api_key: &str, endpoint: impl APIEndpointTrait, unit: Unit, params: Vec<Param<'a>>, additional_params: Option<String>) -> Result<R, Error>
+{
+    let mut backend: CoinDesk = CoinDesk::new();
+    backend.update_api_key(api_key.to_owned());
+    call_backend_endpoint::<R>(&backend, endpoint, unit, params, additional_params).await
+}
+
+
+/// Constructs a URL for API request, sends the request with the backend, and returns the deserialzied response.
+///
+/// Note: Depending on the `AuthMethod` of the backend, the API key is either sent in the `Authorization` header (Default) or
+/// added to the query string of the URL.
+///
+/// Note: `Param::Groups` in `params` replaces the default groups of the API endpoint, and the parameters in `params` replace the default
+/// parameters of the same kind (e.g., `Param::QuoteAsset`).
+///
+/// # Input
+/// - `backend`: Data collection backend that provides the API key and the HTTP client
+/// - `endpoint`: CoinDesk API endpoint for function to send the request to (`APIEndpoint` or a custom type that implements `APIEndpointTrait`)
+/// - `unit`: Unit for data to be binned by (e.g., `Day`, `Hour`)
+/// - `params`: List of parameters expected by the CoinDesk API endpoint
+/// - `additional_params`: Additional parameters to add to the request
+pub async fn call_backend_endpoint<'a, R: DeserializeOwned>(
+    backend: &CoinDesk, endpoint: impl APIEndpointTrait, unit: Unit, mut params: Vec<Param<'a>>, additional_params: Option<String>) -> Result<R, Error>
 {
     // Set up a URL for the API endpoint
-    let mut url: String = endpoint.url(&unit);
-    // Add API key, default parameters and groups to the URL
-    url.push_str(&format!("?api_key={}", api_key));
-    params.push(Param::Groups { v: endpoint.default_groups() });
+    let endpoint_url: String = endpoint.url(&unit);
+    let url: String = backend.base_url().rebase(&endpoint_url);
+    // Add default parameters and groups to the query (Parameters provided by the caller take precedence over the defaults)
+    let mut query: String = String::new();
+    if !params.iter().any(|v| matches!(v, Param::Groups { .. }) ) {
+        params.push(Param::Groups { v: endpoint.default_groups() });
+    }
     if let Some(default_params) = endpoint.default_params() {
+        let default_params: Vec<Param> = default_params.into_iter()
+            .filter(|v| !params.iter().any(|p| discriminant(p) == discriminant(v) ) )
+            .collect();
         params.extend(default_params);
     }
-    // Add parameters to the URL
-    params.iter().for_each(|v| v.add_param_to_url(&mut url) );
-    // Add additional parameters to the URL
-    additional_params.map(|v| url.push_str(&v) );
+    // Add parameters to the query
+    params.iter().for_each(|v| v.add_param_to_query(&mut query) );
+    // Add additional parameters to the query (Re-encoded so that the reserved characters cannot break the query)
+    if let Some(v) = additional_params {
+        form_urlencoded::parse(v.trim_start_matches('&').as_bytes()).for_each(|(k, v)| {
+            query.push_str(&format!("&{}={}", encode_query_value(&k), encode_query_value(&v)));
+        });
+    }
+    #[cfg(feature = "tracing")]
+    {
+        use tracing::Instrument;
+        let key: String = cache_key(&endpoint_url, &query);
+        let span: tracing::Span = tracing::info_span!(
+            "call_api_endpoint", endpoint = %endpoint_url, unit = ?unit, params = %key.split_once('?').map_or("", |(_, v)| v ),
+            status = tracing::field::Empty, latency_ms = tracing::field::Empty, body_size = tracing::field::Empty, retries = 0, cache_hit = false,
+        );
+        fetch_data::<R>(backend, &endpoint_url, &url, &query).instrument(span).await
+    }
+    #[cfg(not(feature = "tracing"))]
+    fetch_data::<R>(backend, &endpoint_url, &url, &query).await
+}
+
+
+/// Returns the key of the cache and the cassette for the request.
+///
+/// Note: The key is the final URL of the request (after the middleware is applied) with the base URL of the backend replaced
+/// by the default one, and with the query parameters sorted and without the API key.
+///
+/// # Input
+/// - `request`: Request that is about to be sent
+/// - `url`: URL of the API endpoint with the base URL of the backend
+/// - `endpoint_url`: URL of the API endpoint with the default base URL
+fn request_key(request: &Request, url: &str, endpoint_url: &str) -> String {
+    let mut request_url: Url = request.url().clone();
+    let query: String = request_url.query().map_or(String::new(), |v| format!("&{}", v) );
+    request_url.set_query(None);
+    match request_url.as_str().strip_prefix(url) {
+        Some(path) => cache_key(&format!("{}{}", endpoint_url, path), &query),
+        None => cache_key(request_url.as_str(), &query),
+    }
+}
+
+
+/// Returns the cached or recorded response to the request, or sends the request, and returns the deserialized response.
+///
+/// Note: The cache and the cassette store the responses as they were received, and the `after_response` hooks of the middleware
+/// are applied to them every time they are served.
+async fn fetch_data<R: DeserializeOwned>(backend: &CoinDesk, endpoint_url: &str, url: &str, query: &str) -> Result<R, Error> {
+    let mut request: Request = build_request(backend, url, query)?;
+    apply_before_request(backend, &mut request).await?;
+    // Normalized request without the API key (Used as the key of the cache and cassettes)
+    let key: String = request_key(&request, url, endpoint_url);
+    // Return the cached response
+    let cache: Option<(&Arc<dyn Cache>, Duration)> = match (backend.cache(), backend.cache_policy().ttl(endpoint_url)) {
+        (Some(cache), Some(ttl)) => Some((cache, ttl)),
+        _ => None,
+    };
+    if let Some(body) = cache.and_then(|(cache, _)| cache.get(&key) ) {
+        let (_, response) = apply_after_response(backend, RawResponse { status: StatusCode::OK, headers: HeaderMap::new(), body, }).await?;
+        if !response.status.is_success() {
+            return Err(status_error(response));
+        }
+        if let Ok(data) = serde_json::from_str::<R>(&response.body) {
+            #[cfg(feature = "tracing")]
+            tracing::Span::current().record("cache_hit", true);
+            return Ok(data);
+        }
+    }
+    // Send the request, or serve the recorded response
+    #[cfg(feature = "tracing")]
+    let start: std::time::Instant = std::time::Instant::now();
+    let (received, response) = match backend.cassette() {
+        Some(cassette) if cassette.mode() == CassetteMode::Replay => apply_after_response(backend, cassette.replay(&key)?).await?,
+        cassette => {
+            backend.api_key()?;
+            let (received, response) = send_request(backend, request).await?;
+            if let Some(cassette) = cassette { cassette.record(&key, &received)? }
+            (received, response)
+        },
+    };
+    #[cfg(feature = "tracing")]
+    tracing::Span::current()
+        .record("status", response.status.as_u16())
+        .record("latency_ms", start.elapsed().as_millis() as u64)
+        .record("body_size", response.body.len());
     // Process API response
-    process_request::<R>(url).await
-}
\ No newline at end of file
+    let response_body: String = process_response(backend, response)?;
+    let data: R = serde_json::from_str(&response_body)?;
+    if let Some((cache, ttl)) = cache.filter(|_| received.status.is_success() ) {
+        cache.insert(&key, &received.body, ttl);
+    }
+    Ok(data)
+}
+
+
+#[cfg(test)]
+mod tests {
+
+    #[test]
+    fn unit_test_query_encoding() -> () {
+        use crate::utils::{Group, Param};
+        let categories: Option<Vec<String>> = Some(vec![String::from("BTC"), String::from("MARKET,ANALYSIS"), String::from("R&D #1")]);
+        let instruments: Vec<String> = vec![String::from("BTC-USD"), String::from("ETH/USD")];
+        let params: Vec<Param> = vec![
+            Param::Instrument { v: "BTC-USD&limit=1", }, Param::Instruments { v: &instruments, }, Param::NewsCategories { v: categories, },
+            Param::OCCoreAddress { v: "0xabc#frag", }, Param::Market { v: String::from("kraken usd"), }, Param::ToTimestamp { v: None, },
+            Param::Groups { v: Some(vec![Group::OHLC, Group::Volume]), },
+        ];
+        let mut query: String = String::new();
+        params.iter().for_each(|v| v.add_param_to_query(&mut query) );
+        assert_eq!(
+            query,
+            "&instrument=BTC-USD%26limit%3D1&instruments=BTC-USD,ETH%2FUSD&categories=BTC,MARKET%2CANALYSIS,R%26D+%231&address=0xabc%23frag&market=kraken+usd&groups=OHLC,VOLUME"
+        );
+    }
+
+    #[tokio::test]
+    async fn unit_test_call_api_endpoint() -> () {
+        use crate::{Unit, APIEndpointTrait};
+        use crate::utils::{Group, Param, call_api_endpoint};
+        use crate::schemas::CoinDeskResponse;
+        use crate::test_utils::{MockServer, MockResponse};
+        struct MockEndpoint { url: String, }
+        impl APIEndpointTrait for MockEndpoint {
+            fn default_groups(&self) -> Option<Vec<Group>> { None }
+
+            fn default_params(&self) -> Option<Vec<Param<'_>>> { None }
+
+            fn url(&self, unit: &Unit) -> String { format!("{}/spot/v1/historical{}", self.url, unit) }
+        }
+        let server: MockServer = MockServer::start(vec![MockResponse::ok("{\"Data\":[{\"TIMESTAMP\":1}]}")]).await;
+        // API key is sent with a backend that is created for the call
+        let endpoint: MockEndpoint = MockEndpoint { url: server.url.clone(), };
+        let response: CoinDeskResponse<Vec<serde_json::Value>> = call_api_endpoint("xxxxxxx", endpoint, Unit::Hour, vec![], None).await.unwrap();
+        assert_eq!(response.data.unwrap().len(), 1);
+        let requests: Vec<String> = server.requests();
+        assert!(requests[0].starts_with("GET /spot/v1/historical/hours"));
+        assert!(requests[0].to_lowercase().contains("authorization: apikey xxxxxxx"));
+    }
+}
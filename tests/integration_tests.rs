@@ -1,5 +1,6 @@
 use std::collections::HashMap;
-use ccdata_api::{Unit, CoinDesk};
+use ccdata_api::{Unit, CoinDesk, Cassette, CassetteMode};
+use ccdata_api::error::Error;
 use ccdata_api::schemas::{self as sh, CoinDeskResponse};
 use ccdata_api::schemas::min_api;
 use ccdata_api::{IndicesMarket, IndicesOHLCV};
@@ -14,15 +15,47 @@ use ccdata_api::{NewsStatus, NewsLang, NewsSourceID, NewsLatestArticle, NewsSour
 use ccdata_api::OverviewMktCapOHLCV;
 
 
+/// Builds the backend for the integration tests.
+///
+/// Note: Without the API key, the tests run offline against the responses recorded in `tests/cassettes`. Set the `CCDATA_API_CASSETTE`
+/// environment variable to `record` to save the API responses to `tests/cassettes`, or to `replay` to replay them even if the API key is defined.
+fn build_backend() -> CoinDesk {
+    let directory: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/cassettes");
+    let mut backend: CoinDesk = CoinDesk::new();
+    let has_api_key: bool = backend.build(&"API_KEY").is_ok();
+    let cassette: Option<Cassette> = match Cassette::from_env(directory).unwrap() {
+        Some(cassette) => {
+            assert!(has_api_key || cassette.mode() == CassetteMode::Replay, "Recording the responses requires `API_KEY` in the `.env` file.");
+            Some(cassette)
+        },
+        None if !has_api_key => Some(Cassette::new(CassetteMode::Replay, directory).unwrap()),
+        None => None,
+    };
+    backend.update_cassette(cassette);
+    backend
+}
+
+
+/// Returns the response, or `None` if the response has not been recorded in `tests/cassettes` (the test is skipped).
+fn recorded<T>(result: Result<T, Error>) -> Option<T> {
+    match result {
+        Err(Error::MissingCassette { request }) => {
+            eprintln!("Skipped: No recorded response for the request {request}.");
+            None
+        },
+        result => Some(result.unwrap()),
+    }
+}
+
+
 // Legacy Integration Tests
 
 
 #[tokio::test]
 async fn test_get_balance_distribution() -> () {
-    let mut backend: CoinDesk = CoinDesk::new();
-    backend.build(&"API_KEY").unwrap();
+    let backend: CoinDesk = build_backend();
     let limit: usize = 2000;
-    let balance_distribution: sh::CCMinResponse<sh::CCMinWrapper<Vec<min_api::BalanceDistribution>>> = backend.get_balance_distribution(None, Some(limit)).await.unwrap();
+    let Some(balance_distribution): Option<sh::CCMinResponse<sh::CCMinWrapper<Vec<min_api::BalanceDistribution>>>> = recorded(backend.get_balance_distribution(None, Some(limit)).await) else { return; };
     assert!(balance_distribution.data.unwrap().data.unwrap().len() <= limit);
 }
 
@@ -35,11 +68,10 @@ async fn test_get_balance_distribution() -> () {
 
 #[tokio::test]
 async fn test_get_indices_ohlcv() -> () {
-    let mut backend: CoinDesk = CoinDesk::new();
-    backend.build(&"API_KEY").unwrap();
+    let backend: CoinDesk = build_backend();
     let market: IndicesMarket = IndicesMarket::CADLI;
     let limit: usize = 2000;
-    let ohlcv: CoinDeskResponse<Vec<IndicesOHLCV>> = backend.get_indices_ohlcv("BTC-USD", None, Some(limit), market, Unit::Day).await.unwrap();
+    let Some(ohlcv): Option<CoinDeskResponse<Vec<IndicesOHLCV>>> = recorded(backend.get_indices_ohlcv("BTC-USD", None, Some(limit), market, Unit::Day).await) else { return; };
     assert_eq!(ohlcv.data.unwrap().len(), limit);
 }
 
@@ -49,44 +81,40 @@ async fn test_get_indices_ohlcv() -> () {
 
 #[tokio::test]
 async fn test_get_spot_ohlcv() -> () {
-    let mut backend: CoinDesk = CoinDesk::new();
-    backend.build(&"API_KEY").unwrap();
+    let backend: CoinDesk = build_backend();
     let market: SpotMarket = SpotMarket::KRAKEN;
     let limit: usize = 2000;
-    let ohlcv: sh::CoinDeskResponse<Vec<SpotOHLCV>> = backend.get_spot_ohlcv("BTC-USD", None, Some(limit), market, Unit::Day).await.unwrap();
+    let Some(ohlcv): Option<sh::CoinDeskResponse<Vec<SpotOHLCV>>> = recorded(backend.get_spot_ohlcv("BTC-USD", None, Some(limit), market, Unit::Day).await) else { return; };
     assert_eq!(ohlcv.data.unwrap().len(), limit);
 }
 
 
 #[tokio::test]
 async fn test_get_spot_instrument_metadata() -> () {
-    let mut backend: CoinDesk = CoinDesk::new();
-    backend.build(&"API_KEY").unwrap();
+    let backend: CoinDesk = build_backend();
     let instruments: Vec<String> = vec![String::from("BTC-USD"), String::from("ETH-USD")];
     let market: SpotMarket = SpotMarket::KRAKEN;
-    let instrument_metadata: CoinDeskResponse<HashMap<String, SpotInstrumentMetdata>> = backend.get_spot_instrument_metadata(&instruments, market).await.unwrap();
+    let Some(instrument_metadata): Option<CoinDeskResponse<HashMap<String, SpotInstrumentMetdata>>> = recorded(backend.get_spot_instrument_metadata(&instruments, market).await) else { return; };
     assert_eq!(instrument_metadata.data.unwrap().len(), 2);
 }
 
 
 #[tokio::test]
 async fn test_get_spot_markets_v2() -> () {
-    let mut backend: CoinDesk = CoinDesk::new();
-    backend.build("API_KEY").unwrap();
+    let backend: CoinDesk = build_backend();
     let markets: Vec<SpotMarket> = vec![SpotMarket::KRAKEN];
-    let markets: CoinDeskResponse<HashMap<String, SpotMarkets>> = backend.get_spot_markets_v2(markets).await.unwrap();
+    let Some(markets): Option<CoinDeskResponse<HashMap<String, SpotMarkets>>> = recorded(backend.get_spot_markets_v2(markets).await) else { return; };
     assert_eq!(markets.data.unwrap().get("kraken").unwrap().exchange_status, String::from("ACTIVE"));
 }
 
 
 #[tokio::test]
 async fn test_get_spot_markets_instruments() -> () {
-    let mut backend: CoinDesk = CoinDesk::new();
-    backend.build(&"API_KEY").unwrap();
+    let backend: CoinDesk = build_backend();
     let instruments: Vec<String> = vec![String::from("BTC-USD"), String::from("ETH-USD")];
     let market: SpotMarket = SpotMarket::KRAKEN;
     let instrument_status: SpotInstrumentStatus = SpotInstrumentStatus::ACTIVE;
-    let markets_instruments: CoinDeskResponse<HashMap<String, SpotMarketsInstruments>> = backend.get_spot_markets_instruments(&instruments, market, instrument_status).await.unwrap();
+    let Some(markets_instruments): Option<CoinDeskResponse<HashMap<String, SpotMarketsInstruments>>> = recorded(backend.get_spot_markets_instruments(&instruments, market, instrument_status).await) else { return; };
     assert_eq!(markets_instruments.data.unwrap().get("kraken").unwrap().instruments.len(), 2);
 }
 
@@ -96,32 +124,29 @@ async fn test_get_spot_markets_instruments() -> () {
 
 #[tokio::test]
 async fn test_get_futures_ohlcv() -> () {
-    let mut backend: CoinDesk = CoinDesk::new();
-    backend.build(&"API_KEY").unwrap();
+    let backend: CoinDesk = build_backend();
     let market: FuturesMarket = FuturesMarket::BINANCE;
     let limit: usize = 2000;
-    let ohlcv: CoinDeskResponse<Vec<FuturesOHLCV>> = backend.get_futures_ohlcv("BTC-USDT-VANILLA-PERPETUAL", None, Some(limit), market, Unit::Day).await.unwrap();
+    let Some(ohlcv): Option<CoinDeskResponse<Vec<FuturesOHLCV>>> = recorded(backend.get_futures_ohlcv("BTC-USDT-VANILLA-PERPETUAL", None, Some(limit), market, Unit::Day).await) else { return; };
     assert!(ohlcv.data.unwrap().len() <= limit);
 }
 
 
 #[tokio::test]
 async fn test_get_futures_instrument_metadata() -> () {
-    let mut backend: CoinDesk = CoinDesk::new();
-    backend.build(&"API_KEY").unwrap();
+    let backend: CoinDesk = build_backend();
     let instruments: Vec<String> = vec![String::from("BTCUSD_PERP"), String::from("ETH-USDT-VANILLA-PERPETUAL")];
     let market: FuturesMarket = FuturesMarket::BINANCE;
-    let futures_metadata: CoinDeskResponse<HashMap<String, FuturesInstrumentMetadata>> = backend.get_futures_instrument_metadata(&instruments, market).await.unwrap();
+    let Some(futures_metadata): Option<CoinDeskResponse<HashMap<String, FuturesInstrumentMetadata>>> = recorded(backend.get_futures_instrument_metadata(&instruments, market).await) else { return; };
     assert_eq!(futures_metadata.data.unwrap().len(), 2);
 }
 
 
 #[tokio::test]
 async fn test_get_futures_markets_v2() -> () {
-    let mut backend: CoinDesk = CoinDesk::new();
-    backend.build(&"API_KEY").unwrap();
+    let backend: CoinDesk = build_backend();
     let markets: Vec<FuturesMarket> = vec![FuturesMarket::BINANCE];
-    let markets: CoinDeskResponse<HashMap<String, FuturesMarkets>> = backend.get_futures_markets_v2(markets).await.unwrap();
+    let Some(markets): Option<CoinDeskResponse<HashMap<String, FuturesMarkets>>> = recorded(backend.get_futures_markets_v2(markets).await) else { return; };
     assert_eq!(markets.data.unwrap().get("binance").unwrap().exchange_status, String::from("ACTIVE"));
 }
 
@@ -131,33 +156,30 @@ async fn test_get_futures_markets_v2() -> () {
 
 #[tokio::test]
 async fn test_get_options_ohlcv() -> () {
-    let mut backend: CoinDesk = CoinDesk::new();
-    backend.build(&"API_KEY").unwrap();
+    let backend: CoinDesk = build_backend();
     let market: OptionsMarket = OptionsMarket::OKEX;
     let to_timestamp: Option<i64> = Some(1735084800);
     let limit: usize = 2000;
-    let ohlcv: CoinDeskResponse<Vec<OptionsOHLCV>> = backend.get_options_ohlcv("BTC-USD-20241227-15000-P", to_timestamp, Some(limit), market, Unit::Day).await.unwrap();
+    let Some(ohlcv): Option<CoinDeskResponse<Vec<OptionsOHLCV>>> = recorded(backend.get_options_ohlcv("BTC-USD-20241227-15000-P", to_timestamp, Some(limit), market, Unit::Day).await) else { return; };
     assert!(ohlcv.data.unwrap().len() <= limit);
 }
 
 
 #[tokio::test]
 async fn test_get_options_instrument_metadata() -> () {
-    let mut backend: CoinDesk = CoinDesk::new();
-    backend.build(&"API_KEY").unwrap();
+    let backend: CoinDesk = build_backend();
     let instruments: Vec<String> = vec![String::from("BTC-29NOV24-25000-P"), String::from("ETH-31JAN25-2500-P")];
     let market: OptionsMarket = OptionsMarket::DERIBIT;
-    let options_metadata: CoinDeskResponse<HashMap<String, OptionsInstrumentMetadata>> = backend.get_options_instrument_metadata(&instruments, market).await.unwrap();
+    let Some(options_metadata): Option<CoinDeskResponse<HashMap<String, OptionsInstrumentMetadata>>> = recorded(backend.get_options_instrument_metadata(&instruments, market).await) else { return; };
     assert_eq!(options_metadata.data.unwrap().len(), 2);
 }
 
 
 #[tokio::test]
 async fn test_get_options_markets_v2() -> () {
-    let mut backend: CoinDesk = CoinDesk::new();
-    backend.build(&"API_KEY").unwrap();
+    let backend: CoinDesk = build_backend();
     let markets: Vec<OptionsMarket> = vec![OptionsMarket::DERIBIT];
-    let markets: CoinDeskResponse<HashMap<String, OptionsMarkets>> = backend.get_options_markets_v2(markets).await.unwrap();
+    let Some(markets): Option<CoinDeskResponse<HashMap<String, OptionsMarkets>>> = recorded(backend.get_options_markets_v2(markets).await) else { return; };
     assert_eq!(markets.data.unwrap().get("deribit").unwrap().exchange_status, String::from("ACTIVE"));
 }
 
@@ -167,21 +189,19 @@ async fn test_get_options_markets_v2() -> () {
 
 #[tokio::test]
 async fn test_get_der_indices_ohlcv() -> () {
-    let mut backend: CoinDesk = CoinDesk::new();
-    backend.build(&"API_KEY").unwrap();
+    let backend: CoinDesk = build_backend();
     let market: DerIndicesMarket = DerIndicesMarket::BINANCE;
     let limit: usize = 2000;
-    let ohlcv: CoinDeskResponse<Vec<DerIndicesOHLCV>> = backend.get_der_indices_ohlcv("BTCUSDT", None, Some(limit), market, Unit::Day).await.unwrap();
+    let Some(ohlcv): Option<CoinDeskResponse<Vec<DerIndicesOHLCV>>> = recorded(backend.get_der_indices_ohlcv("BTCUSDT", None, Some(limit), market, Unit::Day).await) else { return; };
     assert!(ohlcv.data.unwrap().len() <= limit);
 }
 
 
 #[tokio::test]
 async fn test_get_der_indices_markets_v2() -> () {
-    let mut backend: CoinDesk = CoinDesk::new();
-    backend.build(&"API_KEY").unwrap();
+    let backend: CoinDesk = build_backend();
     let markets: Vec<DerIndicesMarket> = vec![DerIndicesMarket::KRAKEN];
-    let markets: CoinDeskResponse<HashMap<String, DerIndicesMarkets>> = backend.get_der_indices_markets_v2(markets).await.unwrap();
+    let Some(markets): Option<CoinDeskResponse<HashMap<String, DerIndicesMarkets>>> = recorded(backend.get_der_indices_markets_v2(markets).await) else { return; };
     assert_eq!(markets.data.unwrap().get("kraken").unwrap().exchange_status, String::from("ACTIVE"));
 }
 
@@ -191,21 +211,19 @@ async fn test_get_der_indices_markets_v2() -> () {
 
 #[tokio::test]
 async fn test_get_ocdex_ohlcv() -> () {
-    let mut backend: CoinDesk = CoinDesk::new();
-    backend.build(&"API_KEY").unwrap();
+    let backend: CoinDesk = build_backend();
     let market: OCDEXMarket = OCDEXMarket::UNISWAPV2;
     let limit: usize = 2000;
-    let ohlcv: CoinDeskResponse<Vec<OCDEXOHLCV>> = backend.get_ocdex_ohlcv("0x0d4a11d5eeaac28ec3f61d100daf4d40471f1852_2", None, Some(limit), market, Unit::Day).await.unwrap();
+    let Some(ohlcv): Option<CoinDeskResponse<Vec<OCDEXOHLCV>>> = recorded(backend.get_ocdex_ohlcv("0x0d4a11d5eeaac28ec3f61d100daf4d40471f1852_2", None, Some(limit), market, Unit::Day).await) else { return; };
     assert!(ohlcv.data.unwrap().len() <= limit);
 }
 
 
 #[tokio::test]
 async fn test_get_ocdex_markets_v2() -> () {
-    let mut backend: CoinDesk = CoinDesk::new();
-    backend.build(&"API_KEY").unwrap();
+    let backend: CoinDesk = build_backend();
     let markets: Vec<OCDEXMarket> = vec![OCDEXMarket::UNISWAPV2];
-    let markets: CoinDeskResponse<HashMap<String, OCDEXMarkets>> = backend.get_ocdex_markets_v2(markets).await.unwrap();
+    let Some(markets): Option<CoinDeskResponse<HashMap<String, OCDEXMarkets>>> = recorded(backend.get_ocdex_markets_v2(markets).await) else { return; };
     assert_eq!(markets.data.unwrap().get("uniswapv2").unwrap().exchange_status, String::from("ACTIVE"));
 }
 
@@ -215,39 +233,35 @@ async fn test_get_ocdex_markets_v2() -> () {
 
 #[tokio::test]
 async fn test_get_occore_eth_block() -> () {
-    let mut backend: CoinDesk = CoinDesk::new();
-    backend.build(&"API_KEY").unwrap();
-    let eth_block: CoinDeskResponse<OCCoreETHBlock> = backend.get_occore_eth_block(19501436).await.unwrap();
+    let backend: CoinDesk = build_backend();
+    let Some(eth_block): Option<CoinDeskResponse<OCCoreETHBlock>> = recorded(backend.get_occore_eth_block(19501436).await) else { return; };
     assert_eq!(eth_block.data.unwrap().symbol, String::from("ETH"));
 }
 
 
 #[tokio::test]
 async fn test_get_occore_assets_by_chain() -> () {
-    let mut backend: CoinDesk = CoinDesk::new();
-    backend.build(&"API_KEY").unwrap();
-    let assets_by_chain: CoinDeskResponse<OCCoreAssetByChain> = backend.get_occore_assets_by_chain("ETH").await.unwrap();
+    let backend: CoinDesk = build_backend();
+    let Some(assets_by_chain): Option<CoinDeskResponse<OCCoreAssetByChain>> = recorded(backend.get_occore_assets_by_chain("ETH").await) else { return; };
     assert_eq!(assets_by_chain.data.unwrap().chain_asset_summary.symbol, String::from("ETH"));
 }
 
 
 #[tokio::test]
 async fn test_get_occore_asset_by_address() -> () {
-    let mut backend: CoinDesk = CoinDesk::new();
-    backend.build(&"API_KEY").unwrap();
+    let backend: CoinDesk = build_backend();
     let address: String = String::from("0xc02aaa39b223fe8d0a0e5c4f27ead9083c756cc2");
     let quote_asset: String = String::from("USD");
-    let asset_by_address: CoinDeskResponse<OCCoreAssetByAddress> = backend.get_occore_asset_by_address("ETH", &address, &quote_asset).await.unwrap();
+    let Some(asset_by_address): Option<CoinDeskResponse<OCCoreAssetByAddress>> = recorded(backend.get_occore_asset_by_address("ETH", &address, &quote_asset).await) else { return; };
     assert_eq!(asset_by_address.data.unwrap().parent_asset_symbol.unwrap(), String::from("ETH"));
 }
 
 
 #[tokio::test]
 async fn test_get_occore_supply() -> () {
-    let mut backend: CoinDesk = CoinDesk::new();
-    backend.build(&"API_KEY").unwrap();
+    let backend: CoinDesk = build_backend();
     let limit: usize = 2000;
-    let historical_supply: CoinDeskResponse<Vec<OCCoreSupply>> = backend.get_occore_supply("BTC", None, Some(limit)).await.unwrap();
+    let Some(historical_supply): Option<CoinDeskResponse<Vec<OCCoreSupply>>> = recorded(backend.get_occore_supply("BTC", None, Some(limit)).await) else { return; };
     assert!(historical_supply.data.unwrap().len() <= limit);
 }
 
@@ -257,10 +271,9 @@ async fn test_get_occore_supply() -> () {
 
 #[tokio::test]
 async fn test_get_asset_metadata_v2() -> () {
-    let mut backend: CoinDesk = CoinDesk::new();
-    backend.build(&"API_KEY").unwrap();
+    let backend: CoinDesk = build_backend();
     let assets: Vec<String> = vec![String::from("ETH"), String::from("BTC")];
-    let metadata: CoinDeskResponse<HashMap<String, AssetMetadata>> = backend.get_asset_metadata_v2(assets).await.unwrap();
+    let Some(metadata): Option<CoinDeskResponse<HashMap<String, AssetMetadata>>> = recorded(backend.get_asset_metadata_v2(assets).await) else { return; };
     assert_eq!(metadata.data.as_ref().unwrap().get("ETH").unwrap().name, String::from("Ethereum"));
     assert_eq!(metadata.data.unwrap().get("BTC").unwrap().name, String::from("Bitcoin"));
 }
@@ -268,60 +281,54 @@ async fn test_get_asset_metadata_v2() -> () {
 
 #[tokio::test]
 async fn test_get_asset_events() -> () {
-    let mut backend: CoinDesk = CoinDesk::new();
-    backend.build(&"API_KEY").unwrap();
+    let backend: CoinDesk = build_backend();
     let limit: usize = 100;
-    let events: CoinDeskResponse<Vec<AssetEvent>> = backend.get_asset_events("ETH", None, Some(limit)).await.unwrap();
+    let Some(events): Option<CoinDeskResponse<Vec<AssetEvent>>> = recorded(backend.get_asset_events("ETH", None, Some(limit)).await) else { return; };
     assert!(events.data.unwrap().len() <= limit);
 }
 
 
 #[tokio::test]
 async fn test_get_asset_code_repo() -> () {
-    let mut backend: CoinDesk = CoinDesk::new();
-    backend.build(&"API_KEY").unwrap();
+    let backend: CoinDesk = build_backend();
     let limit: usize = 2000;
-    let code_repo: CoinDeskResponse<Vec<AssetCodeRepoMetrics>> = backend.get_asset_code_repo("ETH", None, Some(limit)).await.unwrap();
+    let Some(code_repo): Option<CoinDeskResponse<Vec<AssetCodeRepoMetrics>>> = recorded(backend.get_asset_code_repo("ETH", None, Some(limit)).await) else { return; };
     assert_eq!(code_repo.data.unwrap().len(), limit);
 }
 
 
 #[tokio::test]
 async fn test_get_asset_discord() -> () {
-    let mut backend: CoinDesk = CoinDesk::new();
-    backend.build(&"API_KEY").unwrap();
+    let backend: CoinDesk = build_backend();
     let limit: usize = 2000;
-    let discord: CoinDeskResponse<Vec<AssetDiscord>> = backend.get_asset_discord("ETH", None, Some(limit)).await.unwrap();
+    let Some(discord): Option<CoinDeskResponse<Vec<AssetDiscord>>> = recorded(backend.get_asset_discord("ETH", None, Some(limit)).await) else { return; };
     assert!(discord.data.unwrap().len() <= limit);
 }
 
 
 #[tokio::test]
 async fn test_get_asset_reddit() -> () {
-    let mut backend: CoinDesk = CoinDesk::new();
-    backend.build(&"API_KEY").unwrap();
+    let backend: CoinDesk = build_backend();
     let limit: usize = 2000;
-    let reddit: CoinDeskResponse<Vec<AssetReddit>> = backend.get_asset_reddit("ETH", None, Some(limit)).await.unwrap();
+    let Some(reddit): Option<CoinDeskResponse<Vec<AssetReddit>>> = recorded(backend.get_asset_reddit("ETH", None, Some(limit)).await) else { return; };
     assert!(reddit.data.unwrap().len() <= limit);
 }
 
 
 #[tokio::test]
 async fn test_get_asset_telegram() -> () {
-    let mut backend: CoinDesk = CoinDesk::new();
-    backend.build(&"API_KEY").unwrap();
+    let backend: CoinDesk = build_backend();
     let limit: usize = 2000;
-    let telegram: CoinDeskResponse<Vec<AssetTelegram>> = backend.get_asset_telegram("SOL", None, Some(limit)).await.unwrap();
+    let Some(telegram): Option<CoinDeskResponse<Vec<AssetTelegram>>> = recorded(backend.get_asset_telegram("SOL", None, Some(limit)).await) else { return; };
     assert!(telegram.data.unwrap().len() <= limit);
 }
 
 
 #[tokio::test]
 async fn test_get_asset_twitter() -> () {
-    let mut backend: CoinDesk = CoinDesk::new();
-    backend.build(&"API_KEY").unwrap();
+    let backend: CoinDesk = build_backend();
     let limit: usize = 2000;
-    let twitter: CoinDeskResponse<Vec<AssetTwitter>> = backend.get_asset_twitter("BTC", Some(1779119914), Some(limit)).await.unwrap();
+    let Some(twitter): Option<CoinDeskResponse<Vec<AssetTwitter>>> = recorded(backend.get_asset_twitter("BTC", Some(1779119914), Some(limit)).await) else { return; };
     assert!(twitter.data.unwrap().len() <= limit);
 }
 
@@ -331,34 +338,31 @@ async fn test_get_asset_twitter() -> () {
 
 #[tokio::test]
 async fn test_get_news_latest_articles() -> () {
-    let mut backend: CoinDesk = CoinDesk::new();
-    backend.build(&"API_KEY").unwrap();
+    let backend: CoinDesk = build_backend();
     let language: NewsLang = NewsLang::EN;
     let source_id: NewsSourceID = NewsSourceID::ForbesDigitalAssets;
     let limit: usize = 100;
-    let articles: CoinDeskResponse<Vec<NewsLatestArticle>> = backend.get_news_latest_articles(language, source_id, None, None, None, Some(limit)).await.unwrap();
+    let Some(articles): Option<CoinDeskResponse<Vec<NewsLatestArticle>>> = recorded(backend.get_news_latest_articles(language, source_id, None, None, None, Some(limit)).await) else { return; };
     assert_eq!(articles.data.unwrap().len(), limit);
 }
 
 
 #[tokio::test]
 async fn test_get_news_sources() -> () {
-    let mut backend: CoinDesk = CoinDesk::new();
-    backend.build(&"API_KEY").unwrap();
+    let backend: CoinDesk = build_backend();
     let language: NewsLang = NewsLang::EN;
     let source_type: NewsSourceType = NewsSourceType::RSS;
     let status: NewsStatus = NewsStatus::ACTIVE;
-    let sources: CoinDeskResponse<Vec<NewsSource>> = backend.get_news_sources(language, source_type, status).await.unwrap();
+    let Some(sources): Option<CoinDeskResponse<Vec<NewsSource>>> = recorded(backend.get_news_sources(language, source_type, status).await) else { return; };
     assert_eq!(sources.data.unwrap()[0].source_type, String::from("RSS"));
 }
 
 
 #[tokio::test]
 async fn test_get_news_categories() -> () {
-    let mut backend: CoinDesk = CoinDesk::new();
-    backend.build(&"API_KEY").unwrap();
+    let backend: CoinDesk = build_backend();
     let status: NewsStatus = NewsStatus::ACTIVE;
-    let categories: CoinDeskResponse<Vec<NewsCategory>> = backend.get_news_categories(status).await.unwrap();
+    let Some(categories): Option<CoinDeskResponse<Vec<NewsCategory>>> = recorded(backend.get_news_categories(status).await) else { return; };
     assert_eq!(categories.data.unwrap()[0].status, String::from("ACTIVE"));
 }
 
@@ -368,10 +372,9 @@ async fn test_get_news_categories() -> () {
 
 #[tokio::test]
 async fn test_get_overview_mktcap_ohlcv() -> () {
-    let mut backend: CoinDesk = CoinDesk::new();
-    backend.build(&"API_KEY").unwrap();
+    let backend: CoinDesk = build_backend();
     let limit: usize = 2000;
-    let mktcap: CoinDeskResponse<Vec<OverviewMktCapOHLCV>> = backend.get_overview_mktcap_ohlcv(None, Some(limit)).await.unwrap();
+    let Some(mktcap): Option<CoinDeskResponse<Vec<OverviewMktCapOHLCV>>> = recorded(backend.get_overview_mktcap_ohlcv(None, Some(limit)).await) else { return; };
     assert_eq!(mktcap.data.unwrap().len(), limit);
 }
 
@@ -381,8 +384,7 @@ async fn test_get_overview_mktcap_ohlcv() -> () {
 
 #[tokio::test]
 async fn test_extra() -> () {
-    let mut backend: CoinDesk = CoinDesk::new();
-    backend.build(&"API_KEY").unwrap();
+    let backend: CoinDesk = build_backend();
     let symbol: String = String::from("BTC");
     // let to_timestamp: Option<i64> = Some(1577145600); // 24 Dec 2019
     // let to_timestamp: Option<i64> = Some(1404342000); // 03 Jul 2014
@@ -393,6 +395,6 @@ async fn test_extra() -> () {
     // let data = backend.get_historical_daily(&symbol, to_timestamp, limit).await.unwrap();
     // let data = backend.get_asset_code_repo(&symbol, to_timestamp, limit).await.unwrap();
     // let data = backend.get_occore_supply(&symbol, to_timestamp, limit).await.unwrap();
-    let data = backend.get_spot_ohlcv(&format!("{}-USD", symbol), to_timestamp, limit, SpotMarket::KRAKEN, Unit::Day).await.unwrap();
+    let Some(data) = recorded(backend.get_spot_ohlcv(&format!("{}-USD", symbol), to_timestamp, limit, SpotMarket::KRAKEN, Unit::Day).await) else { return; };
     println!("{:?}", data);
 }
\ No newline at end of file